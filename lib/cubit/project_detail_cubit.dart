@@ -774,6 +774,8 @@ class ProjectDetailCubit extends Cubit<ProjectDetailState> {
                 mfp: k.mfp,
                 derivationPath: k.derivationPath,
                 xpub: k.xpub,
+                // Reported by analysis only, building doesn't read it
+                syntheticMfp: false,
               ))
           .toList();
 
@@ -869,8 +871,10 @@ class ProjectDetailCubit extends Cubit<ProjectDetailState> {
         for (var p in existingPaths) p.rustId: p.priority,
       };
 
-      final result =
-          await analyzeDescriptor(descriptor: newDescriptor.trim());
+      final result = await analyzeDescriptor(
+        descriptor: newDescriptor.trim(),
+        changeDescriptor: null,
+      );
 
       // Create key entries, preserving customName from:
       // 1. Edited key names (priority - includes unsaved edits)
@@ -65,7 +65,10 @@ class ProjectListCubit extends Cubit<ProjectListState> {
     required String name,
   }) async {
     try {
-      final result = await analyzeDescriptor(descriptor: descriptor.trim());
+      final result = await analyzeDescriptor(
+        descriptor: descriptor.trim(),
+        changeDescriptor: null,
+      );
 
       final projectId = await _db.insertProject(ProjectsCompanion.insert(
         name: name.isEmpty ? 'Unnamed project' : name,
@@ -186,7 +189,10 @@ class ProjectListCubit extends Cubit<ProjectListState> {
       final exportData = ProjectExport.fromJsonString(jsonString);
 
       // Analyze the descriptor
-      final result = await analyzeDescriptor(descriptor: exportData.descriptor.trim());
+      final result = await analyzeDescriptor(
+        descriptor: exportData.descriptor.trim(),
+        changeDescriptor: null,
+      );
 
       // Create project
       final projectId = await _db.insertProject(ProjectsCompanion.insert(
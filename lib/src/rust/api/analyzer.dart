@@ -7,110 +7,230 @@ import '../frb_generated.dart';
 import 'model.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `network_display_name`
-
-Future<APIAnalysisResult> analyzeDescriptor({required String descriptor}) =>
-    RustLib.instance.api.crateApiAnalyzerAnalyzeDescriptor(
-      descriptor: descriptor,
-    );
-
-Future<String> buildDescriptor({
-  required APIWalletType walletType,
-  required List<APIPubKey> keys,
-  required List<APISpendPathDef> spendPaths,
-}) => RustLib.instance.api.crateApiAnalyzerBuildDescriptor(
-  walletType: walletType,
-  keys: keys,
-  spendPaths: spendPaths,
-);
+
+            // These functions are ignored because they are not marked as `pub`: `analysis_result`, `analyze_cached`, `core_keys`, `core_spend_path_defs`, `network_display_name`, `validate_caravan_keys`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`
+
+
+            /// Analyze a descriptor, or a receive/change pair when `change_descriptor` is set
+///
+/// A pair is reported as one wallet, with both halves merged into a single
+/// multipath (`<0;1>`) descriptor. Single descriptors are cached by checksum.
+Future<APIAnalysisResult>  analyzeDescriptor({required String descriptor , String? changeDescriptor }) => RustLib.instance.api.crateApiAnalyzerAnalyzeDescriptor(descriptor: descriptor, changeDescriptor: changeDescriptor);
+
+/// Analyze many descriptors in parallel
+///
+/// Results are in the same order as `descriptors`. A descriptor that fails
+/// has its error reported instead of failing the batch.
+Future<List<APIBatchAnalysis>>  analyzeDescriptors({required List<String> descriptors }) => RustLib.instance.api.crateApiAnalyzerAnalyzeDescriptors(descriptors: descriptors);
+
+/// Drop every cached analysis
+Future<void>  clearAnalysisCache() => RustLib.instance.api.crateApiAnalyzerClearAnalysisCache();
+
+/// Import a BIP-129 (BSMS) round 2 descriptor record
+///
+/// The record's first address must be the one the descriptor derives.
+Future<APIAnalysisResult>  importBsmsRecord({required String record }) => RustLib.instance.api.crateApiAnalyzerImportBsmsRecord(record: record);
+
+/// BIP-129 (BSMS) round 2 descriptor record of a descriptor
+Future<String>  exportBsmsRecord({required String descriptor }) => RustLib.instance.api.crateApiAnalyzerExportBsmsRecord(descriptor: descriptor);
+
+/// New BIP-129 (BSMS) session token in hex: 16 bytes when `extended`, 8 otherwise
+Future<String>  createBsmsToken({required bool extended }) => RustLib.instance.api.crateApiAnalyzerCreateBsmsToken(extended: extended);
+
+/// Encrypt a BSMS payload for the session of `token`
+///
+/// A `00` token leaves the payload in the clear.
+Future<String>  encryptBsmsPayload({required String token , required String data }) => RustLib.instance.api.crateApiAnalyzerEncryptBsmsPayload(token: token, data: data);
+
+/// Decrypt a BSMS payload of the session of `token`, checking its MAC
+Future<String>  decryptBsmsPayload({required String token , required String payload }) => RustLib.instance.api.crateApiAnalyzerDecryptBsmsPayload(token: token, payload: payload);
+
+/// Import a BIP-129 (BSMS) round 1 key record of the session of `token`
+///
+/// The record is decrypted first in encrypted sessions. The key is only
+/// returned once the record's signature by it checks out.
+Future<APIPubKey>  importBsmsKeyRecord({required String token , required String record }) => RustLib.instance.api.crateApiAnalyzerImportBsmsKeyRecord(token: token, record: record);
+
+/// Import a Coldcard multisig setup file
+Future<APIImportedWallet>  importColdcardMultisig({required String file }) => RustLib.instance.api.crateApiAnalyzerImportColdcardMultisig(file: file);
+
+/// Coldcard multisig setup file of a descriptor, such as one from `build_descriptor`
+///
+/// Fails with `UnsupportedExport` for policies the format can't express,
+/// like timelocks or taproot.
+Future<String>  exportColdcardMultisig({required String name , required String descriptor }) => RustLib.instance.api.crateApiAnalyzerExportColdcardMultisig(name: name, descriptor: descriptor);
+
+/// Import an unencrypted Electrum wallet file, single key or multisig
+///
+/// The script type comes from the SLIP-132 version of its xpubs.
+Future<APIImportedWallet>  importElectrumWallet({required String file }) => RustLib.instance.api.crateApiAnalyzerImportElectrumWallet(file: file);
+
+/// Watch-only Electrum wallet file of a descriptor
+///
+/// Fails with `UnsupportedExport` for policies Electrum can't express,
+/// like timelocks or taproot.
+Future<String>  exportElectrumWallet({required String descriptor }) => RustLib.instance.api.crateApiAnalyzerExportElectrumWallet(descriptor: descriptor);
+
+/// Import a Caravan wallet configuration
+///
+/// Every key is checked against the configured network with `validate_key`.
+/// The analysis reports that network, which xpub versions alone can't tell
+/// apart from the other test networks.
+Future<APIImportedWallet>  importCaravanWallet({required String config }) => RustLib.instance.api.crateApiAnalyzerImportCaravanWallet(config: config);
+
+/// Caravan wallet configuration of a descriptor, on `network`
+///
+/// Fails with `UnsupportedExport` for policies Caravan can't express, like
+/// single keys, timelocks or taproot, and when a key isn't of `network`.
+Future<String>  exportCaravanWallet({required String name , required String descriptor , required APINetwork network }) => RustLib.instance.api.crateApiAnalyzerExportCaravanWallet(name: name, descriptor: descriptor, network: network);
+
+/// Derive `count` addresses of one keychain, starting at index `start`
+///
+/// The network is detected from the descriptor keys. `count` must not exceed
+/// `MAX_ADDRESS_COUNT`.
+Future<List<APIAddress>>  deriveAddresses({required String descriptor , required APIKeychainKind keychain , required int start , required int count }) => RustLib.instance.api.crateApiAnalyzerDeriveAddresses(descriptor: descriptor, keychain: keychain, start: start, count: count);
+
+/// Find which keychain and index `address` belongs to
+///
+/// Both keychains are scanned from index 0 up to `gap`, at most
+/// `MAX_ADDRESS_COUNT`. Returns `None` when the address is not found.
+Future<APIAddress?>  findAddress({required String descriptor , required String address , required int gap }) => RustLib.instance.api.crateApiAnalyzerFindAddress(descriptor: descriptor, address: address, gap: gap);
+
+/// Size and fee of spending `inputs` inputs through the spend path
+/// `spend_path_id`, at each of `fee_rates` (sat/vB)
+///
+/// There is one output per `destinations` script type, plus a change output
+/// of the wallet's own type when `change` is set.
+Future<List<APIFeeEstimate>>  estimateSpendPathFees({required String descriptor , required int spendPathId , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates }) => RustLib.instance.api.crateApiAnalyzerEstimateSpendPathFees(descriptor: descriptor, spendPathId: spendPathId, inputs: inputs, destinations: destinations, change: change, feeRates: feeRates);
+
+/// Expected size and fee of a spend at each of `fee_rates` (sat/vB), with
+/// each spend path used as often as `likelihoods` says
+///
+/// Spend paths missing from `likelihoods` are never used. Transactions are
+/// as in `estimate_spend_path_fees`.
+Future<List<APIExpectedCost>>  estimateExpectedCost({required String descriptor , required List<APISpendPathLikelihood> likelihoods , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates }) => RustLib.instance.api.crateApiAnalyzerEstimateExpectedCost(descriptor: descriptor, likelihoods: likelihoods, inputs: inputs, destinations: destinations, change: change, feeRates: feeRates);
+
+/// Cost of consolidating `utxos` UTXOs with each spend path, at each of
+/// `fee_rates` (sat/vB)
+Future<List<APIConsolidationPlan>>  planConsolidation({required String descriptor , required int utxos , required List<double> feeRates }) => RustLib.instance.api.crateApiAnalyzerPlanConsolidation(descriptor: descriptor, utxos: utxos, feeRates: feeRates);
+
+/// Standard dust limit of the wallet's outputs and economic dust of each spend
+/// path at each of `fee_rates` (sat/vB)
+Future<APIDustAnalysis>  analyzeDust({required String descriptor , required List<double> feeRates }) => RustLib.instance.api.crateApiAnalyzerAnalyzeDust(descriptor: descriptor, feeRates: feeRates);
+
+Future<String>  buildDescriptor({required APIWalletType walletType , required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths }) => RustLib.instance.api.crateApiAnalyzerBuildDescriptor(walletType: walletType, keys: keys, spendPaths: spendPaths);
+
+/// Build a Taproot descriptor and report the depth and cost of each spend path
+///
+/// When the spend paths have probabilities, the script tree is the one with
+/// the lowest expected spend weight; `expected_wu_in` is averaged with them.
+Future<APITaprootBuild>  buildTaprootDescriptor({required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths }) => RustLib.instance.api.crateApiAnalyzerBuildTaprootDescriptor(keys: keys, spendPaths: spendPaths);
+
+/// Build the policy as every wallet type and compare the cost of each spend
+/// path, spending `inputs` inputs at each of `fee_rates` (sat/vB)
+///
+/// Outputs are as in `estimate_spend_path_fees`. Every wallet type is
+/// reported, with the ones that can't express the policy marked unsupported.
+Future<List<APIScriptTypeComparison>>  compareScriptTypes({required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates }) => RustLib.instance.api.crateApiAnalyzerCompareScriptTypes(keys: keys, spendPaths: spendPaths, inputs: inputs, destinations: destinations, change: change, feeRates: feeRates);
 
 /// Calculate the deterministic rustId for a spend path
 /// Delegates to core::spend_path::calculate_spend_path_id (single source of truth)
-Future<int> calculateSpendPathId({
-  required int threshold,
-  required List<String> mfps,
-  required int relTimelock,
-  required int absTimelock,
-}) => RustLib.instance.api.crateApiAnalyzerCalculateSpendPathId(
-  threshold: threshold,
-  mfps: mfps,
-  relTimelock: relTimelock,
-  absTimelock: absTimelock,
-);
+Future<int>  calculateSpendPathId({required int threshold , required List<String> mfps , required int relTimelock , required int absTimelock }) => RustLib.instance.api.crateApiAnalyzerCalculateSpendPathId(threshold: threshold, mfps: mfps, relTimelock: relTimelock, absTimelock: absTimelock);
 
 /// Decode legacy relative timelock consensus value (for database migration)
-Future<APIRelativeTimelock> decodeLegacyRelTimelock({required int consensus}) =>
-    RustLib.instance.api.crateApiAnalyzerDecodeLegacyRelTimelock(
-      consensus: consensus,
-    );
+Future<APIRelativeTimelock>  decodeLegacyRelTimelock({required int consensus }) => RustLib.instance.api.crateApiAnalyzerDecodeLegacyRelTimelock(consensus: consensus);
 
 /// Decode legacy absolute timelock consensus value (for database migration)
-Future<APIAbsoluteTimelock> decodeLegacyAbsTimelock({required int consensus}) =>
-    RustLib.instance.api.crateApiAnalyzerDecodeLegacyAbsTimelock(
-      consensus: consensus,
-    );
+Future<APIAbsoluteTimelock>  decodeLegacyAbsTimelock({required int consensus }) => RustLib.instance.api.crateApiAnalyzerDecodeLegacyAbsTimelock(consensus: consensus);
 
 /// Calculate spend path rustId from semantic timelock values
 /// Used when Flutter needs to compute rustId from type+value storage
-Future<int> calculateRustidFromTimelocks({
-  required int threshold,
-  required List<String> mfps,
-  required APIRelativeTimelock relTimelock,
-  required APIAbsoluteTimelock absTimelock,
-}) => RustLib.instance.api.crateApiAnalyzerCalculateRustidFromTimelocks(
-  threshold: threshold,
-  mfps: mfps,
-  relTimelock: relTimelock,
-  absTimelock: absTimelock,
-);
+Future<int>  calculateRustidFromTimelocks({required int threshold , required List<String> mfps , required APIRelativeTimelock relTimelock , required APIAbsoluteTimelock absTimelock }) => RustLib.instance.api.crateApiAnalyzerCalculateRustidFromTimelocks(threshold: threshold, mfps: mfps, relTimelock: relTimelock, absTimelock: absTimelock);
 
 /// Validate a key and check network compatibility
 ///
 /// Returns Ok(()) if the key is valid and compatible with the network,
 /// or Err with a descriptive message if validation fails.
-Future<void> validateKey({
-  required String mfp,
-  required String derivationPath,
-  required String xpub,
-  required APINetwork network,
-}) => RustLib.instance.api.crateApiAnalyzerValidateKey(
-  mfp: mfp,
-  derivationPath: derivationPath,
-  xpub: xpub,
-  network: network,
-);
-
-class APIAnalysisResult {
-  final String descriptor;
-  final APINetwork network;
-  final APIWalletType walletType;
-  final List<APIPubKey> keys;
-  final List<APISpendPath> spendPaths;
-
-  const APIAnalysisResult({
-    required this.descriptor,
-    required this.network,
-    required this.walletType,
-    required this.keys,
-    required this.spendPaths,
-  });
-
-  @override
-  int get hashCode =>
-      descriptor.hashCode ^
-      network.hashCode ^
-      walletType.hashCode ^
-      keys.hashCode ^
-      spendPaths.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is APIAnalysisResult &&
-          runtimeType == other.runtimeType &&
-          descriptor == other.descriptor &&
-          network == other.network &&
-          walletType == other.walletType &&
-          keys == other.keys &&
-          spendPaths == other.spendPaths;
-}
+Future<void>  validateKey({required String mfp , required String derivationPath , required String xpub , required APINetwork network }) => RustLib.instance.api.crateApiAnalyzerValidateKey(mfp: mfp, derivationPath: derivationPath, xpub: xpub, network: network);
+
+            class APIAnalysisResult  {
+                final String descriptor;
+final APINetwork network;
+final APIWalletType walletType;
+final List<APIPubKey> keys;
+final List<APISpendPath> spendPaths;
+final APISpendability spendability;
+
+                const APIAnalysisResult({required this.descriptor ,required this.network ,required this.walletType ,required this.keys ,required this.spendPaths ,required this.spendability ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => descriptor.hashCode^network.hashCode^walletType.hashCode^keys.hashCode^spendPaths.hashCode^spendability.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIAnalysisResult &&
+                runtimeType == other.runtimeType
+                && descriptor == other.descriptor&& network == other.network&& walletType == other.walletType&& keys == other.keys&& spendPaths == other.spendPaths&& spendability == other.spendability;
+        
+            }
+
+/// Outcome of analyzing one descriptor of a batch
+class APIBatchAnalysis  {
+                final String descriptor;
+final APIAnalysisResult? result;
+final String? error;
+
+                const APIBatchAnalysis({required this.descriptor ,this.result ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => descriptor.hashCode^result.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIBatchAnalysis &&
+                runtimeType == other.runtimeType
+                && descriptor == other.descriptor&& result == other.result&& error == other.error;
+        
+            }
+
+/// Wallet imported from another wallet's setup file
+class APIImportedWallet  {
+                /// Name given in the file, if any
+final String? name;
+final APIAnalysisResult analysis;
+
+                const APIImportedWallet({this.name ,required this.analysis ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^analysis.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIImportedWallet &&
+                runtimeType == other.runtimeType
+                && name == other.name&& analysis == other.analysis;
+        
+            }
+            
\ No newline at end of file
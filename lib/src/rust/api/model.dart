@@ -7,231 +7,664 @@ import '../core/spend_path.dart';
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `try_from`
-
-class APIAbsoluteTimelock {
-  final APIAbsoluteTimelockType timelockType;
-  final int value;
-
-  const APIAbsoluteTimelock({required this.timelockType, required this.value});
-
-  static Future<APIAbsoluteTimelock> fromConsensus({required int consensus}) =>
-      RustLib.instance.api.crateApiModelApiAbsoluteTimelockFromConsensus(
-        consensus: consensus,
-      );
-
-  Future<int> toConsensus() => RustLib.instance.api
-      .crateApiModelApiAbsoluteTimelockToConsensus(that: this);
-
-  @override
-  int get hashCode => timelockType.hashCode ^ value.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is APIAbsoluteTimelock &&
-          runtimeType == other.runtimeType &&
-          timelockType == other.timelockType &&
-          value == other.value;
-}
-
-enum APIAbsoluteTimelockType { blocks, timestamp }
-
-enum APINetwork { bitcoin, testnet, testnet4, signet, regtest }
-
-class APIPolicyPath {
-  final String policyId;
-  final Uint32List path;
-
-  const APIPolicyPath({required this.policyId, required this.path});
-
-  static Future<APIPolicyPath> default_() =>
-      RustLib.instance.api.crateApiModelApiPolicyPathDefault();
-
-  static Future<List<APIPolicyPath>> fromSpendpath({
-    required SpendPath spendPath,
-  }) => RustLib.instance.api.crateApiModelApiPolicyPathFromSpendpath(
-    spendPath: spendPath,
-  );
-
-  @override
-  int get hashCode => policyId.hashCode ^ path.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is APIPolicyPath &&
-          runtimeType == other.runtimeType &&
-          policyId == other.policyId &&
-          path == other.path;
-}
-
-class APIPubKey {
-  final String mfp;
-  final String derivationPath;
-  final String xpub;
-
-  const APIPubKey({
-    required this.mfp,
-    required this.derivationPath,
-    required this.xpub,
-  });
-
-  @override
-  int get hashCode => mfp.hashCode ^ derivationPath.hashCode ^ xpub.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is APIPubKey &&
-          runtimeType == other.runtimeType &&
-          mfp == other.mfp &&
-          derivationPath == other.derivationPath &&
-          xpub == other.xpub;
-}
-
-class APIRelativeTimelock {
-  final APIRelativeTimelockType timelockType;
-  final int value;
-
-  const APIRelativeTimelock({required this.timelockType, required this.value});
-
-  static Future<APIRelativeTimelock> fromConsensus({required int consensus}) =>
-      RustLib.instance.api.crateApiModelApiRelativeTimelockFromConsensus(
-        consensus: consensus,
-      );
-
-  Future<int> toConsensus() => RustLib.instance.api
-      .crateApiModelApiRelativeTimelockToConsensus(that: this);
-
-  @override
-  int get hashCode => timelockType.hashCode ^ value.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is APIRelativeTimelock &&
-          runtimeType == other.runtimeType &&
-          timelockType == other.timelockType &&
-          value == other.value;
-}
-
-enum APIRelativeTimelockType { blocks, time }
-
-class APISpendPath {
-  final int id;
-  final List<APIPolicyPath> policyPath;
-  final int threshold;
-  final List<String> mfps;
-  final APIRelativeTimelock relTimelock;
-  final APIAbsoluteTimelock absTimelock;
-  final int wuBase;
-  final int wuIn;
-  final int wuOut;
-  final int trDepth;
-  final double vbSweep;
-
-  const APISpendPath({
-    required this.id,
-    required this.policyPath,
-    required this.threshold,
-    required this.mfps,
-    required this.relTimelock,
-    required this.absTimelock,
-    required this.wuBase,
-    required this.wuIn,
-    required this.wuOut,
-    required this.trDepth,
-    required this.vbSweep,
-  });
-
-  static Future<List<APISpendPath>> fromSorted({
-    required List<SpendPath> coreSpendPaths,
-  }) => RustLib.instance.api.crateApiModelApiSpendPathFromSorted(
-    coreSpendPaths: coreSpendPaths,
-  );
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      policyPath.hashCode ^
-      threshold.hashCode ^
-      mfps.hashCode ^
-      relTimelock.hashCode ^
-      absTimelock.hashCode ^
-      wuBase.hashCode ^
-      wuIn.hashCode ^
-      wuOut.hashCode ^
-      trDepth.hashCode ^
-      vbSweep.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is APISpendPath &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          policyPath == other.policyPath &&
-          threshold == other.threshold &&
-          mfps == other.mfps &&
-          relTimelock == other.relTimelock &&
-          absTimelock == other.absTimelock &&
-          wuBase == other.wuBase &&
-          wuIn == other.wuIn &&
-          wuOut == other.wuOut &&
-          trDepth == other.trDepth &&
-          vbSweep == other.vbSweep;
-}
-
-class APISpendPathDef {
-  final int threshold;
-  final List<String> mfps;
-  final APIRelativeTimelock relTimelock;
-  final APIAbsoluteTimelock absTimelock;
-  final bool isKeyPath;
-
-  /// Taproot script tree priority (0 = deepest/least likely, higher = shallower/more likely).
-  /// Ignored for non-Taproot descriptors.
-  final int priority;
-
-  const APISpendPathDef({
-    required this.threshold,
-    required this.mfps,
-    required this.relTimelock,
-    required this.absTimelock,
-    required this.isKeyPath,
-    required this.priority,
-  });
-
-  @override
-  int get hashCode =>
-      threshold.hashCode ^
-      mfps.hashCode ^
-      relTimelock.hashCode ^
-      absTimelock.hashCode ^
-      isKeyPath.hashCode ^
-      priority.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is APISpendPathDef &&
-          runtimeType == other.runtimeType &&
-          threshold == other.threshold &&
-          mfps == other.mfps &&
-          relTimelock == other.relTimelock &&
-          absTimelock == other.absTimelock &&
-          isKeyPath == other.isKeyPath &&
-          priority == other.priority;
-}
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `try_from`
+
+
+            
+
+            class APIAbsoluteTimelock  {
+                final APIAbsoluteTimelockType timelockType;
+final int value;
+
+                const APIAbsoluteTimelock({required this.timelockType ,required this.value ,});
+
+                static Future<APIAbsoluteTimelock>  fromConsensus({required int consensus })=>RustLib.instance.api.crateApiModelApiAbsoluteTimelockFromConsensus(consensus: consensus);
+
+
+ Future<int>  toConsensus()=>RustLib.instance.api.crateApiModelApiAbsoluteTimelockToConsensus(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => timelockType.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIAbsoluteTimelock &&
+                runtimeType == other.runtimeType
+                && timelockType == other.timelockType&& value == other.value;
+        
+            }
+
+enum APIAbsoluteTimelockType {
+                    blocks,
+timestamp,
+                    ;
+                    
+                }
+
+class APIAddress  {
+                final APIKeychainKind keychain;
+final int index;
+final String address;
+final String scriptPubkey;
+
+                const APIAddress({required this.keychain ,required this.index ,required this.address ,required this.scriptPubkey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => keychain.hashCode^index.hashCode^address.hashCode^scriptPubkey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIAddress &&
+                runtimeType == other.runtimeType
+                && keychain == other.keychain&& index == other.index&& address == other.address&& scriptPubkey == other.scriptPubkey;
+        
+            }
+
+/// Cost of sweeping UTXOs into the wallet through one spend path
+class APIConsolidationPlan  {
+                final int spendPathId;
+/// Most inputs a standard transaction can spend, even with the largest signatures
+final int maxInputsPerTx;
+/// Transactions needed to consolidate every UTXO
+final int transactions;
+/// Total size and fee of the transactions, one per fee rate
+final List<APIFeeEstimate> fees;
+
+                const APIConsolidationPlan({required this.spendPathId ,required this.maxInputsPerTx ,required this.transactions ,required this.fees ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => spendPathId.hashCode^maxInputsPerTx.hashCode^transactions.hashCode^fees.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIConsolidationPlan &&
+                runtimeType == other.runtimeType
+                && spendPathId == other.spendPathId&& maxInputsPerTx == other.maxInputsPerTx&& transactions == other.transactions&& fees == other.fees;
+        
+            }
+
+/// Dust thresholds of a wallet, in sats
+class APIDustAnalysis  {
+                /// Standard dust limit of the wallet's outputs; `None` for unknown output types
+final BigInt? dustLimit;
+final List<APISpendPathDust> spendPaths;
+
+                const APIDustAnalysis({this.dustLimit ,required this.spendPaths ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => dustLimit.hashCode^spendPaths.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIDustAnalysis &&
+                runtimeType == other.runtimeType
+                && dustLimit == other.dustLimit&& spendPaths == other.spendPaths;
+        
+            }
+
+/// Size and fee of a spend averaged over the spend paths by their likelihood
+class APIExpectedCost  {
+                /// sat/vB
+final double feeRate;
+final double vbytes;
+final double feeSat;
+/// Same with the largest signatures
+final double vbytesMax;
+final double feeSatMax;
+
+                const APIExpectedCost({required this.feeRate ,required this.vbytes ,required this.feeSat ,required this.vbytesMax ,required this.feeSatMax ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => feeRate.hashCode^vbytes.hashCode^feeSat.hashCode^vbytesMax.hashCode^feeSatMax.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIExpectedCost &&
+                runtimeType == other.runtimeType
+                && feeRate == other.feeRate&& vbytes == other.vbytes&& feeSat == other.feeSat&& vbytesMax == other.vbytesMax&& feeSatMax == other.feeSatMax;
+        
+            }
+
+/// Size and fee of a transaction at one fee rate
+class APIFeeEstimate  {
+                /// sat/vB
+final double feeRate;
+final BigInt vbytes;
+final BigInt feeSat;
+/// Size and fee with the largest signatures, safe to budget for
+final BigInt vbytesMax;
+final BigInt feeSatMax;
+
+                const APIFeeEstimate({required this.feeRate ,required this.vbytes ,required this.feeSat ,required this.vbytesMax ,required this.feeSatMax ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => feeRate.hashCode^vbytes.hashCode^feeSat.hashCode^vbytesMax.hashCode^feeSatMax.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIFeeEstimate &&
+                runtimeType == other.runtimeType
+                && feeRate == other.feeRate&& vbytes == other.vbytes&& feeSat == other.feeSat&& vbytesMax == other.vbytesMax&& feeSatMax == other.feeSatMax;
+        
+            }
+
+class APIHashLock  {
+                final APIHashType hashType;
+final String digest;
+
+                const APIHashLock({required this.hashType ,required this.digest ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hashType.hashCode^digest.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIHashLock &&
+                runtimeType == other.runtimeType
+                && hashType == other.hashType&& digest == other.digest;
+        
+            }
+
+enum APIHashType {
+                    sha256,
+hash256,
+ripemd160,
+hash160,
+                    ;
+                    
+                }
+
+enum APIKeychainKind {
+                    /// Receive addresses (first `<0;1>` branch)
+external_,
+/// Change addresses (second `<0;1>` branch)
+internal,
+                    ;
+                    
+                }
+
+enum APINetwork {
+                    bitcoin,
+testnet,
+testnet4,
+signet,
+regtest,
+                    ;
+                    
+                }
+
+class APIPolicyPath  {
+                final String policyId;
+final Uint32List path;
+
+                const APIPolicyPath({required this.policyId ,required this.path ,});
+
+                static Future<APIPolicyPath>  default_()=>RustLib.instance.api.crateApiModelApiPolicyPathDefault();
+
+
+static Future<List<APIPolicyPath>>  fromSpendpath({required SpendPath spendPath })=>RustLib.instance.api.crateApiModelApiPolicyPathFromSpendpath(spendPath: spendPath);
+
+
+                
+
+                
+        @override
+        int get hashCode => policyId.hashCode^path.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIPolicyPath &&
+                runtimeType == other.runtimeType
+                && policyId == other.policyId&& path == other.path;
+        
+            }
+
+class APIPubKey  {
+                final String mfp;
+final String derivationPath;
+/// Extended public key, or the hex of a raw public key
+final String xpub;
+/// `mfp` is derived from the hash of a raw key that has no origin info
+final bool syntheticMfp;
+
+                const APIPubKey({required this.mfp ,required this.derivationPath ,required this.xpub ,required this.syntheticMfp ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => mfp.hashCode^derivationPath.hashCode^xpub.hashCode^syntheticMfp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIPubKey &&
+                runtimeType == other.runtimeType
+                && mfp == other.mfp&& derivationPath == other.derivationPath&& xpub == other.xpub&& syntheticMfp == other.syntheticMfp;
+        
+            }
+
+class APIRelativeTimelock  {
+                final APIRelativeTimelockType timelockType;
+final int value;
+
+                const APIRelativeTimelock({required this.timelockType ,required this.value ,});
+
+                static Future<APIRelativeTimelock>  fromConsensus({required int consensus })=>RustLib.instance.api.crateApiModelApiRelativeTimelockFromConsensus(consensus: consensus);
+
+
+ Future<int>  toConsensus()=>RustLib.instance.api.crateApiModelApiRelativeTimelockToConsensus(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => timelockType.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIRelativeTimelock &&
+                runtimeType == other.runtimeType
+                && timelockType == other.timelockType&& value == other.value;
+        
+            }
+
+enum APIRelativeTimelockType {
+                    blocks,
+time,
+                    ;
+                    
+                }
+
+/// A policy built as one wallet type, for side-by-side comparison
+class APIScriptTypeComparison  {
+                final APIWalletType walletType;
+/// Whether the wallet type can express the policy
+final bool supported;
+/// Why the wallet type can't express the policy
+final String? unsupportedReason;
+final String? descriptor;
+/// Empty when unsupported; IDs match across wallet types
+final List<APISpendPathCost> spendPaths;
+
+                const APIScriptTypeComparison({required this.walletType ,required this.supported ,this.unsupportedReason ,this.descriptor ,required this.spendPaths ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => walletType.hashCode^supported.hashCode^unsupportedReason.hashCode^descriptor.hashCode^spendPaths.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APIScriptTypeComparison &&
+                runtimeType == other.runtimeType
+                && walletType == other.walletType&& supported == other.supported&& unsupportedReason == other.unsupportedReason&& descriptor == other.descriptor&& spendPaths == other.spendPaths;
+        
+            }
+
+/// Nested group of signers with its own threshold
+class APISignerGroup  {
+                final int threshold;
+/// Direct signers of the group
+final List<String> mfps;
+/// Nested groups, each counting as one item towards `threshold`
+final List<APISignerGroup> groups;
+
+                const APISignerGroup({required this.threshold ,required this.mfps ,required this.groups ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => threshold.hashCode^mfps.hashCode^groups.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APISignerGroup &&
+                runtimeType == other.runtimeType
+                && threshold == other.threshold&& mfps == other.mfps&& groups == other.groups;
+        
+            }
+
+/// Minimal combination of signers that can spend a path
+class APISignerSet  {
+                final List<String> mfps;
+final int wuIn;
+
+                const APISignerSet({required this.mfps ,required this.wuIn ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => mfps.hashCode^wuIn.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APISignerSet &&
+                runtimeType == other.runtimeType
+                && mfps == other.mfps&& wuIn == other.wuIn;
+        
+            }
+
+class APISpendPath  {
+                final int id;
+final List<APIPolicyPath> policyPath;
+final int threshold;
+/// Every signer of the path, including those of nested groups
+final List<String> mfps;
+/// Nested signer groups; empty for flat k-of-n paths
+final List<APISignerGroup> signerGroups;
+final APIRelativeTimelock relTimelock;
+final APIAbsoluteTimelock absTimelock;
+/// Every relative timelock of the path; `rel_timelock` is the strictest one
+final List<APIRelativeTimelock> relTimelocks;
+/// Every absolute timelock of the path; `abs_timelock` is the strictest one
+final List<APIAbsoluteTimelock> absTimelocks;
+/// Height and time based locks are mixed, so the path can't be satisfied
+final bool timelockConflict;
+/// Preimages that must be revealed to spend
+final List<APIHashLock> hashlocks;
+final int wuBase;
+final int wuIn;
+/// Input weight with low-R ECDSA signatures (71 bytes)
+final int wuInMin;
+/// Input weight with high-R ECDSA signatures (72 bytes) and Schnorr
+/// signatures with an explicit sighash type (65 bytes)
+final int wuInMax;
+final int wuOut;
+/// Minimal signer combinations with their input weight, cheapest first
+final List<APISignerSet> signerSets;
+/// More combinations exist than `signer_sets` lists
+final bool signerSetsTruncated;
+final int trDepth;
+final double vbSweep;
+
+                const APISpendPath({required this.id ,required this.policyPath ,required this.threshold ,required this.mfps ,required this.signerGroups ,required this.relTimelock ,required this.absTimelock ,required this.relTimelocks ,required this.absTimelocks ,required this.timelockConflict ,required this.hashlocks ,required this.wuBase ,required this.wuIn ,required this.wuInMin ,required this.wuInMax ,required this.wuOut ,required this.signerSets ,required this.signerSetsTruncated ,required this.trDepth ,required this.vbSweep ,});
+
+                static Future<List<APISpendPath>>  fromSorted({required List<SpendPath> coreSpendPaths })=>RustLib.instance.api.crateApiModelApiSpendPathFromSorted(coreSpendPaths: coreSpendPaths);
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^policyPath.hashCode^threshold.hashCode^mfps.hashCode^signerGroups.hashCode^relTimelock.hashCode^absTimelock.hashCode^relTimelocks.hashCode^absTimelocks.hashCode^timelockConflict.hashCode^hashlocks.hashCode^wuBase.hashCode^wuIn.hashCode^wuInMin.hashCode^wuInMax.hashCode^wuOut.hashCode^signerSets.hashCode^signerSetsTruncated.hashCode^trDepth.hashCode^vbSweep.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APISpendPath &&
+                runtimeType == other.runtimeType
+                && id == other.id&& policyPath == other.policyPath&& threshold == other.threshold&& mfps == other.mfps&& signerGroups == other.signerGroups&& relTimelock == other.relTimelock&& absTimelock == other.absTimelock&& relTimelocks == other.relTimelocks&& absTimelocks == other.absTimelocks&& timelockConflict == other.timelockConflict&& hashlocks == other.hashlocks&& wuBase == other.wuBase&& wuIn == other.wuIn&& wuInMin == other.wuInMin&& wuInMax == other.wuInMax&& wuOut == other.wuOut&& signerSets == other.signerSets&& signerSetsTruncated == other.signerSetsTruncated&& trDepth == other.trDepth&& vbSweep == other.vbSweep;
+        
+            }
+
+/// Input weight and transaction fees of a spend path
+class APISpendPathCost  {
+                final int spendPathId;
+final int wuIn;
+final int wuInMax;
+/// One per requested fee rate, in the same order
+final List<APIFeeEstimate> fees;
+
+                const APISpendPathCost({required this.spendPathId ,required this.wuIn ,required this.wuInMax ,required this.fees ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => spendPathId.hashCode^wuIn.hashCode^wuInMax.hashCode^fees.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APISpendPathCost &&
+                runtimeType == other.runtimeType
+                && spendPathId == other.spendPathId&& wuIn == other.wuIn&& wuInMax == other.wuInMax&& fees == other.fees;
+        
+            }
+
+class APISpendPathDef  {
+                final int threshold;
+final List<String> mfps;
+final APIRelativeTimelock relTimelock;
+final APIAbsoluteTimelock absTimelock;
+final bool isKeyPath;
+/// Taproot script tree priority (0 = deepest/least likely, higher = shallower/more likely).
+/// Ignored for non-Taproot descriptors.
+final int priority;
+/// Expected share of spends that use this path, in any unit. Either every
+/// path has one or none does; when given, the Taproot script tree is built
+/// from them instead of from `priority`.
+final double? probability;
+
+                const APISpendPathDef({required this.threshold ,required this.mfps ,required this.relTimelock ,required this.absTimelock ,required this.isKeyPath ,required this.priority ,this.probability ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => threshold.hashCode^mfps.hashCode^relTimelock.hashCode^absTimelock.hashCode^isKeyPath.hashCode^priority.hashCode^probability.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APISpendPathDef &&
+                runtimeType == other.runtimeType
+                && threshold == other.threshold&& mfps == other.mfps&& relTimelock == other.relTimelock&& absTimelock == other.absTimelock&& isKeyPath == other.isKeyPath&& priority == other.priority&& probability == other.probability;
+        
+            }
+
+/// Economic dust of a spend path: UTXOs worth this much or less give nothing
+/// back when spent through it
+class APISpendPathDust  {
+                final int spendPathId;
+/// One value per requested fee rate, in the same order
+final Uint64List economicDust;
+
+                const APISpendPathDust({required this.spendPathId ,required this.economicDust ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => spendPathId.hashCode^economicDust.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APISpendPathDust &&
+                runtimeType == other.runtimeType
+                && spendPathId == other.spendPathId&& economicDust == other.economicDust;
+        
+            }
+
+/// How often a spend path is expected to be used, in any unit
+class APISpendPathLikelihood  {
+                final int spendPathId;
+final double likelihood;
+
+                const APISpendPathLikelihood({required this.spendPathId ,required this.likelihood ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => spendPathId.hashCode^likelihood.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APISpendPathLikelihood &&
+                runtimeType == other.runtimeType
+                && spendPathId == other.spendPathId&& likelihood == other.likelihood;
+        
+            }
+
+/// Whether the spend paths tell how the outputs are spent
+enum APISpendability {
+                    known,
+/// Only the output script is known (`addr()`, `raw()`)
+unknown,
+unspendable,
+                    ;
+                    
+                }
+
+/// Taproot descriptor along with the expected cost of its script tree
+class APITaprootBuild  {
+                final String descriptor;
+final List<APITaprootPathCost> spendPaths;
+/// Input weight averaged over the spend paths by probability
+final double expectedWuIn;
+
+                const APITaprootBuild({required this.descriptor ,required this.spendPaths ,required this.expectedWuIn ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => descriptor.hashCode^spendPaths.hashCode^expectedWuIn.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APITaprootBuild &&
+                runtimeType == other.runtimeType
+                && descriptor == other.descriptor&& spendPaths == other.spendPaths&& expectedWuIn == other.expectedWuIn;
+        
+            }
+
+/// Where a spend path ended up in the Taproot tree and what it costs
+class APITaprootPathCost  {
+                final int spendPathId;
+/// Normalized, so all paths add up to 1
+final double probability;
+/// -1 for the key path, leaf depth for script paths
+final int trDepth;
+final int wuIn;
+
+                const APITaprootPathCost({required this.spendPathId ,required this.probability ,required this.trDepth ,required this.wuIn ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => spendPathId.hashCode^probability.hashCode^trDepth.hashCode^wuIn.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is APITaprootPathCost &&
+                runtimeType == other.runtimeType
+                && spendPathId == other.spendPathId&& probability == other.probability&& trDepth == other.trDepth&& wuIn == other.wuIn;
+        
+            }
 
 enum APIWalletType {
-  p2Pkh,
-  p2Wpkh,
-  p2Sh,
-  p2Wsh,
-  p2Tr,
-  p2ShWpkh,
-  p2ShWsh,
-  unknown,
-}
+                    p2Pkh,
+p2Wpkh,
+p2Sh,
+p2Wsh,
+p2Tr,
+p2ShWpkh,
+p2ShWsh,
+unknown,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -6,5 +6,17 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpendPath>>
-abstract class SpendPath implements RustOpaqueInterface {}
+
+            
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpendPath>>
+                abstract class SpendPath implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -9,1645 +9,2411 @@ import 'core/spend_path.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiAnalyzerInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 1693999108;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_deadbolt',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<APIAnalysisResult> crateApiAnalyzerAnalyzeDescriptor({
-    required String descriptor,
-  });
-
-  Future<APIAbsoluteTimelock> crateApiModelApiAbsoluteTimelockFromConsensus({
-    required int consensus,
-  });
-
-  Future<int> crateApiModelApiAbsoluteTimelockToConsensus({
-    required APIAbsoluteTimelock that,
-  });
-
-  Future<APIPolicyPath> crateApiModelApiPolicyPathDefault();
-
-  Future<List<APIPolicyPath>> crateApiModelApiPolicyPathFromSpendpath({
-    required SpendPath spendPath,
-  });
-
-  Future<APIRelativeTimelock> crateApiModelApiRelativeTimelockFromConsensus({
-    required int consensus,
-  });
-
-  Future<int> crateApiModelApiRelativeTimelockToConsensus({
-    required APIRelativeTimelock that,
-  });
-
-  Future<List<APISpendPath>> crateApiModelApiSpendPathFromSorted({
-    required List<SpendPath> coreSpendPaths,
-  });
-
-  Future<String> crateApiAnalyzerBuildDescriptor({
-    required APIWalletType walletType,
-    required List<APIPubKey> keys,
-    required List<APISpendPathDef> spendPaths,
-  });
-
-  Future<int> crateApiAnalyzerCalculateRustidFromTimelocks({
-    required int threshold,
-    required List<String> mfps,
-    required APIRelativeTimelock relTimelock,
-    required APIAbsoluteTimelock absTimelock,
-  });
-
-  Future<int> crateApiAnalyzerCalculateSpendPathId({
-    required int threshold,
-    required List<String> mfps,
-    required int relTimelock,
-    required int absTimelock,
-  });
-
-  Future<APIAbsoluteTimelock> crateApiAnalyzerDecodeLegacyAbsTimelock({
-    required int consensus,
-  });
-
-  Future<APIRelativeTimelock> crateApiAnalyzerDecodeLegacyRelTimelock({
-    required int consensus,
-  });
-
-  Future<void> crateApiAnalyzerInitApp();
-
-  Future<void> crateApiAnalyzerValidateKey({
-    required String mfp,
-    required String derivationPath,
-    required String xpub,
-    required APINetwork network,
-  });
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SpendPath;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SpendPath;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SpendPathPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<APIAnalysisResult> crateApiAnalyzerAnalyzeDescriptor({
-    required String descriptor,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(descriptor, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiAnalyzerInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => 57443799;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_deadbolt',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<APIAnalysisResult> crateApiAnalyzerAnalyzeDescriptor({required String descriptor , String? changeDescriptor });
+
+Future<List<APIBatchAnalysis>> crateApiAnalyzerAnalyzeDescriptors({required List<String> descriptors });
+
+Future<APIDustAnalysis> crateApiAnalyzerAnalyzeDust({required String descriptor , required List<double> feeRates });
+
+Future<APIAbsoluteTimelock> crateApiModelApiAbsoluteTimelockFromConsensus({required int consensus });
+
+Future<int> crateApiModelApiAbsoluteTimelockToConsensus({required APIAbsoluteTimelock that });
+
+Future<APIPolicyPath> crateApiModelApiPolicyPathDefault();
+
+Future<List<APIPolicyPath>> crateApiModelApiPolicyPathFromSpendpath({required SpendPath spendPath });
+
+Future<APIRelativeTimelock> crateApiModelApiRelativeTimelockFromConsensus({required int consensus });
+
+Future<int> crateApiModelApiRelativeTimelockToConsensus({required APIRelativeTimelock that });
+
+Future<List<APISpendPath>> crateApiModelApiSpendPathFromSorted({required List<SpendPath> coreSpendPaths });
+
+Future<String> crateApiAnalyzerBuildDescriptor({required APIWalletType walletType , required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths });
+
+Future<APITaprootBuild> crateApiAnalyzerBuildTaprootDescriptor({required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths });
+
+Future<int> crateApiAnalyzerCalculateRustidFromTimelocks({required int threshold , required List<String> mfps , required APIRelativeTimelock relTimelock , required APIAbsoluteTimelock absTimelock });
+
+Future<int> crateApiAnalyzerCalculateSpendPathId({required int threshold , required List<String> mfps , required int relTimelock , required int absTimelock });
+
+Future<void> crateApiAnalyzerClearAnalysisCache();
+
+Future<List<APIScriptTypeComparison>> crateApiAnalyzerCompareScriptTypes({required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates });
+
+Future<String> crateApiAnalyzerCreateBsmsToken({required bool extended });
+
+Future<APIAbsoluteTimelock> crateApiAnalyzerDecodeLegacyAbsTimelock({required int consensus });
+
+Future<APIRelativeTimelock> crateApiAnalyzerDecodeLegacyRelTimelock({required int consensus });
+
+Future<String> crateApiAnalyzerDecryptBsmsPayload({required String token , required String payload });
+
+Future<List<APIAddress>> crateApiAnalyzerDeriveAddresses({required String descriptor , required APIKeychainKind keychain , required int start , required int count });
+
+Future<String> crateApiAnalyzerEncryptBsmsPayload({required String token , required String data });
+
+Future<List<APIExpectedCost>> crateApiAnalyzerEstimateExpectedCost({required String descriptor , required List<APISpendPathLikelihood> likelihoods , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates });
+
+Future<List<APIFeeEstimate>> crateApiAnalyzerEstimateSpendPathFees({required String descriptor , required int spendPathId , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates });
+
+Future<String> crateApiAnalyzerExportBsmsRecord({required String descriptor });
+
+Future<String> crateApiAnalyzerExportCaravanWallet({required String name , required String descriptor , required APINetwork network });
+
+Future<String> crateApiAnalyzerExportColdcardMultisig({required String name , required String descriptor });
+
+Future<String> crateApiAnalyzerExportElectrumWallet({required String descriptor });
+
+Future<APIAddress?> crateApiAnalyzerFindAddress({required String descriptor , required String address , required int gap });
+
+Future<APIPubKey> crateApiAnalyzerImportBsmsKeyRecord({required String token , required String record });
+
+Future<APIAnalysisResult> crateApiAnalyzerImportBsmsRecord({required String record });
+
+Future<APIImportedWallet> crateApiAnalyzerImportCaravanWallet({required String config });
+
+Future<APIImportedWallet> crateApiAnalyzerImportColdcardMultisig({required String file });
+
+Future<APIImportedWallet> crateApiAnalyzerImportElectrumWallet({required String file });
+
+Future<void> crateApiAnalyzerInitApp();
+
+Future<List<APIConsolidationPlan>> crateApiAnalyzerPlanConsolidation({required String descriptor , required int utxos , required List<double> feeRates });
+
+Future<void> crateApiAnalyzerValidateKey({required String mfp , required String derivationPath , required String xpub , required APINetwork network });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SpendPath;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SpendPath;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SpendPathPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<APIAnalysisResult> crateApiAnalyzerAnalyzeDescriptor({required String descriptor , String? changeDescriptor })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+sse_encode_opt_String(changeDescriptor, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_api_analysis_result,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAnalyzerAnalyzeDescriptorConstMeta,
-        argValues: [descriptor],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalyzerAnalyzeDescriptorConstMeta =>
-      const TaskConstMeta(
-        debugName: "analyze_descriptor",
-        argNames: ["descriptor"],
-      );
-
-  @override
-  Future<APIAbsoluteTimelock> crateApiModelApiAbsoluteTimelockFromConsensus({
-    required int consensus,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(consensus, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAnalyzerAnalyzeDescriptorConstMeta,
+            argValues: [descriptor, changeDescriptor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerAnalyzeDescriptorConstMeta => const TaskConstMeta(
+            debugName: "analyze_descriptor",
+            argNames: ["descriptor", "changeDescriptor"],
+        );
+        
+
+@override Future<List<APIBatchAnalysis>> crateApiAnalyzerAnalyzeDescriptors({required List<String> descriptors })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(descriptors, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_api_batch_analysis,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerAnalyzeDescriptorsConstMeta,
+            argValues: [descriptors],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerAnalyzeDescriptorsConstMeta => const TaskConstMeta(
+            debugName: "analyze_descriptors",
+            argNames: ["descriptors"],
+        );
+        
+
+@override Future<APIDustAnalysis> crateApiAnalyzerAnalyzeDust({required String descriptor , required List<double> feeRates })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+sse_encode_list_prim_f_64_loose(feeRates, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_api_dust_analysis,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerAnalyzeDustConstMeta,
+            argValues: [descriptor, feeRates],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerAnalyzeDustConstMeta => const TaskConstMeta(
+            debugName: "analyze_dust",
+            argNames: ["descriptor", "feeRates"],
+        );
+        
+
+@override Future<APIAbsoluteTimelock> crateApiModelApiAbsoluteTimelockFromConsensus({required int consensus })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(consensus, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_api_absolute_timelock,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelApiAbsoluteTimelockFromConsensusConstMeta,
-        argValues: [consensus],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelApiAbsoluteTimelockFromConsensusConstMeta =>
-      const TaskConstMeta(
-        debugName: "api_absolute_timelock_from_consensus",
-        argNames: ["consensus"],
-      );
-
-  @override
-  Future<int> crateApiModelApiAbsoluteTimelockToConsensus({
-    required APIAbsoluteTimelock that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_api_absolute_timelock(that, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiModelApiAbsoluteTimelockFromConsensusConstMeta,
+            argValues: [consensus],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelApiAbsoluteTimelockFromConsensusConstMeta => const TaskConstMeta(
+            debugName: "api_absolute_timelock_from_consensus",
+            argNames: ["consensus"],
+        );
+        
+
+@override Future<int> crateApiModelApiAbsoluteTimelockToConsensus({required APIAbsoluteTimelock that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_api_absolute_timelock(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiModelApiAbsoluteTimelockToConsensusConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelApiAbsoluteTimelockToConsensusConstMeta =>
-      const TaskConstMeta(
-        debugName: "api_absolute_timelock_to_consensus",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<APIPolicyPath> crateApiModelApiPolicyPathDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiModelApiAbsoluteTimelockToConsensusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelApiAbsoluteTimelockToConsensusConstMeta => const TaskConstMeta(
+            debugName: "api_absolute_timelock_to_consensus",
+            argNames: ["that"],
+        );
+        
+
+@override Future<APIPolicyPath> crateApiModelApiPolicyPathDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_api_policy_path,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelApiPolicyPathDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelApiPolicyPathDefaultConstMeta =>
-      const TaskConstMeta(debugName: "api_policy_path_default", argNames: []);
-
-  @override
-  Future<List<APIPolicyPath>> crateApiModelApiPolicyPathFromSpendpath({
-    required SpendPath spendPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-            spendPath,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiModelApiPolicyPathDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelApiPolicyPathDefaultConstMeta => const TaskConstMeta(
+            debugName: "api_policy_path_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<APIPolicyPath>> crateApiModelApiPolicyPathFromSpendpath({required SpendPath spendPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(spendPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_api_policy_path,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiModelApiPolicyPathFromSpendpathConstMeta,
-        argValues: [spendPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelApiPolicyPathFromSpendpathConstMeta =>
-      const TaskConstMeta(
-        debugName: "api_policy_path_from_spendpath",
-        argNames: ["spendPath"],
-      );
-
-  @override
-  Future<APIRelativeTimelock> crateApiModelApiRelativeTimelockFromConsensus({
-    required int consensus,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(consensus, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiModelApiPolicyPathFromSpendpathConstMeta,
+            argValues: [spendPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelApiPolicyPathFromSpendpathConstMeta => const TaskConstMeta(
+            debugName: "api_policy_path_from_spendpath",
+            argNames: ["spendPath"],
+        );
+        
+
+@override Future<APIRelativeTimelock> crateApiModelApiRelativeTimelockFromConsensus({required int consensus })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(consensus, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_api_relative_timelock,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelApiRelativeTimelockFromConsensusConstMeta,
-        argValues: [consensus],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelApiRelativeTimelockFromConsensusConstMeta =>
-      const TaskConstMeta(
-        debugName: "api_relative_timelock_from_consensus",
-        argNames: ["consensus"],
-      );
-
-  @override
-  Future<int> crateApiModelApiRelativeTimelockToConsensus({
-    required APIRelativeTimelock that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_api_relative_timelock(that, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiModelApiRelativeTimelockFromConsensusConstMeta,
+            argValues: [consensus],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelApiRelativeTimelockFromConsensusConstMeta => const TaskConstMeta(
+            debugName: "api_relative_timelock_from_consensus",
+            argNames: ["consensus"],
+        );
+        
+
+@override Future<int> crateApiModelApiRelativeTimelockToConsensus({required APIRelativeTimelock that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_api_relative_timelock(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiModelApiRelativeTimelockToConsensusConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelApiRelativeTimelockToConsensusConstMeta =>
-      const TaskConstMeta(
-        debugName: "api_relative_timelock_to_consensus",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<List<APISpendPath>> crateApiModelApiSpendPathFromSorted({
-    required List<SpendPath> coreSpendPaths,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-            coreSpendPaths,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiModelApiRelativeTimelockToConsensusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelApiRelativeTimelockToConsensusConstMeta => const TaskConstMeta(
+            debugName: "api_relative_timelock_to_consensus",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<APISpendPath>> crateApiModelApiSpendPathFromSorted({required List<SpendPath> coreSpendPaths })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(coreSpendPaths, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_api_spend_path,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiModelApiSpendPathFromSortedConstMeta,
-        argValues: [coreSpendPaths],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelApiSpendPathFromSortedConstMeta =>
-      const TaskConstMeta(
-        debugName: "api_spend_path_from_sorted",
-        argNames: ["coreSpendPaths"],
-      );
-
-  @override
-  Future<String> crateApiAnalyzerBuildDescriptor({
-    required APIWalletType walletType,
-    required List<APIPubKey> keys,
-    required List<APISpendPathDef> spendPaths,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_api_wallet_type(walletType, serializer);
-          sse_encode_list_api_pub_key(keys, serializer);
-          sse_encode_list_api_spend_path_def(spendPaths, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 9,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiModelApiSpendPathFromSortedConstMeta,
+            argValues: [coreSpendPaths],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelApiSpendPathFromSortedConstMeta => const TaskConstMeta(
+            debugName: "api_spend_path_from_sorted",
+            argNames: ["coreSpendPaths"],
+        );
+        
+
+@override Future<String> crateApiAnalyzerBuildDescriptor({required APIWalletType walletType , required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_api_wallet_type(walletType, serializer);
+sse_encode_list_api_pub_key(keys, serializer);
+sse_encode_list_api_spend_path_def(spendPaths, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAnalyzerBuildDescriptorConstMeta,
-        argValues: [walletType, keys, spendPaths],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalyzerBuildDescriptorConstMeta =>
-      const TaskConstMeta(
-        debugName: "build_descriptor",
-        argNames: ["walletType", "keys", "spendPaths"],
-      );
-
-  @override
-  Future<int> crateApiAnalyzerCalculateRustidFromTimelocks({
-    required int threshold,
-    required List<String> mfps,
-    required APIRelativeTimelock relTimelock,
-    required APIAbsoluteTimelock absTimelock,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(threshold, serializer);
-          sse_encode_list_String(mfps, serializer);
-          sse_encode_box_autoadd_api_relative_timelock(relTimelock, serializer);
-          sse_encode_box_autoadd_api_absolute_timelock(absTimelock, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAnalyzerBuildDescriptorConstMeta,
+            argValues: [walletType, keys, spendPaths],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerBuildDescriptorConstMeta => const TaskConstMeta(
+            debugName: "build_descriptor",
+            argNames: ["walletType", "keys", "spendPaths"],
+        );
+        
+
+@override Future<APITaprootBuild> crateApiAnalyzerBuildTaprootDescriptor({required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_api_pub_key(keys, serializer);
+sse_encode_list_api_spend_path_def(spendPaths, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_api_taproot_build,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerBuildTaprootDescriptorConstMeta,
+            argValues: [keys, spendPaths],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerBuildTaprootDescriptorConstMeta => const TaskConstMeta(
+            debugName: "build_taproot_descriptor",
+            argNames: ["keys", "spendPaths"],
+        );
+        
+
+@override Future<int> crateApiAnalyzerCalculateRustidFromTimelocks({required int threshold , required List<String> mfps , required APIRelativeTimelock relTimelock , required APIAbsoluteTimelock absTimelock })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(threshold, serializer);
+sse_encode_list_String(mfps, serializer);
+sse_encode_box_autoadd_api_relative_timelock(relTimelock, serializer);
+sse_encode_box_autoadd_api_absolute_timelock(absTimelock, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAnalyzerCalculateRustidFromTimelocksConstMeta,
-        argValues: [threshold, mfps, relTimelock, absTimelock],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalyzerCalculateRustidFromTimelocksConstMeta =>
-      const TaskConstMeta(
-        debugName: "calculate_rustid_from_timelocks",
-        argNames: ["threshold", "mfps", "relTimelock", "absTimelock"],
-      );
-
-  @override
-  Future<int> crateApiAnalyzerCalculateSpendPathId({
-    required int threshold,
-    required List<String> mfps,
-    required int relTimelock,
-    required int absTimelock,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(threshold, serializer);
-          sse_encode_list_String(mfps, serializer);
-          sse_encode_u_32(relTimelock, serializer);
-          sse_encode_u_32(absTimelock, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAnalyzerCalculateRustidFromTimelocksConstMeta,
+            argValues: [threshold, mfps, relTimelock, absTimelock],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerCalculateRustidFromTimelocksConstMeta => const TaskConstMeta(
+            debugName: "calculate_rustid_from_timelocks",
+            argNames: ["threshold", "mfps", "relTimelock", "absTimelock"],
+        );
+        
+
+@override Future<int> crateApiAnalyzerCalculateSpendPathId({required int threshold , required List<String> mfps , required int relTimelock , required int absTimelock })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(threshold, serializer);
+sse_encode_list_String(mfps, serializer);
+sse_encode_u_32(relTimelock, serializer);
+sse_encode_u_32(absTimelock, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAnalyzerCalculateSpendPathIdConstMeta,
-        argValues: [threshold, mfps, relTimelock, absTimelock],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalyzerCalculateSpendPathIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "calculate_spend_path_id",
-        argNames: ["threshold", "mfps", "relTimelock", "absTimelock"],
-      );
-
-  @override
-  Future<APIAbsoluteTimelock> crateApiAnalyzerDecodeLegacyAbsTimelock({
-    required int consensus,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(consensus, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAnalyzerCalculateSpendPathIdConstMeta,
+            argValues: [threshold, mfps, relTimelock, absTimelock],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerCalculateSpendPathIdConstMeta => const TaskConstMeta(
+            debugName: "calculate_spend_path_id",
+            argNames: ["threshold", "mfps", "relTimelock", "absTimelock"],
+        );
+        
+
+@override Future<void> crateApiAnalyzerClearAnalysisCache()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerClearAnalysisCacheConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerClearAnalysisCacheConstMeta => const TaskConstMeta(
+            debugName: "clear_analysis_cache",
+            argNames: [],
+        );
+        
+
+@override Future<List<APIScriptTypeComparison>> crateApiAnalyzerCompareScriptTypes({required List<APIPubKey> keys , required List<APISpendPathDef> spendPaths , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_api_pub_key(keys, serializer);
+sse_encode_list_api_spend_path_def(spendPaths, serializer);
+sse_encode_u_32(inputs, serializer);
+sse_encode_list_api_wallet_type(destinations, serializer);
+sse_encode_bool(change, serializer);
+sse_encode_list_prim_f_64_loose(feeRates, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_api_script_type_comparison,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerCompareScriptTypesConstMeta,
+            argValues: [keys, spendPaths, inputs, destinations, change, feeRates],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerCompareScriptTypesConstMeta => const TaskConstMeta(
+            debugName: "compare_script_types",
+            argNames: ["keys", "spendPaths", "inputs", "destinations", "change", "feeRates"],
+        );
+        
+
+@override Future<String> crateApiAnalyzerCreateBsmsToken({required bool extended })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(extended, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerCreateBsmsTokenConstMeta,
+            argValues: [extended],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerCreateBsmsTokenConstMeta => const TaskConstMeta(
+            debugName: "create_bsms_token",
+            argNames: ["extended"],
+        );
+        
+
+@override Future<APIAbsoluteTimelock> crateApiAnalyzerDecodeLegacyAbsTimelock({required int consensus })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(consensus, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_api_absolute_timelock,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAnalyzerDecodeLegacyAbsTimelockConstMeta,
-        argValues: [consensus],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalyzerDecodeLegacyAbsTimelockConstMeta =>
-      const TaskConstMeta(
-        debugName: "decode_legacy_abs_timelock",
-        argNames: ["consensus"],
-      );
-
-  @override
-  Future<APIRelativeTimelock> crateApiAnalyzerDecodeLegacyRelTimelock({
-    required int consensus,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(consensus, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAnalyzerDecodeLegacyAbsTimelockConstMeta,
+            argValues: [consensus],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerDecodeLegacyAbsTimelockConstMeta => const TaskConstMeta(
+            debugName: "decode_legacy_abs_timelock",
+            argNames: ["consensus"],
+        );
+        
+
+@override Future<APIRelativeTimelock> crateApiAnalyzerDecodeLegacyRelTimelock({required int consensus })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(consensus, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_api_relative_timelock,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAnalyzerDecodeLegacyRelTimelockConstMeta,
-        argValues: [consensus],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalyzerDecodeLegacyRelTimelockConstMeta =>
-      const TaskConstMeta(
-        debugName: "decode_legacy_rel_timelock",
-        argNames: ["consensus"],
-      );
-
-  @override
-  Future<void> crateApiAnalyzerInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAnalyzerDecodeLegacyRelTimelockConstMeta,
+            argValues: [consensus],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerDecodeLegacyRelTimelockConstMeta => const TaskConstMeta(
+            debugName: "decode_legacy_rel_timelock",
+            argNames: ["consensus"],
+        );
+        
+
+@override Future<String> crateApiAnalyzerDecryptBsmsPayload({required String token , required String payload })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+sse_encode_String(payload, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerDecryptBsmsPayloadConstMeta,
+            argValues: [token, payload],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerDecryptBsmsPayloadConstMeta => const TaskConstMeta(
+            debugName: "decrypt_bsms_payload",
+            argNames: ["token", "payload"],
+        );
+        
+
+@override Future<List<APIAddress>> crateApiAnalyzerDeriveAddresses({required String descriptor , required APIKeychainKind keychain , required int start , required int count })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+sse_encode_api_keychain_kind(keychain, serializer);
+sse_encode_u_32(start, serializer);
+sse_encode_u_32(count, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_api_address,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerDeriveAddressesConstMeta,
+            argValues: [descriptor, keychain, start, count],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerDeriveAddressesConstMeta => const TaskConstMeta(
+            debugName: "derive_addresses",
+            argNames: ["descriptor", "keychain", "start", "count"],
+        );
+        
+
+@override Future<String> crateApiAnalyzerEncryptBsmsPayload({required String token , required String data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+sse_encode_String(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerEncryptBsmsPayloadConstMeta,
+            argValues: [token, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerEncryptBsmsPayloadConstMeta => const TaskConstMeta(
+            debugName: "encrypt_bsms_payload",
+            argNames: ["token", "data"],
+        );
+        
+
+@override Future<List<APIExpectedCost>> crateApiAnalyzerEstimateExpectedCost({required String descriptor , required List<APISpendPathLikelihood> likelihoods , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+sse_encode_list_api_spend_path_likelihood(likelihoods, serializer);
+sse_encode_u_32(inputs, serializer);
+sse_encode_list_api_wallet_type(destinations, serializer);
+sse_encode_bool(change, serializer);
+sse_encode_list_prim_f_64_loose(feeRates, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_api_expected_cost,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerEstimateExpectedCostConstMeta,
+            argValues: [descriptor, likelihoods, inputs, destinations, change, feeRates],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerEstimateExpectedCostConstMeta => const TaskConstMeta(
+            debugName: "estimate_expected_cost",
+            argNames: ["descriptor", "likelihoods", "inputs", "destinations", "change", "feeRates"],
+        );
+        
+
+@override Future<List<APIFeeEstimate>> crateApiAnalyzerEstimateSpendPathFees({required String descriptor , required int spendPathId , required int inputs , required List<APIWalletType> destinations , required bool change , required List<double> feeRates })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+sse_encode_u_32(spendPathId, serializer);
+sse_encode_u_32(inputs, serializer);
+sse_encode_list_api_wallet_type(destinations, serializer);
+sse_encode_bool(change, serializer);
+sse_encode_list_prim_f_64_loose(feeRates, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_api_fee_estimate,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerEstimateSpendPathFeesConstMeta,
+            argValues: [descriptor, spendPathId, inputs, destinations, change, feeRates],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerEstimateSpendPathFeesConstMeta => const TaskConstMeta(
+            debugName: "estimate_spend_path_fees",
+            argNames: ["descriptor", "spendPathId", "inputs", "destinations", "change", "feeRates"],
+        );
+        
+
+@override Future<String> crateApiAnalyzerExportBsmsRecord({required String descriptor })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerExportBsmsRecordConstMeta,
+            argValues: [descriptor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerExportBsmsRecordConstMeta => const TaskConstMeta(
+            debugName: "export_bsms_record",
+            argNames: ["descriptor"],
+        );
+        
+
+@override Future<String> crateApiAnalyzerExportCaravanWallet({required String name , required String descriptor , required APINetwork network })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_String(descriptor, serializer);
+sse_encode_api_network(network, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerExportCaravanWalletConstMeta,
+            argValues: [name, descriptor, network],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerExportCaravanWalletConstMeta => const TaskConstMeta(
+            debugName: "export_caravan_wallet",
+            argNames: ["name", "descriptor", "network"],
+        );
+        
+
+@override Future<String> crateApiAnalyzerExportColdcardMultisig({required String name , required String descriptor })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_String(descriptor, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerExportColdcardMultisigConstMeta,
+            argValues: [name, descriptor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerExportColdcardMultisigConstMeta => const TaskConstMeta(
+            debugName: "export_coldcard_multisig",
+            argNames: ["name", "descriptor"],
+        );
+        
+
+@override Future<String> crateApiAnalyzerExportElectrumWallet({required String descriptor })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerExportElectrumWalletConstMeta,
+            argValues: [descriptor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerExportElectrumWalletConstMeta => const TaskConstMeta(
+            debugName: "export_electrum_wallet",
+            argNames: ["descriptor"],
+        );
+        
+
+@override Future<APIAddress?> crateApiAnalyzerFindAddress({required String descriptor , required String address , required int gap })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+sse_encode_String(address, serializer);
+sse_encode_u_32(gap, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_api_address,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerFindAddressConstMeta,
+            argValues: [descriptor, address, gap],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerFindAddressConstMeta => const TaskConstMeta(
+            debugName: "find_address",
+            argNames: ["descriptor", "address", "gap"],
+        );
+        
+
+@override Future<APIPubKey> crateApiAnalyzerImportBsmsKeyRecord({required String token , required String record })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+sse_encode_String(record, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_api_pub_key,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerImportBsmsKeyRecordConstMeta,
+            argValues: [token, record],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerImportBsmsKeyRecordConstMeta => const TaskConstMeta(
+            debugName: "import_bsms_key_record",
+            argNames: ["token", "record"],
+        );
+        
+
+@override Future<APIAnalysisResult> crateApiAnalyzerImportBsmsRecord({required String record })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(record, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_api_analysis_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerImportBsmsRecordConstMeta,
+            argValues: [record],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerImportBsmsRecordConstMeta => const TaskConstMeta(
+            debugName: "import_bsms_record",
+            argNames: ["record"],
+        );
+        
+
+@override Future<APIImportedWallet> crateApiAnalyzerImportCaravanWallet({required String config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_api_imported_wallet,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerImportCaravanWalletConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerImportCaravanWalletConstMeta => const TaskConstMeta(
+            debugName: "import_caravan_wallet",
+            argNames: ["config"],
+        );
+        
+
+@override Future<APIImportedWallet> crateApiAnalyzerImportColdcardMultisig({required String file })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(file, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_api_imported_wallet,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerImportColdcardMultisigConstMeta,
+            argValues: [file],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerImportColdcardMultisigConstMeta => const TaskConstMeta(
+            debugName: "import_coldcard_multisig",
+            argNames: ["file"],
+        );
+        
+
+@override Future<APIImportedWallet> crateApiAnalyzerImportElectrumWallet({required String file })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(file, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_api_imported_wallet,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerImportElectrumWalletConstMeta,
+            argValues: [file],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerImportElectrumWalletConstMeta => const TaskConstMeta(
+            debugName: "import_electrum_wallet",
+            argNames: ["file"],
+        );
+        
+
+@override Future<void> crateApiAnalyzerInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAnalyzerInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalyzerInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<void> crateApiAnalyzerValidateKey({
-    required String mfp,
-    required String derivationPath,
-    required String xpub,
-    required APINetwork network,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mfp, serializer);
-          sse_encode_String(derivationPath, serializer);
-          sse_encode_String(xpub, serializer);
-          sse_encode_api_network(network, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 15,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiAnalyzerInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<List<APIConsolidationPlan>> crateApiAnalyzerPlanConsolidation({required String descriptor , required int utxos , required List<double> feeRates })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(descriptor, serializer);
+sse_encode_u_32(utxos, serializer);
+sse_encode_list_prim_f_64_loose(feeRates, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_api_consolidation_plan,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAnalyzerPlanConsolidationConstMeta,
+            argValues: [descriptor, utxos, feeRates],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerPlanConsolidationConstMeta => const TaskConstMeta(
+            debugName: "plan_consolidation",
+            argNames: ["descriptor", "utxos", "feeRates"],
+        );
+        
+
+@override Future<void> crateApiAnalyzerValidateKey({required String mfp , required String derivationPath , required String xpub , required APINetwork network })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(mfp, serializer);
+sse_encode_String(derivationPath, serializer);
+sse_encode_String(xpub, serializer);
+sse_encode_api_network(network, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiAnalyzerValidateKeyConstMeta,
-        argValues: [mfp, derivationPath, xpub, network],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalyzerValidateKeyConstMeta =>
-      const TaskConstMeta(
-        debugName: "validate_key",
-        argNames: ["mfp", "derivationPath", "xpub", "network"],
-      );
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SpendPath => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SpendPath => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  SpendPath
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SpendPathImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SpendPath
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SpendPathImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SpendPath
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SpendPathImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  APIAbsoluteTimelock dco_decode_api_absolute_timelock(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return APIAbsoluteTimelock(
-      timelockType: dco_decode_api_absolute_timelock_type(arr[0]),
-      value: dco_decode_u_32(arr[1]),
-    );
-  }
-
-  @protected
-  APIAbsoluteTimelockType dco_decode_api_absolute_timelock_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return APIAbsoluteTimelockType.values[raw as int];
-  }
-
-  @protected
-  APIAnalysisResult dco_decode_api_analysis_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return APIAnalysisResult(
-      descriptor: dco_decode_String(arr[0]),
-      network: dco_decode_api_network(arr[1]),
-      walletType: dco_decode_api_wallet_type(arr[2]),
-      keys: dco_decode_list_api_pub_key(arr[3]),
-      spendPaths: dco_decode_list_api_spend_path(arr[4]),
-    );
-  }
-
-  @protected
-  APINetwork dco_decode_api_network(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return APINetwork.values[raw as int];
-  }
-
-  @protected
-  APIPolicyPath dco_decode_api_policy_path(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return APIPolicyPath(
-      policyId: dco_decode_String(arr[0]),
-      path: dco_decode_list_prim_u_32_strict(arr[1]),
-    );
-  }
-
-  @protected
-  APIPubKey dco_decode_api_pub_key(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return APIPubKey(
-      mfp: dco_decode_String(arr[0]),
-      derivationPath: dco_decode_String(arr[1]),
-      xpub: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  APIRelativeTimelock dco_decode_api_relative_timelock(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return APIRelativeTimelock(
-      timelockType: dco_decode_api_relative_timelock_type(arr[0]),
-      value: dco_decode_u_32(arr[1]),
-    );
-  }
-
-  @protected
-  APIRelativeTimelockType dco_decode_api_relative_timelock_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return APIRelativeTimelockType.values[raw as int];
-  }
-
-  @protected
-  APISpendPath dco_decode_api_spend_path(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return APISpendPath(
-      id: dco_decode_u_32(arr[0]),
-      policyPath: dco_decode_list_api_policy_path(arr[1]),
-      threshold: dco_decode_u_32(arr[2]),
-      mfps: dco_decode_list_String(arr[3]),
-      relTimelock: dco_decode_api_relative_timelock(arr[4]),
-      absTimelock: dco_decode_api_absolute_timelock(arr[5]),
-      wuBase: dco_decode_u_32(arr[6]),
-      wuIn: dco_decode_u_32(arr[7]),
-      wuOut: dco_decode_u_32(arr[8]),
-      trDepth: dco_decode_i_32(arr[9]),
-      vbSweep: dco_decode_f_32(arr[10]),
-    );
-  }
-
-  @protected
-  APISpendPathDef dco_decode_api_spend_path_def(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return APISpendPathDef(
-      threshold: dco_decode_u_32(arr[0]),
-      mfps: dco_decode_list_String(arr[1]),
-      relTimelock: dco_decode_api_relative_timelock(arr[2]),
-      absTimelock: dco_decode_api_absolute_timelock(arr[3]),
-      isKeyPath: dco_decode_bool(arr[4]),
-      priority: dco_decode_u_32(arr[5]),
-    );
-  }
-
-  @protected
-  APIWalletType dco_decode_api_wallet_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return APIWalletType.values[raw as int];
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  APIAbsoluteTimelock dco_decode_box_autoadd_api_absolute_timelock(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_api_absolute_timelock(raw);
-  }
-
-  @protected
-  APIRelativeTimelock dco_decode_box_autoadd_api_relative_timelock(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_api_relative_timelock(raw);
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  List<SpendPath>
-  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(
-          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath,
         )
-        .toList();
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<APIPolicyPath> dco_decode_list_api_policy_path(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_api_policy_path).toList();
-  }
-
-  @protected
-  List<APIPubKey> dco_decode_list_api_pub_key(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_api_pub_key).toList();
-  }
-
-  @protected
-  List<APISpendPath> dco_decode_list_api_spend_path(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_api_spend_path).toList();
-  }
-
-  @protected
-  List<APISpendPathDef> dco_decode_list_api_spend_path_def(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_api_spend_path_def).toList();
-  }
-
-  @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint32List;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  SpendPath
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SpendPathImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SpendPath
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SpendPathImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SpendPath
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SpendPathImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  APIAbsoluteTimelock sse_decode_api_absolute_timelock(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_timelockType = sse_decode_api_absolute_timelock_type(deserializer);
-    var var_value = sse_decode_u_32(deserializer);
-    return APIAbsoluteTimelock(
-      timelockType: var_timelockType,
-      value: var_value,
-    );
-  }
-
-  @protected
-  APIAbsoluteTimelockType sse_decode_api_absolute_timelock_type(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return APIAbsoluteTimelockType.values[inner];
-  }
-
-  @protected
-  APIAnalysisResult sse_decode_api_analysis_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_descriptor = sse_decode_String(deserializer);
-    var var_network = sse_decode_api_network(deserializer);
-    var var_walletType = sse_decode_api_wallet_type(deserializer);
-    var var_keys = sse_decode_list_api_pub_key(deserializer);
-    var var_spendPaths = sse_decode_list_api_spend_path(deserializer);
-    return APIAnalysisResult(
-      descriptor: var_descriptor,
-      network: var_network,
-      walletType: var_walletType,
-      keys: var_keys,
-      spendPaths: var_spendPaths,
-    );
-  }
-
-  @protected
-  APINetwork sse_decode_api_network(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return APINetwork.values[inner];
-  }
-
-  @protected
-  APIPolicyPath sse_decode_api_policy_path(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_policyId = sse_decode_String(deserializer);
-    var var_path = sse_decode_list_prim_u_32_strict(deserializer);
-    return APIPolicyPath(policyId: var_policyId, path: var_path);
-  }
-
-  @protected
-  APIPubKey sse_decode_api_pub_key(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_mfp = sse_decode_String(deserializer);
-    var var_derivationPath = sse_decode_String(deserializer);
-    var var_xpub = sse_decode_String(deserializer);
-    return APIPubKey(
-      mfp: var_mfp,
-      derivationPath: var_derivationPath,
-      xpub: var_xpub,
-    );
-  }
-
-  @protected
-  APIRelativeTimelock sse_decode_api_relative_timelock(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_timelockType = sse_decode_api_relative_timelock_type(deserializer);
-    var var_value = sse_decode_u_32(deserializer);
-    return APIRelativeTimelock(
-      timelockType: var_timelockType,
-      value: var_value,
-    );
-  }
-
-  @protected
-  APIRelativeTimelockType sse_decode_api_relative_timelock_type(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return APIRelativeTimelockType.values[inner];
-  }
-
-  @protected
-  APISpendPath sse_decode_api_spend_path(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_policyPath = sse_decode_list_api_policy_path(deserializer);
-    var var_threshold = sse_decode_u_32(deserializer);
-    var var_mfps = sse_decode_list_String(deserializer);
-    var var_relTimelock = sse_decode_api_relative_timelock(deserializer);
-    var var_absTimelock = sse_decode_api_absolute_timelock(deserializer);
-    var var_wuBase = sse_decode_u_32(deserializer);
-    var var_wuIn = sse_decode_u_32(deserializer);
-    var var_wuOut = sse_decode_u_32(deserializer);
-    var var_trDepth = sse_decode_i_32(deserializer);
-    var var_vbSweep = sse_decode_f_32(deserializer);
-    return APISpendPath(
-      id: var_id,
-      policyPath: var_policyPath,
-      threshold: var_threshold,
-      mfps: var_mfps,
-      relTimelock: var_relTimelock,
-      absTimelock: var_absTimelock,
-      wuBase: var_wuBase,
-      wuIn: var_wuIn,
-      wuOut: var_wuOut,
-      trDepth: var_trDepth,
-      vbSweep: var_vbSweep,
-    );
-  }
-
-  @protected
-  APISpendPathDef sse_decode_api_spend_path_def(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_threshold = sse_decode_u_32(deserializer);
-    var var_mfps = sse_decode_list_String(deserializer);
-    var var_relTimelock = sse_decode_api_relative_timelock(deserializer);
-    var var_absTimelock = sse_decode_api_absolute_timelock(deserializer);
-    var var_isKeyPath = sse_decode_bool(deserializer);
-    var var_priority = sse_decode_u_32(deserializer);
-    return APISpendPathDef(
-      threshold: var_threshold,
-      mfps: var_mfps,
-      relTimelock: var_relTimelock,
-      absTimelock: var_absTimelock,
-      isKeyPath: var_isKeyPath,
-      priority: var_priority,
-    );
-  }
-
-  @protected
-  APIWalletType sse_decode_api_wallet_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return APIWalletType.values[inner];
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  APIAbsoluteTimelock sse_decode_box_autoadd_api_absolute_timelock(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_api_absolute_timelock(deserializer));
-  }
-
-  @protected
-  APIRelativeTimelock sse_decode_box_autoadd_api_relative_timelock(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_api_relative_timelock(deserializer));
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  List<SpendPath>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SpendPath>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-          deserializer,
-        ),
-      );
-    }
-    return ans_;
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<APIPolicyPath> sse_decode_list_api_policy_path(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <APIPolicyPath>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_policy_path(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<APIPubKey> sse_decode_list_api_pub_key(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <APIPubKey>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_pub_key(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<APISpendPath> sse_decode_list_api_spend_path(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <APISpendPath>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_spend_path(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<APISpendPathDef> sse_decode_list_api_spend_path_def(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <APISpendPathDef>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_spend_path_def(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint32List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SpendPath self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SpendPathImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SpendPath self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SpendPathImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SpendPath self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SpendPathImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_api_absolute_timelock(
-    APIAbsoluteTimelock self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_api_absolute_timelock_type(self.timelockType, serializer);
-    sse_encode_u_32(self.value, serializer);
-  }
-
-  @protected
-  void sse_encode_api_absolute_timelock_type(
-    APIAbsoluteTimelockType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_api_analysis_result(
-    APIAnalysisResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.descriptor, serializer);
-    sse_encode_api_network(self.network, serializer);
-    sse_encode_api_wallet_type(self.walletType, serializer);
-    sse_encode_list_api_pub_key(self.keys, serializer);
-    sse_encode_list_api_spend_path(self.spendPaths, serializer);
-  }
-
-  @protected
-  void sse_encode_api_network(APINetwork self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_api_policy_path(
-    APIPolicyPath self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.policyId, serializer);
-    sse_encode_list_prim_u_32_strict(self.path, serializer);
-  }
-
-  @protected
-  void sse_encode_api_pub_key(APIPubKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.mfp, serializer);
-    sse_encode_String(self.derivationPath, serializer);
-    sse_encode_String(self.xpub, serializer);
-  }
-
-  @protected
-  void sse_encode_api_relative_timelock(
-    APIRelativeTimelock self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_api_relative_timelock_type(self.timelockType, serializer);
-    sse_encode_u_32(self.value, serializer);
-  }
-
-  @protected
-  void sse_encode_api_relative_timelock_type(
-    APIRelativeTimelockType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_api_spend_path(APISpendPath self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_list_api_policy_path(self.policyPath, serializer);
-    sse_encode_u_32(self.threshold, serializer);
-    sse_encode_list_String(self.mfps, serializer);
-    sse_encode_api_relative_timelock(self.relTimelock, serializer);
-    sse_encode_api_absolute_timelock(self.absTimelock, serializer);
-    sse_encode_u_32(self.wuBase, serializer);
-    sse_encode_u_32(self.wuIn, serializer);
-    sse_encode_u_32(self.wuOut, serializer);
-    sse_encode_i_32(self.trDepth, serializer);
-    sse_encode_f_32(self.vbSweep, serializer);
-  }
-
-  @protected
-  void sse_encode_api_spend_path_def(
-    APISpendPathDef self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.threshold, serializer);
-    sse_encode_list_String(self.mfps, serializer);
-    sse_encode_api_relative_timelock(self.relTimelock, serializer);
-    sse_encode_api_absolute_timelock(self.absTimelock, serializer);
-    sse_encode_bool(self.isKeyPath, serializer);
-    sse_encode_u_32(self.priority, serializer);
-  }
-
-  @protected
-  void sse_encode_api_wallet_type(
-    APIWalletType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_api_absolute_timelock(
-    APIAbsoluteTimelock self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_api_absolute_timelock(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_api_relative_timelock(
-    APIRelativeTimelock self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_api_relative_timelock(self, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    List<SpendPath> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-        item,
-        serializer,
-      );
-    }
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_api_policy_path(
-    List<APIPolicyPath> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_policy_path(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_api_pub_key(
-    List<APIPubKey> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_pub_key(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_api_spend_path(
-    List<APISpendPath> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_spend_path(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_api_spend_path_def(
-    List<APISpendPathDef> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_spend_path_def(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint32List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
-
-@sealed
-class SpendPathImpl extends RustOpaque implements SpendPath {
-  // Not to be used by end users
-  SpendPathImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  SpendPathImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_SpendPath,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SpendPath,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SpendPathPtr,
-  );
-}
+        ,
+            constMeta: kCrateApiAnalyzerValidateKeyConstMeta,
+            argValues: [mfp, derivationPath, xpub, network],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalyzerValidateKeyConstMeta => const TaskConstMeta(
+            debugName: "validate_key",
+            argNames: ["mfp", "derivationPath", "xpub", "network"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SpendPath => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SpendPath => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected SpendPath dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SpendPathImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SpendPath dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SpendPathImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SpendPath dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SpendPathImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected APIAbsoluteTimelock dco_decode_api_absolute_timelock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APIAbsoluteTimelock(timelockType: dco_decode_api_absolute_timelock_type(arr[0]),
+value: dco_decode_u_32(arr[1]),); }
+
+@protected APIAbsoluteTimelockType dco_decode_api_absolute_timelock_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return APIAbsoluteTimelockType.values[raw as int]; }
+
+@protected APIAddress dco_decode_api_address(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return APIAddress(keychain: dco_decode_api_keychain_kind(arr[0]),
+index: dco_decode_u_32(arr[1]),
+address: dco_decode_String(arr[2]),
+scriptPubkey: dco_decode_String(arr[3]),); }
+
+@protected APIAnalysisResult dco_decode_api_analysis_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return APIAnalysisResult(descriptor: dco_decode_String(arr[0]),
+network: dco_decode_api_network(arr[1]),
+walletType: dco_decode_api_wallet_type(arr[2]),
+keys: dco_decode_list_api_pub_key(arr[3]),
+spendPaths: dco_decode_list_api_spend_path(arr[4]),
+spendability: dco_decode_api_spendability(arr[5]),); }
+
+@protected APIBatchAnalysis dco_decode_api_batch_analysis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return APIBatchAnalysis(descriptor: dco_decode_String(arr[0]),
+result: dco_decode_opt_box_autoadd_api_analysis_result(arr[1]),
+error: dco_decode_opt_String(arr[2]),); }
+
+@protected APIConsolidationPlan dco_decode_api_consolidation_plan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return APIConsolidationPlan(spendPathId: dco_decode_u_32(arr[0]),
+maxInputsPerTx: dco_decode_u_32(arr[1]),
+transactions: dco_decode_u_32(arr[2]),
+fees: dco_decode_list_api_fee_estimate(arr[3]),); }
+
+@protected APIDustAnalysis dco_decode_api_dust_analysis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APIDustAnalysis(dustLimit: dco_decode_opt_box_autoadd_u_64(arr[0]),
+spendPaths: dco_decode_list_api_spend_path_dust(arr[1]),); }
+
+@protected APIExpectedCost dco_decode_api_expected_cost(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return APIExpectedCost(feeRate: dco_decode_f_64(arr[0]),
+vbytes: dco_decode_f_64(arr[1]),
+feeSat: dco_decode_f_64(arr[2]),
+vbytesMax: dco_decode_f_64(arr[3]),
+feeSatMax: dco_decode_f_64(arr[4]),); }
+
+@protected APIFeeEstimate dco_decode_api_fee_estimate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return APIFeeEstimate(feeRate: dco_decode_f_64(arr[0]),
+vbytes: dco_decode_u_64(arr[1]),
+feeSat: dco_decode_u_64(arr[2]),
+vbytesMax: dco_decode_u_64(arr[3]),
+feeSatMax: dco_decode_u_64(arr[4]),); }
+
+@protected APIHashLock dco_decode_api_hash_lock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APIHashLock(hashType: dco_decode_api_hash_type(arr[0]),
+digest: dco_decode_String(arr[1]),); }
+
+@protected APIHashType dco_decode_api_hash_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return APIHashType.values[raw as int]; }
+
+@protected APIImportedWallet dco_decode_api_imported_wallet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APIImportedWallet(name: dco_decode_opt_String(arr[0]),
+analysis: dco_decode_api_analysis_result(arr[1]),); }
+
+@protected APIKeychainKind dco_decode_api_keychain_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return APIKeychainKind.values[raw as int]; }
+
+@protected APINetwork dco_decode_api_network(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return APINetwork.values[raw as int]; }
+
+@protected APIPolicyPath dco_decode_api_policy_path(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APIPolicyPath(policyId: dco_decode_String(arr[0]),
+path: dco_decode_list_prim_u_32_strict(arr[1]),); }
+
+@protected APIPubKey dco_decode_api_pub_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return APIPubKey(mfp: dco_decode_String(arr[0]),
+derivationPath: dco_decode_String(arr[1]),
+xpub: dco_decode_String(arr[2]),
+syntheticMfp: dco_decode_bool(arr[3]),); }
+
+@protected APIRelativeTimelock dco_decode_api_relative_timelock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APIRelativeTimelock(timelockType: dco_decode_api_relative_timelock_type(arr[0]),
+value: dco_decode_u_32(arr[1]),); }
+
+@protected APIRelativeTimelockType dco_decode_api_relative_timelock_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return APIRelativeTimelockType.values[raw as int]; }
+
+@protected APIScriptTypeComparison dco_decode_api_script_type_comparison(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return APIScriptTypeComparison(walletType: dco_decode_api_wallet_type(arr[0]),
+supported: dco_decode_bool(arr[1]),
+unsupportedReason: dco_decode_opt_String(arr[2]),
+descriptor: dco_decode_opt_String(arr[3]),
+spendPaths: dco_decode_list_api_spend_path_cost(arr[4]),); }
+
+@protected APISignerGroup dco_decode_api_signer_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return APISignerGroup(threshold: dco_decode_u_32(arr[0]),
+mfps: dco_decode_list_String(arr[1]),
+groups: dco_decode_list_api_signer_group(arr[2]),); }
+
+@protected APISignerSet dco_decode_api_signer_set(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APISignerSet(mfps: dco_decode_list_String(arr[0]),
+wuIn: dco_decode_u_32(arr[1]),); }
+
+@protected APISpendPath dco_decode_api_spend_path(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 20) throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+                return APISpendPath(id: dco_decode_u_32(arr[0]),
+policyPath: dco_decode_list_api_policy_path(arr[1]),
+threshold: dco_decode_u_32(arr[2]),
+mfps: dco_decode_list_String(arr[3]),
+signerGroups: dco_decode_list_api_signer_group(arr[4]),
+relTimelock: dco_decode_api_relative_timelock(arr[5]),
+absTimelock: dco_decode_api_absolute_timelock(arr[6]),
+relTimelocks: dco_decode_list_api_relative_timelock(arr[7]),
+absTimelocks: dco_decode_list_api_absolute_timelock(arr[8]),
+timelockConflict: dco_decode_bool(arr[9]),
+hashlocks: dco_decode_list_api_hash_lock(arr[10]),
+wuBase: dco_decode_u_32(arr[11]),
+wuIn: dco_decode_u_32(arr[12]),
+wuInMin: dco_decode_u_32(arr[13]),
+wuInMax: dco_decode_u_32(arr[14]),
+wuOut: dco_decode_u_32(arr[15]),
+signerSets: dco_decode_list_api_signer_set(arr[16]),
+signerSetsTruncated: dco_decode_bool(arr[17]),
+trDepth: dco_decode_i_32(arr[18]),
+vbSweep: dco_decode_f_32(arr[19]),); }
+
+@protected APISpendPathCost dco_decode_api_spend_path_cost(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return APISpendPathCost(spendPathId: dco_decode_u_32(arr[0]),
+wuIn: dco_decode_u_32(arr[1]),
+wuInMax: dco_decode_u_32(arr[2]),
+fees: dco_decode_list_api_fee_estimate(arr[3]),); }
+
+@protected APISpendPathDef dco_decode_api_spend_path_def(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return APISpendPathDef(threshold: dco_decode_u_32(arr[0]),
+mfps: dco_decode_list_String(arr[1]),
+relTimelock: dco_decode_api_relative_timelock(arr[2]),
+absTimelock: dco_decode_api_absolute_timelock(arr[3]),
+isKeyPath: dco_decode_bool(arr[4]),
+priority: dco_decode_u_32(arr[5]),
+probability: dco_decode_opt_box_autoadd_f_64(arr[6]),); }
+
+@protected APISpendPathDust dco_decode_api_spend_path_dust(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APISpendPathDust(spendPathId: dco_decode_u_32(arr[0]),
+economicDust: dco_decode_list_prim_u_64_strict(arr[1]),); }
+
+@protected APISpendPathLikelihood dco_decode_api_spend_path_likelihood(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return APISpendPathLikelihood(spendPathId: dco_decode_u_32(arr[0]),
+likelihood: dco_decode_f_64(arr[1]),); }
+
+@protected APISpendability dco_decode_api_spendability(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return APISpendability.values[raw as int]; }
+
+@protected APITaprootBuild dco_decode_api_taproot_build(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return APITaprootBuild(descriptor: dco_decode_String(arr[0]),
+spendPaths: dco_decode_list_api_taproot_path_cost(arr[1]),
+expectedWuIn: dco_decode_f_64(arr[2]),); }
+
+@protected APITaprootPathCost dco_decode_api_taproot_path_cost(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return APITaprootPathCost(spendPathId: dco_decode_u_32(arr[0]),
+probability: dco_decode_f_64(arr[1]),
+trDepth: dco_decode_i_32(arr[2]),
+wuIn: dco_decode_u_32(arr[3]),); }
+
+@protected APIWalletType dco_decode_api_wallet_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return APIWalletType.values[raw as int]; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected APIAbsoluteTimelock dco_decode_box_autoadd_api_absolute_timelock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_api_absolute_timelock(raw); }
+
+@protected APIAddress dco_decode_box_autoadd_api_address(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_api_address(raw); }
+
+@protected APIAnalysisResult dco_decode_box_autoadd_api_analysis_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_api_analysis_result(raw); }
+
+@protected APIRelativeTimelock dco_decode_box_autoadd_api_relative_timelock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_api_relative_timelock(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected List<SpendPath> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath).toList(); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<APIAbsoluteTimelock> dco_decode_list_api_absolute_timelock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_absolute_timelock).toList(); }
+
+@protected List<APIAddress> dco_decode_list_api_address(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_address).toList(); }
+
+@protected List<APIBatchAnalysis> dco_decode_list_api_batch_analysis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_batch_analysis).toList(); }
+
+@protected List<APIConsolidationPlan> dco_decode_list_api_consolidation_plan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_consolidation_plan).toList(); }
+
+@protected List<APIExpectedCost> dco_decode_list_api_expected_cost(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_expected_cost).toList(); }
+
+@protected List<APIFeeEstimate> dco_decode_list_api_fee_estimate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_fee_estimate).toList(); }
+
+@protected List<APIHashLock> dco_decode_list_api_hash_lock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_hash_lock).toList(); }
+
+@protected List<APIPolicyPath> dco_decode_list_api_policy_path(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_policy_path).toList(); }
+
+@protected List<APIPubKey> dco_decode_list_api_pub_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_pub_key).toList(); }
+
+@protected List<APIRelativeTimelock> dco_decode_list_api_relative_timelock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_relative_timelock).toList(); }
+
+@protected List<APIScriptTypeComparison> dco_decode_list_api_script_type_comparison(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_script_type_comparison).toList(); }
+
+@protected List<APISignerGroup> dco_decode_list_api_signer_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_signer_group).toList(); }
+
+@protected List<APISignerSet> dco_decode_list_api_signer_set(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_signer_set).toList(); }
+
+@protected List<APISpendPath> dco_decode_list_api_spend_path(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_spend_path).toList(); }
+
+@protected List<APISpendPathCost> dco_decode_list_api_spend_path_cost(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_spend_path_cost).toList(); }
+
+@protected List<APISpendPathDef> dco_decode_list_api_spend_path_def(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_spend_path_def).toList(); }
+
+@protected List<APISpendPathDust> dco_decode_list_api_spend_path_dust(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_spend_path_dust).toList(); }
+
+@protected List<APISpendPathLikelihood> dco_decode_list_api_spend_path_likelihood(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_spend_path_likelihood).toList(); }
+
+@protected List<APITaprootPathCost> dco_decode_list_api_taproot_path_cost(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_taproot_path_cost).toList(); }
+
+@protected List<APIWalletType> dco_decode_list_api_wallet_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_api_wallet_type).toList(); }
+
+@protected List<double> dco_decode_list_prim_f_64_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<double>; }
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float64List; }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeUint64List(raw); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected APIAddress? dco_decode_opt_box_autoadd_api_address(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_api_address(raw); }
+
+@protected APIAnalysisResult? dco_decode_opt_box_autoadd_api_analysis_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_api_analysis_result(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected SpendPath sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SpendPathImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SpendPath sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SpendPathImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SpendPath sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SpendPathImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected APIAbsoluteTimelock sse_decode_api_absolute_timelock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timelockType = sse_decode_api_absolute_timelock_type(deserializer);
+var var_value = sse_decode_u_32(deserializer);
+return APIAbsoluteTimelock(timelockType: var_timelockType, value: var_value); }
+
+@protected APIAbsoluteTimelockType sse_decode_api_absolute_timelock_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return APIAbsoluteTimelockType.values[inner]; }
+
+@protected APIAddress sse_decode_api_address(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_keychain = sse_decode_api_keychain_kind(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+var var_address = sse_decode_String(deserializer);
+var var_scriptPubkey = sse_decode_String(deserializer);
+return APIAddress(keychain: var_keychain, index: var_index, address: var_address, scriptPubkey: var_scriptPubkey); }
+
+@protected APIAnalysisResult sse_decode_api_analysis_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_descriptor = sse_decode_String(deserializer);
+var var_network = sse_decode_api_network(deserializer);
+var var_walletType = sse_decode_api_wallet_type(deserializer);
+var var_keys = sse_decode_list_api_pub_key(deserializer);
+var var_spendPaths = sse_decode_list_api_spend_path(deserializer);
+var var_spendability = sse_decode_api_spendability(deserializer);
+return APIAnalysisResult(descriptor: var_descriptor, network: var_network, walletType: var_walletType, keys: var_keys, spendPaths: var_spendPaths, spendability: var_spendability); }
+
+@protected APIBatchAnalysis sse_decode_api_batch_analysis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_descriptor = sse_decode_String(deserializer);
+var var_result = sse_decode_opt_box_autoadd_api_analysis_result(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return APIBatchAnalysis(descriptor: var_descriptor, result: var_result, error: var_error); }
+
+@protected APIConsolidationPlan sse_decode_api_consolidation_plan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_spendPathId = sse_decode_u_32(deserializer);
+var var_maxInputsPerTx = sse_decode_u_32(deserializer);
+var var_transactions = sse_decode_u_32(deserializer);
+var var_fees = sse_decode_list_api_fee_estimate(deserializer);
+return APIConsolidationPlan(spendPathId: var_spendPathId, maxInputsPerTx: var_maxInputsPerTx, transactions: var_transactions, fees: var_fees); }
+
+@protected APIDustAnalysis sse_decode_api_dust_analysis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dustLimit = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_spendPaths = sse_decode_list_api_spend_path_dust(deserializer);
+return APIDustAnalysis(dustLimit: var_dustLimit, spendPaths: var_spendPaths); }
+
+@protected APIExpectedCost sse_decode_api_expected_cost(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_feeRate = sse_decode_f_64(deserializer);
+var var_vbytes = sse_decode_f_64(deserializer);
+var var_feeSat = sse_decode_f_64(deserializer);
+var var_vbytesMax = sse_decode_f_64(deserializer);
+var var_feeSatMax = sse_decode_f_64(deserializer);
+return APIExpectedCost(feeRate: var_feeRate, vbytes: var_vbytes, feeSat: var_feeSat, vbytesMax: var_vbytesMax, feeSatMax: var_feeSatMax); }
+
+@protected APIFeeEstimate sse_decode_api_fee_estimate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_feeRate = sse_decode_f_64(deserializer);
+var var_vbytes = sse_decode_u_64(deserializer);
+var var_feeSat = sse_decode_u_64(deserializer);
+var var_vbytesMax = sse_decode_u_64(deserializer);
+var var_feeSatMax = sse_decode_u_64(deserializer);
+return APIFeeEstimate(feeRate: var_feeRate, vbytes: var_vbytes, feeSat: var_feeSat, vbytesMax: var_vbytesMax, feeSatMax: var_feeSatMax); }
+
+@protected APIHashLock sse_decode_api_hash_lock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hashType = sse_decode_api_hash_type(deserializer);
+var var_digest = sse_decode_String(deserializer);
+return APIHashLock(hashType: var_hashType, digest: var_digest); }
+
+@protected APIHashType sse_decode_api_hash_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return APIHashType.values[inner]; }
+
+@protected APIImportedWallet sse_decode_api_imported_wallet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_analysis = sse_decode_api_analysis_result(deserializer);
+return APIImportedWallet(name: var_name, analysis: var_analysis); }
+
+@protected APIKeychainKind sse_decode_api_keychain_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return APIKeychainKind.values[inner]; }
+
+@protected APINetwork sse_decode_api_network(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return APINetwork.values[inner]; }
+
+@protected APIPolicyPath sse_decode_api_policy_path(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_policyId = sse_decode_String(deserializer);
+var var_path = sse_decode_list_prim_u_32_strict(deserializer);
+return APIPolicyPath(policyId: var_policyId, path: var_path); }
+
+@protected APIPubKey sse_decode_api_pub_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_mfp = sse_decode_String(deserializer);
+var var_derivationPath = sse_decode_String(deserializer);
+var var_xpub = sse_decode_String(deserializer);
+var var_syntheticMfp = sse_decode_bool(deserializer);
+return APIPubKey(mfp: var_mfp, derivationPath: var_derivationPath, xpub: var_xpub, syntheticMfp: var_syntheticMfp); }
+
+@protected APIRelativeTimelock sse_decode_api_relative_timelock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timelockType = sse_decode_api_relative_timelock_type(deserializer);
+var var_value = sse_decode_u_32(deserializer);
+return APIRelativeTimelock(timelockType: var_timelockType, value: var_value); }
+
+@protected APIRelativeTimelockType sse_decode_api_relative_timelock_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return APIRelativeTimelockType.values[inner]; }
+
+@protected APIScriptTypeComparison sse_decode_api_script_type_comparison(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_walletType = sse_decode_api_wallet_type(deserializer);
+var var_supported = sse_decode_bool(deserializer);
+var var_unsupportedReason = sse_decode_opt_String(deserializer);
+var var_descriptor = sse_decode_opt_String(deserializer);
+var var_spendPaths = sse_decode_list_api_spend_path_cost(deserializer);
+return APIScriptTypeComparison(walletType: var_walletType, supported: var_supported, unsupportedReason: var_unsupportedReason, descriptor: var_descriptor, spendPaths: var_spendPaths); }
+
+@protected APISignerGroup sse_decode_api_signer_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_threshold = sse_decode_u_32(deserializer);
+var var_mfps = sse_decode_list_String(deserializer);
+var var_groups = sse_decode_list_api_signer_group(deserializer);
+return APISignerGroup(threshold: var_threshold, mfps: var_mfps, groups: var_groups); }
+
+@protected APISignerSet sse_decode_api_signer_set(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_mfps = sse_decode_list_String(deserializer);
+var var_wuIn = sse_decode_u_32(deserializer);
+return APISignerSet(mfps: var_mfps, wuIn: var_wuIn); }
+
+@protected APISpendPath sse_decode_api_spend_path(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_policyPath = sse_decode_list_api_policy_path(deserializer);
+var var_threshold = sse_decode_u_32(deserializer);
+var var_mfps = sse_decode_list_String(deserializer);
+var var_signerGroups = sse_decode_list_api_signer_group(deserializer);
+var var_relTimelock = sse_decode_api_relative_timelock(deserializer);
+var var_absTimelock = sse_decode_api_absolute_timelock(deserializer);
+var var_relTimelocks = sse_decode_list_api_relative_timelock(deserializer);
+var var_absTimelocks = sse_decode_list_api_absolute_timelock(deserializer);
+var var_timelockConflict = sse_decode_bool(deserializer);
+var var_hashlocks = sse_decode_list_api_hash_lock(deserializer);
+var var_wuBase = sse_decode_u_32(deserializer);
+var var_wuIn = sse_decode_u_32(deserializer);
+var var_wuInMin = sse_decode_u_32(deserializer);
+var var_wuInMax = sse_decode_u_32(deserializer);
+var var_wuOut = sse_decode_u_32(deserializer);
+var var_signerSets = sse_decode_list_api_signer_set(deserializer);
+var var_signerSetsTruncated = sse_decode_bool(deserializer);
+var var_trDepth = sse_decode_i_32(deserializer);
+var var_vbSweep = sse_decode_f_32(deserializer);
+return APISpendPath(id: var_id, policyPath: var_policyPath, threshold: var_threshold, mfps: var_mfps, signerGroups: var_signerGroups, relTimelock: var_relTimelock, absTimelock: var_absTimelock, relTimelocks: var_relTimelocks, absTimelocks: var_absTimelocks, timelockConflict: var_timelockConflict, hashlocks: var_hashlocks, wuBase: var_wuBase, wuIn: var_wuIn, wuInMin: var_wuInMin, wuInMax: var_wuInMax, wuOut: var_wuOut, signerSets: var_signerSets, signerSetsTruncated: var_signerSetsTruncated, trDepth: var_trDepth, vbSweep: var_vbSweep); }
+
+@protected APISpendPathCost sse_decode_api_spend_path_cost(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_spendPathId = sse_decode_u_32(deserializer);
+var var_wuIn = sse_decode_u_32(deserializer);
+var var_wuInMax = sse_decode_u_32(deserializer);
+var var_fees = sse_decode_list_api_fee_estimate(deserializer);
+return APISpendPathCost(spendPathId: var_spendPathId, wuIn: var_wuIn, wuInMax: var_wuInMax, fees: var_fees); }
+
+@protected APISpendPathDef sse_decode_api_spend_path_def(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_threshold = sse_decode_u_32(deserializer);
+var var_mfps = sse_decode_list_String(deserializer);
+var var_relTimelock = sse_decode_api_relative_timelock(deserializer);
+var var_absTimelock = sse_decode_api_absolute_timelock(deserializer);
+var var_isKeyPath = sse_decode_bool(deserializer);
+var var_priority = sse_decode_u_32(deserializer);
+var var_probability = sse_decode_opt_box_autoadd_f_64(deserializer);
+return APISpendPathDef(threshold: var_threshold, mfps: var_mfps, relTimelock: var_relTimelock, absTimelock: var_absTimelock, isKeyPath: var_isKeyPath, priority: var_priority, probability: var_probability); }
+
+@protected APISpendPathDust sse_decode_api_spend_path_dust(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_spendPathId = sse_decode_u_32(deserializer);
+var var_economicDust = sse_decode_list_prim_u_64_strict(deserializer);
+return APISpendPathDust(spendPathId: var_spendPathId, economicDust: var_economicDust); }
+
+@protected APISpendPathLikelihood sse_decode_api_spend_path_likelihood(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_spendPathId = sse_decode_u_32(deserializer);
+var var_likelihood = sse_decode_f_64(deserializer);
+return APISpendPathLikelihood(spendPathId: var_spendPathId, likelihood: var_likelihood); }
+
+@protected APISpendability sse_decode_api_spendability(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return APISpendability.values[inner]; }
+
+@protected APITaprootBuild sse_decode_api_taproot_build(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_descriptor = sse_decode_String(deserializer);
+var var_spendPaths = sse_decode_list_api_taproot_path_cost(deserializer);
+var var_expectedWuIn = sse_decode_f_64(deserializer);
+return APITaprootBuild(descriptor: var_descriptor, spendPaths: var_spendPaths, expectedWuIn: var_expectedWuIn); }
+
+@protected APITaprootPathCost sse_decode_api_taproot_path_cost(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_spendPathId = sse_decode_u_32(deserializer);
+var var_probability = sse_decode_f_64(deserializer);
+var var_trDepth = sse_decode_i_32(deserializer);
+var var_wuIn = sse_decode_u_32(deserializer);
+return APITaprootPathCost(spendPathId: var_spendPathId, probability: var_probability, trDepth: var_trDepth, wuIn: var_wuIn); }
+
+@protected APIWalletType sse_decode_api_wallet_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return APIWalletType.values[inner]; }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected APIAbsoluteTimelock sse_decode_box_autoadd_api_absolute_timelock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_api_absolute_timelock(deserializer)); }
+
+@protected APIAddress sse_decode_box_autoadd_api_address(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_api_address(deserializer)); }
+
+@protected APIAnalysisResult sse_decode_box_autoadd_api_analysis_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_api_analysis_result(deserializer)); }
+
+@protected APIRelativeTimelock sse_decode_box_autoadd_api_relative_timelock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_api_relative_timelock(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected List<SpendPath> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SpendPath>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIAbsoluteTimelock> sse_decode_list_api_absolute_timelock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIAbsoluteTimelock>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_absolute_timelock(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIAddress> sse_decode_list_api_address(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIAddress>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_address(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIBatchAnalysis> sse_decode_list_api_batch_analysis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIBatchAnalysis>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_batch_analysis(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIConsolidationPlan> sse_decode_list_api_consolidation_plan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIConsolidationPlan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_consolidation_plan(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIExpectedCost> sse_decode_list_api_expected_cost(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIExpectedCost>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_expected_cost(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIFeeEstimate> sse_decode_list_api_fee_estimate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIFeeEstimate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_fee_estimate(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIHashLock> sse_decode_list_api_hash_lock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIHashLock>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_hash_lock(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIPolicyPath> sse_decode_list_api_policy_path(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIPolicyPath>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_policy_path(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIPubKey> sse_decode_list_api_pub_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIPubKey>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_pub_key(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIRelativeTimelock> sse_decode_list_api_relative_timelock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIRelativeTimelock>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_relative_timelock(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIScriptTypeComparison> sse_decode_list_api_script_type_comparison(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIScriptTypeComparison>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_script_type_comparison(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APISignerGroup> sse_decode_list_api_signer_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APISignerGroup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_signer_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APISignerSet> sse_decode_list_api_signer_set(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APISignerSet>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_signer_set(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APISpendPath> sse_decode_list_api_spend_path(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APISpendPath>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_spend_path(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APISpendPathCost> sse_decode_list_api_spend_path_cost(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APISpendPathCost>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_spend_path_cost(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APISpendPathDef> sse_decode_list_api_spend_path_def(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APISpendPathDef>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_spend_path_def(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APISpendPathDust> sse_decode_list_api_spend_path_dust(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APISpendPathDust>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_spend_path_dust(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APISpendPathLikelihood> sse_decode_list_api_spend_path_likelihood(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APISpendPathLikelihood>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_spend_path_likelihood(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APITaprootPathCost> sse_decode_list_api_taproot_path_cost(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APITaprootPathCost>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_taproot_path_cost(deserializer)); }
+        return ans_;
+         }
+
+@protected List<APIWalletType> sse_decode_list_api_wallet_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <APIWalletType>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_api_wallet_type(deserializer)); }
+        return ans_;
+         }
+
+@protected List<double> sse_decode_list_prim_f_64_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat64List(len_); }
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat64List(len_); }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected APIAddress? sse_decode_opt_box_autoadd_api_address(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_api_address(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected APIAnalysisResult? sse_decode_opt_box_autoadd_api_analysis_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_api_analysis_result(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SpendPath self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SpendPathImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SpendPath self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SpendPathImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SpendPath self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SpendPathImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_api_absolute_timelock(APIAbsoluteTimelock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_absolute_timelock_type(self.timelockType, serializer);
+sse_encode_u_32(self.value, serializer);
+ }
+
+@protected void sse_encode_api_absolute_timelock_type(APIAbsoluteTimelockType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_api_address(APIAddress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_keychain_kind(self.keychain, serializer);
+sse_encode_u_32(self.index, serializer);
+sse_encode_String(self.address, serializer);
+sse_encode_String(self.scriptPubkey, serializer);
+ }
+
+@protected void sse_encode_api_analysis_result(APIAnalysisResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.descriptor, serializer);
+sse_encode_api_network(self.network, serializer);
+sse_encode_api_wallet_type(self.walletType, serializer);
+sse_encode_list_api_pub_key(self.keys, serializer);
+sse_encode_list_api_spend_path(self.spendPaths, serializer);
+sse_encode_api_spendability(self.spendability, serializer);
+ }
+
+@protected void sse_encode_api_batch_analysis(APIBatchAnalysis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.descriptor, serializer);
+sse_encode_opt_box_autoadd_api_analysis_result(self.result, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_api_consolidation_plan(APIConsolidationPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.spendPathId, serializer);
+sse_encode_u_32(self.maxInputsPerTx, serializer);
+sse_encode_u_32(self.transactions, serializer);
+sse_encode_list_api_fee_estimate(self.fees, serializer);
+ }
+
+@protected void sse_encode_api_dust_analysis(APIDustAnalysis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_64(self.dustLimit, serializer);
+sse_encode_list_api_spend_path_dust(self.spendPaths, serializer);
+ }
+
+@protected void sse_encode_api_expected_cost(APIExpectedCost self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.feeRate, serializer);
+sse_encode_f_64(self.vbytes, serializer);
+sse_encode_f_64(self.feeSat, serializer);
+sse_encode_f_64(self.vbytesMax, serializer);
+sse_encode_f_64(self.feeSatMax, serializer);
+ }
+
+@protected void sse_encode_api_fee_estimate(APIFeeEstimate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.feeRate, serializer);
+sse_encode_u_64(self.vbytes, serializer);
+sse_encode_u_64(self.feeSat, serializer);
+sse_encode_u_64(self.vbytesMax, serializer);
+sse_encode_u_64(self.feeSatMax, serializer);
+ }
+
+@protected void sse_encode_api_hash_lock(APIHashLock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_hash_type(self.hashType, serializer);
+sse_encode_String(self.digest, serializer);
+ }
+
+@protected void sse_encode_api_hash_type(APIHashType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_api_imported_wallet(APIImportedWallet self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_api_analysis_result(self.analysis, serializer);
+ }
+
+@protected void sse_encode_api_keychain_kind(APIKeychainKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_api_network(APINetwork self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_api_policy_path(APIPolicyPath self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.policyId, serializer);
+sse_encode_list_prim_u_32_strict(self.path, serializer);
+ }
+
+@protected void sse_encode_api_pub_key(APIPubKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.mfp, serializer);
+sse_encode_String(self.derivationPath, serializer);
+sse_encode_String(self.xpub, serializer);
+sse_encode_bool(self.syntheticMfp, serializer);
+ }
+
+@protected void sse_encode_api_relative_timelock(APIRelativeTimelock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_relative_timelock_type(self.timelockType, serializer);
+sse_encode_u_32(self.value, serializer);
+ }
+
+@protected void sse_encode_api_relative_timelock_type(APIRelativeTimelockType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_api_script_type_comparison(APIScriptTypeComparison self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_wallet_type(self.walletType, serializer);
+sse_encode_bool(self.supported, serializer);
+sse_encode_opt_String(self.unsupportedReason, serializer);
+sse_encode_opt_String(self.descriptor, serializer);
+sse_encode_list_api_spend_path_cost(self.spendPaths, serializer);
+ }
+
+@protected void sse_encode_api_signer_group(APISignerGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.threshold, serializer);
+sse_encode_list_String(self.mfps, serializer);
+sse_encode_list_api_signer_group(self.groups, serializer);
+ }
+
+@protected void sse_encode_api_signer_set(APISignerSet self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.mfps, serializer);
+sse_encode_u_32(self.wuIn, serializer);
+ }
+
+@protected void sse_encode_api_spend_path(APISpendPath self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_list_api_policy_path(self.policyPath, serializer);
+sse_encode_u_32(self.threshold, serializer);
+sse_encode_list_String(self.mfps, serializer);
+sse_encode_list_api_signer_group(self.signerGroups, serializer);
+sse_encode_api_relative_timelock(self.relTimelock, serializer);
+sse_encode_api_absolute_timelock(self.absTimelock, serializer);
+sse_encode_list_api_relative_timelock(self.relTimelocks, serializer);
+sse_encode_list_api_absolute_timelock(self.absTimelocks, serializer);
+sse_encode_bool(self.timelockConflict, serializer);
+sse_encode_list_api_hash_lock(self.hashlocks, serializer);
+sse_encode_u_32(self.wuBase, serializer);
+sse_encode_u_32(self.wuIn, serializer);
+sse_encode_u_32(self.wuInMin, serializer);
+sse_encode_u_32(self.wuInMax, serializer);
+sse_encode_u_32(self.wuOut, serializer);
+sse_encode_list_api_signer_set(self.signerSets, serializer);
+sse_encode_bool(self.signerSetsTruncated, serializer);
+sse_encode_i_32(self.trDepth, serializer);
+sse_encode_f_32(self.vbSweep, serializer);
+ }
+
+@protected void sse_encode_api_spend_path_cost(APISpendPathCost self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.spendPathId, serializer);
+sse_encode_u_32(self.wuIn, serializer);
+sse_encode_u_32(self.wuInMax, serializer);
+sse_encode_list_api_fee_estimate(self.fees, serializer);
+ }
+
+@protected void sse_encode_api_spend_path_def(APISpendPathDef self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.threshold, serializer);
+sse_encode_list_String(self.mfps, serializer);
+sse_encode_api_relative_timelock(self.relTimelock, serializer);
+sse_encode_api_absolute_timelock(self.absTimelock, serializer);
+sse_encode_bool(self.isKeyPath, serializer);
+sse_encode_u_32(self.priority, serializer);
+sse_encode_opt_box_autoadd_f_64(self.probability, serializer);
+ }
+
+@protected void sse_encode_api_spend_path_dust(APISpendPathDust self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.spendPathId, serializer);
+sse_encode_list_prim_u_64_strict(self.economicDust, serializer);
+ }
+
+@protected void sse_encode_api_spend_path_likelihood(APISpendPathLikelihood self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.spendPathId, serializer);
+sse_encode_f_64(self.likelihood, serializer);
+ }
+
+@protected void sse_encode_api_spendability(APISpendability self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_api_taproot_build(APITaprootBuild self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.descriptor, serializer);
+sse_encode_list_api_taproot_path_cost(self.spendPaths, serializer);
+sse_encode_f_64(self.expectedWuIn, serializer);
+ }
+
+@protected void sse_encode_api_taproot_path_cost(APITaprootPathCost self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.spendPathId, serializer);
+sse_encode_f_64(self.probability, serializer);
+sse_encode_i_32(self.trDepth, serializer);
+sse_encode_u_32(self.wuIn, serializer);
+ }
+
+@protected void sse_encode_api_wallet_type(APIWalletType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_api_absolute_timelock(APIAbsoluteTimelock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_absolute_timelock(self, serializer); }
+
+@protected void sse_encode_box_autoadd_api_address(APIAddress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_address(self, serializer); }
+
+@protected void sse_encode_box_autoadd_api_analysis_result(APIAnalysisResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_analysis_result(self, serializer); }
+
+@protected void sse_encode_box_autoadd_api_relative_timelock(APIRelativeTimelock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_api_relative_timelock(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(List<SpendPath> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(item, serializer); } }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_api_absolute_timelock(List<APIAbsoluteTimelock> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_absolute_timelock(item, serializer); } }
+
+@protected void sse_encode_list_api_address(List<APIAddress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_address(item, serializer); } }
+
+@protected void sse_encode_list_api_batch_analysis(List<APIBatchAnalysis> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_batch_analysis(item, serializer); } }
+
+@protected void sse_encode_list_api_consolidation_plan(List<APIConsolidationPlan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_consolidation_plan(item, serializer); } }
+
+@protected void sse_encode_list_api_expected_cost(List<APIExpectedCost> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_expected_cost(item, serializer); } }
+
+@protected void sse_encode_list_api_fee_estimate(List<APIFeeEstimate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_fee_estimate(item, serializer); } }
+
+@protected void sse_encode_list_api_hash_lock(List<APIHashLock> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_hash_lock(item, serializer); } }
+
+@protected void sse_encode_list_api_policy_path(List<APIPolicyPath> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_policy_path(item, serializer); } }
+
+@protected void sse_encode_list_api_pub_key(List<APIPubKey> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_pub_key(item, serializer); } }
+
+@protected void sse_encode_list_api_relative_timelock(List<APIRelativeTimelock> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_relative_timelock(item, serializer); } }
+
+@protected void sse_encode_list_api_script_type_comparison(List<APIScriptTypeComparison> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_script_type_comparison(item, serializer); } }
+
+@protected void sse_encode_list_api_signer_group(List<APISignerGroup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_signer_group(item, serializer); } }
+
+@protected void sse_encode_list_api_signer_set(List<APISignerSet> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_signer_set(item, serializer); } }
+
+@protected void sse_encode_list_api_spend_path(List<APISpendPath> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_spend_path(item, serializer); } }
+
+@protected void sse_encode_list_api_spend_path_cost(List<APISpendPathCost> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_spend_path_cost(item, serializer); } }
+
+@protected void sse_encode_list_api_spend_path_def(List<APISpendPathDef> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_spend_path_def(item, serializer); } }
+
+@protected void sse_encode_list_api_spend_path_dust(List<APISpendPathDust> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_spend_path_dust(item, serializer); } }
+
+@protected void sse_encode_list_api_spend_path_likelihood(List<APISpendPathLikelihood> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_spend_path_likelihood(item, serializer); } }
+
+@protected void sse_encode_list_api_taproot_path_cost(List<APITaprootPathCost> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_taproot_path_cost(item, serializer); } }
+
+@protected void sse_encode_list_api_wallet_type(List<APIWalletType> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_api_wallet_type(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_64_loose(List<double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat64List(self is Float64List ? self : Float64List.fromList(self)); }
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat64List(self); }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_api_address(APIAddress? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_api_address(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_api_analysis_result(APIAnalysisResult? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_api_analysis_result(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
+
+            @sealed class SpendPathImpl extends RustOpaque implements SpendPath {
+                // Not to be used by end users
+                SpendPathImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SpendPathImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SpendPath,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SpendPath,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SpendPathPtr,
+                );
+
+                
+            }
\ No newline at end of file
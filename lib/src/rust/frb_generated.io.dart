@@ -12,466 +12,554 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_SpendPathPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr;
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  SpendPath
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    dynamic raw,
-  );
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  SpendPath
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    dynamic raw,
-  );
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SpendPathPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr;
 
-  @protected
-  SpendPath
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    dynamic raw,
-  );
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  APIAbsoluteTimelock dco_decode_api_absolute_timelock(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  APIAbsoluteTimelockType dco_decode_api_absolute_timelock_type(dynamic raw);
+@protected SpendPath dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(dynamic raw);
 
-  @protected
-  APIAnalysisResult dco_decode_api_analysis_result(dynamic raw);
+@protected SpendPath dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(dynamic raw);
 
-  @protected
-  APINetwork dco_decode_api_network(dynamic raw);
+@protected SpendPath dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(dynamic raw);
 
-  @protected
-  APIPolicyPath dco_decode_api_policy_path(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  APIPubKey dco_decode_api_pub_key(dynamic raw);
+@protected APIAbsoluteTimelock dco_decode_api_absolute_timelock(dynamic raw);
 
-  @protected
-  APIRelativeTimelock dco_decode_api_relative_timelock(dynamic raw);
+@protected APIAbsoluteTimelockType dco_decode_api_absolute_timelock_type(dynamic raw);
 
-  @protected
-  APIRelativeTimelockType dco_decode_api_relative_timelock_type(dynamic raw);
+@protected APIAddress dco_decode_api_address(dynamic raw);
 
-  @protected
-  APISpendPath dco_decode_api_spend_path(dynamic raw);
+@protected APIAnalysisResult dco_decode_api_analysis_result(dynamic raw);
 
-  @protected
-  APISpendPathDef dco_decode_api_spend_path_def(dynamic raw);
+@protected APIBatchAnalysis dco_decode_api_batch_analysis(dynamic raw);
 
-  @protected
-  APIWalletType dco_decode_api_wallet_type(dynamic raw);
+@protected APIConsolidationPlan dco_decode_api_consolidation_plan(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected APIDustAnalysis dco_decode_api_dust_analysis(dynamic raw);
 
-  @protected
-  APIAbsoluteTimelock dco_decode_box_autoadd_api_absolute_timelock(dynamic raw);
+@protected APIExpectedCost dco_decode_api_expected_cost(dynamic raw);
 
-  @protected
-  APIRelativeTimelock dco_decode_box_autoadd_api_relative_timelock(dynamic raw);
+@protected APIFeeEstimate dco_decode_api_fee_estimate(dynamic raw);
 
-  @protected
-  double dco_decode_f_32(dynamic raw);
+@protected APIHashLock dco_decode_api_hash_lock(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected APIHashType dco_decode_api_hash_type(dynamic raw);
 
-  @protected
-  List<SpendPath>
-  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    dynamic raw,
-  );
+@protected APIImportedWallet dco_decode_api_imported_wallet(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected APIKeychainKind dco_decode_api_keychain_kind(dynamic raw);
 
-  @protected
-  List<APIPolicyPath> dco_decode_list_api_policy_path(dynamic raw);
+@protected APINetwork dco_decode_api_network(dynamic raw);
 
-  @protected
-  List<APIPubKey> dco_decode_list_api_pub_key(dynamic raw);
+@protected APIPolicyPath dco_decode_api_policy_path(dynamic raw);
 
-  @protected
-  List<APISpendPath> dco_decode_list_api_spend_path(dynamic raw);
+@protected APIPubKey dco_decode_api_pub_key(dynamic raw);
 
-  @protected
-  List<APISpendPathDef> dco_decode_list_api_spend_path_def(dynamic raw);
+@protected APIRelativeTimelock dco_decode_api_relative_timelock(dynamic raw);
 
-  @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+@protected APIRelativeTimelockType dco_decode_api_relative_timelock_type(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected APIScriptTypeComparison dco_decode_api_script_type_comparison(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected APISignerGroup dco_decode_api_signer_group(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected APISignerSet dco_decode_api_signer_set(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected APISpendPath dco_decode_api_spend_path(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected APISpendPathCost dco_decode_api_spend_path_cost(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected APISpendPathDef dco_decode_api_spend_path_def(dynamic raw);
 
-  @protected
-  SpendPath
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SseDeserializer deserializer,
-  );
+@protected APISpendPathDust dco_decode_api_spend_path_dust(dynamic raw);
 
-  @protected
-  SpendPath
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SseDeserializer deserializer,
-  );
+@protected APISpendPathLikelihood dco_decode_api_spend_path_likelihood(dynamic raw);
 
-  @protected
-  SpendPath
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SseDeserializer deserializer,
-  );
+@protected APISpendability dco_decode_api_spendability(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected APITaprootBuild dco_decode_api_taproot_build(dynamic raw);
 
-  @protected
-  APIAbsoluteTimelock sse_decode_api_absolute_timelock(
-    SseDeserializer deserializer,
-  );
+@protected APITaprootPathCost dco_decode_api_taproot_path_cost(dynamic raw);
 
-  @protected
-  APIAbsoluteTimelockType sse_decode_api_absolute_timelock_type(
-    SseDeserializer deserializer,
-  );
+@protected APIWalletType dco_decode_api_wallet_type(dynamic raw);
 
-  @protected
-  APIAnalysisResult sse_decode_api_analysis_result(
-    SseDeserializer deserializer,
-  );
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  APINetwork sse_decode_api_network(SseDeserializer deserializer);
+@protected APIAbsoluteTimelock dco_decode_box_autoadd_api_absolute_timelock(dynamic raw);
 
-  @protected
-  APIPolicyPath sse_decode_api_policy_path(SseDeserializer deserializer);
+@protected APIAddress dco_decode_box_autoadd_api_address(dynamic raw);
 
-  @protected
-  APIPubKey sse_decode_api_pub_key(SseDeserializer deserializer);
+@protected APIAnalysisResult dco_decode_box_autoadd_api_analysis_result(dynamic raw);
 
-  @protected
-  APIRelativeTimelock sse_decode_api_relative_timelock(
-    SseDeserializer deserializer,
-  );
+@protected APIRelativeTimelock dco_decode_box_autoadd_api_relative_timelock(dynamic raw);
 
-  @protected
-  APIRelativeTimelockType sse_decode_api_relative_timelock_type(
-    SseDeserializer deserializer,
-  );
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  APISpendPath sse_decode_api_spend_path(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  APISpendPathDef sse_decode_api_spend_path_def(SseDeserializer deserializer);
+@protected double dco_decode_f_32(dynamic raw);
 
-  @protected
-  APIWalletType sse_decode_api_wallet_type(SseDeserializer deserializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected List<SpendPath> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<APIAbsoluteTimelock> dco_decode_list_api_absolute_timelock(dynamic raw);
+
+@protected List<APIAddress> dco_decode_list_api_address(dynamic raw);
+
+@protected List<APIBatchAnalysis> dco_decode_list_api_batch_analysis(dynamic raw);
+
+@protected List<APIConsolidationPlan> dco_decode_list_api_consolidation_plan(dynamic raw);
+
+@protected List<APIExpectedCost> dco_decode_list_api_expected_cost(dynamic raw);
+
+@protected List<APIFeeEstimate> dco_decode_list_api_fee_estimate(dynamic raw);
+
+@protected List<APIHashLock> dco_decode_list_api_hash_lock(dynamic raw);
+
+@protected List<APIPolicyPath> dco_decode_list_api_policy_path(dynamic raw);
+
+@protected List<APIPubKey> dco_decode_list_api_pub_key(dynamic raw);
+
+@protected List<APIRelativeTimelock> dco_decode_list_api_relative_timelock(dynamic raw);
+
+@protected List<APIScriptTypeComparison> dco_decode_list_api_script_type_comparison(dynamic raw);
+
+@protected List<APISignerGroup> dco_decode_list_api_signer_group(dynamic raw);
+
+@protected List<APISignerSet> dco_decode_list_api_signer_set(dynamic raw);
+
+@protected List<APISpendPath> dco_decode_list_api_spend_path(dynamic raw);
+
+@protected List<APISpendPathCost> dco_decode_list_api_spend_path_cost(dynamic raw);
+
+@protected List<APISpendPathDef> dco_decode_list_api_spend_path_def(dynamic raw);
+
+@protected List<APISpendPathDust> dco_decode_list_api_spend_path_dust(dynamic raw);
+
+@protected List<APISpendPathLikelihood> dco_decode_list_api_spend_path_likelihood(dynamic raw);
+
+@protected List<APITaprootPathCost> dco_decode_list_api_taproot_path_cost(dynamic raw);
+
+@protected List<APIWalletType> dco_decode_list_api_wallet_type(dynamic raw);
+
+@protected List<double> dco_decode_list_prim_f_64_loose(dynamic raw);
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected APIAddress? dco_decode_opt_box_autoadd_api_address(dynamic raw);
+
+@protected APIAnalysisResult? dco_decode_opt_box_autoadd_api_analysis_result(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected SpendPath sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SseDeserializer deserializer);
+
+@protected SpendPath sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SseDeserializer deserializer);
+
+@protected SpendPath sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected APIAbsoluteTimelock sse_decode_api_absolute_timelock(SseDeserializer deserializer);
+
+@protected APIAbsoluteTimelockType sse_decode_api_absolute_timelock_type(SseDeserializer deserializer);
+
+@protected APIAddress sse_decode_api_address(SseDeserializer deserializer);
+
+@protected APIAnalysisResult sse_decode_api_analysis_result(SseDeserializer deserializer);
+
+@protected APIBatchAnalysis sse_decode_api_batch_analysis(SseDeserializer deserializer);
+
+@protected APIConsolidationPlan sse_decode_api_consolidation_plan(SseDeserializer deserializer);
+
+@protected APIDustAnalysis sse_decode_api_dust_analysis(SseDeserializer deserializer);
+
+@protected APIExpectedCost sse_decode_api_expected_cost(SseDeserializer deserializer);
+
+@protected APIFeeEstimate sse_decode_api_fee_estimate(SseDeserializer deserializer);
+
+@protected APIHashLock sse_decode_api_hash_lock(SseDeserializer deserializer);
+
+@protected APIHashType sse_decode_api_hash_type(SseDeserializer deserializer);
+
+@protected APIImportedWallet sse_decode_api_imported_wallet(SseDeserializer deserializer);
+
+@protected APIKeychainKind sse_decode_api_keychain_kind(SseDeserializer deserializer);
+
+@protected APINetwork sse_decode_api_network(SseDeserializer deserializer);
+
+@protected APIPolicyPath sse_decode_api_policy_path(SseDeserializer deserializer);
+
+@protected APIPubKey sse_decode_api_pub_key(SseDeserializer deserializer);
+
+@protected APIRelativeTimelock sse_decode_api_relative_timelock(SseDeserializer deserializer);
+
+@protected APIRelativeTimelockType sse_decode_api_relative_timelock_type(SseDeserializer deserializer);
+
+@protected APIScriptTypeComparison sse_decode_api_script_type_comparison(SseDeserializer deserializer);
+
+@protected APISignerGroup sse_decode_api_signer_group(SseDeserializer deserializer);
+
+@protected APISignerSet sse_decode_api_signer_set(SseDeserializer deserializer);
+
+@protected APISpendPath sse_decode_api_spend_path(SseDeserializer deserializer);
+
+@protected APISpendPathCost sse_decode_api_spend_path_cost(SseDeserializer deserializer);
+
+@protected APISpendPathDef sse_decode_api_spend_path_def(SseDeserializer deserializer);
+
+@protected APISpendPathDust sse_decode_api_spend_path_dust(SseDeserializer deserializer);
+
+@protected APISpendPathLikelihood sse_decode_api_spend_path_likelihood(SseDeserializer deserializer);
+
+@protected APISpendability sse_decode_api_spendability(SseDeserializer deserializer);
+
+@protected APITaprootBuild sse_decode_api_taproot_build(SseDeserializer deserializer);
+
+@protected APITaprootPathCost sse_decode_api_taproot_path_cost(SseDeserializer deserializer);
+
+@protected APIWalletType sse_decode_api_wallet_type(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected APIAbsoluteTimelock sse_decode_box_autoadd_api_absolute_timelock(SseDeserializer deserializer);
+
+@protected APIAddress sse_decode_box_autoadd_api_address(SseDeserializer deserializer);
+
+@protected APIAnalysisResult sse_decode_box_autoadd_api_analysis_result(SseDeserializer deserializer);
+
+@protected APIRelativeTimelock sse_decode_box_autoadd_api_relative_timelock(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected List<SpendPath> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<APIAbsoluteTimelock> sse_decode_list_api_absolute_timelock(SseDeserializer deserializer);
+
+@protected List<APIAddress> sse_decode_list_api_address(SseDeserializer deserializer);
+
+@protected List<APIBatchAnalysis> sse_decode_list_api_batch_analysis(SseDeserializer deserializer);
+
+@protected List<APIConsolidationPlan> sse_decode_list_api_consolidation_plan(SseDeserializer deserializer);
+
+@protected List<APIExpectedCost> sse_decode_list_api_expected_cost(SseDeserializer deserializer);
+
+@protected List<APIFeeEstimate> sse_decode_list_api_fee_estimate(SseDeserializer deserializer);
+
+@protected List<APIHashLock> sse_decode_list_api_hash_lock(SseDeserializer deserializer);
+
+@protected List<APIPolicyPath> sse_decode_list_api_policy_path(SseDeserializer deserializer);
+
+@protected List<APIPubKey> sse_decode_list_api_pub_key(SseDeserializer deserializer);
+
+@protected List<APIRelativeTimelock> sse_decode_list_api_relative_timelock(SseDeserializer deserializer);
+
+@protected List<APIScriptTypeComparison> sse_decode_list_api_script_type_comparison(SseDeserializer deserializer);
+
+@protected List<APISignerGroup> sse_decode_list_api_signer_group(SseDeserializer deserializer);
+
+@protected List<APISignerSet> sse_decode_list_api_signer_set(SseDeserializer deserializer);
+
+@protected List<APISpendPath> sse_decode_list_api_spend_path(SseDeserializer deserializer);
+
+@protected List<APISpendPathCost> sse_decode_list_api_spend_path_cost(SseDeserializer deserializer);
+
+@protected List<APISpendPathDef> sse_decode_list_api_spend_path_def(SseDeserializer deserializer);
+
+@protected List<APISpendPathDust> sse_decode_list_api_spend_path_dust(SseDeserializer deserializer);
+
+@protected List<APISpendPathLikelihood> sse_decode_list_api_spend_path_likelihood(SseDeserializer deserializer);
+
+@protected List<APITaprootPathCost> sse_decode_list_api_taproot_path_cost(SseDeserializer deserializer);
+
+@protected List<APIWalletType> sse_decode_list_api_wallet_type(SseDeserializer deserializer);
+
+@protected List<double> sse_decode_list_prim_f_64_loose(SseDeserializer deserializer);
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected APIAddress? sse_decode_opt_box_autoadd_api_address(SseDeserializer deserializer);
+
+@protected APIAnalysisResult? sse_decode_opt_box_autoadd_api_analysis_result(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SpendPath self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SpendPath self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(SpendPath self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_api_absolute_timelock(APIAbsoluteTimelock self, SseSerializer serializer);
+
+@protected void sse_encode_api_absolute_timelock_type(APIAbsoluteTimelockType self, SseSerializer serializer);
+
+@protected void sse_encode_api_address(APIAddress self, SseSerializer serializer);
+
+@protected void sse_encode_api_analysis_result(APIAnalysisResult self, SseSerializer serializer);
+
+@protected void sse_encode_api_batch_analysis(APIBatchAnalysis self, SseSerializer serializer);
+
+@protected void sse_encode_api_consolidation_plan(APIConsolidationPlan self, SseSerializer serializer);
+
+@protected void sse_encode_api_dust_analysis(APIDustAnalysis self, SseSerializer serializer);
+
+@protected void sse_encode_api_expected_cost(APIExpectedCost self, SseSerializer serializer);
+
+@protected void sse_encode_api_fee_estimate(APIFeeEstimate self, SseSerializer serializer);
+
+@protected void sse_encode_api_hash_lock(APIHashLock self, SseSerializer serializer);
+
+@protected void sse_encode_api_hash_type(APIHashType self, SseSerializer serializer);
+
+@protected void sse_encode_api_imported_wallet(APIImportedWallet self, SseSerializer serializer);
+
+@protected void sse_encode_api_keychain_kind(APIKeychainKind self, SseSerializer serializer);
+
+@protected void sse_encode_api_network(APINetwork self, SseSerializer serializer);
+
+@protected void sse_encode_api_policy_path(APIPolicyPath self, SseSerializer serializer);
+
+@protected void sse_encode_api_pub_key(APIPubKey self, SseSerializer serializer);
+
+@protected void sse_encode_api_relative_timelock(APIRelativeTimelock self, SseSerializer serializer);
+
+@protected void sse_encode_api_relative_timelock_type(APIRelativeTimelockType self, SseSerializer serializer);
+
+@protected void sse_encode_api_script_type_comparison(APIScriptTypeComparison self, SseSerializer serializer);
+
+@protected void sse_encode_api_signer_group(APISignerGroup self, SseSerializer serializer);
+
+@protected void sse_encode_api_signer_set(APISignerSet self, SseSerializer serializer);
+
+@protected void sse_encode_api_spend_path(APISpendPath self, SseSerializer serializer);
+
+@protected void sse_encode_api_spend_path_cost(APISpendPathCost self, SseSerializer serializer);
+
+@protected void sse_encode_api_spend_path_def(APISpendPathDef self, SseSerializer serializer);
+
+@protected void sse_encode_api_spend_path_dust(APISpendPathDust self, SseSerializer serializer);
+
+@protected void sse_encode_api_spend_path_likelihood(APISpendPathLikelihood self, SseSerializer serializer);
+
+@protected void sse_encode_api_spendability(APISpendability self, SseSerializer serializer);
+
+@protected void sse_encode_api_taproot_build(APITaprootBuild self, SseSerializer serializer);
+
+@protected void sse_encode_api_taproot_path_cost(APITaprootPathCost self, SseSerializer serializer);
+
+@protected void sse_encode_api_wallet_type(APIWalletType self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_api_absolute_timelock(APIAbsoluteTimelock self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_api_address(APIAddress self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_api_analysis_result(APIAnalysisResult self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_api_relative_timelock(APIRelativeTimelock self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(List<SpendPath> self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_absolute_timelock(List<APIAbsoluteTimelock> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_address(List<APIAddress> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_batch_analysis(List<APIBatchAnalysis> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_consolidation_plan(List<APIConsolidationPlan> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_expected_cost(List<APIExpectedCost> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_fee_estimate(List<APIFeeEstimate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_hash_lock(List<APIHashLock> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_policy_path(List<APIPolicyPath> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_pub_key(List<APIPubKey> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_relative_timelock(List<APIRelativeTimelock> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_script_type_comparison(List<APIScriptTypeComparison> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_signer_group(List<APISignerGroup> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_signer_set(List<APISignerSet> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_spend_path(List<APISpendPath> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_spend_path_cost(List<APISpendPathCost> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_spend_path_def(List<APISpendPathDef> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_spend_path_dust(List<APISpendPathDust> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_spend_path_likelihood(List<APISpendPathLikelihood> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_taproot_path_cost(List<APITaprootPathCost> self, SseSerializer serializer);
+
+@protected void sse_encode_list_api_wallet_type(List<APIWalletType> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_64_loose(List<double> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_api_address(APIAddress? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_api_analysis_result(APIAnalysisResult? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  APIAbsoluteTimelock sse_decode_box_autoadd_api_absolute_timelock(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  APIRelativeTimelock sse_decode_box_autoadd_api_relative_timelock(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
-  @protected
-  List<SpendPath>
-  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
-
-  @protected
-  List<APIPolicyPath> sse_decode_list_api_policy_path(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<APIPubKey> sse_decode_list_api_pub_key(SseDeserializer deserializer);
-
-  @protected
-  List<APISpendPath> sse_decode_list_api_spend_path(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<APISpendPathDef> sse_decode_list_api_spend_path_def(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SpendPath self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SpendPath self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    SpendPath self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_api_absolute_timelock(
-    APIAbsoluteTimelock self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_api_absolute_timelock_type(
-    APIAbsoluteTimelockType self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_api_analysis_result(
-    APIAnalysisResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_api_network(APINetwork self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_api_policy_path(APIPolicyPath self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_api_pub_key(APIPubKey self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_api_relative_timelock(
-    APIRelativeTimelock self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_api_relative_timelock_type(
-    APIRelativeTimelockType self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_api_spend_path(APISpendPath self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_api_spend_path_def(
-    APISpendPathDef self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_api_wallet_type(APIWalletType self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_api_absolute_timelock(
-    APIAbsoluteTimelock self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_api_relative_timelock(
-    APIRelativeTimelock self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    List<SpendPath> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_api_policy_path(
-    List<APIPolicyPath> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_api_pub_key(
-    List<APIPubKey> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_api_spend_path(
-    List<APISpendPath> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_api_spend_path_def(
-    List<APISpendPathDef> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
-
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
-
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_deadbolt_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_deadbolt_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-}
+
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_deadbolt_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_deadbolt_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPath = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpendPathPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
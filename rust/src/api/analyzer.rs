@@ -1,48 +1,445 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{LazyLock, Mutex};
+
 use anyhow::Result;
+use bdk_wallet::miniscript::descriptor::checksum::desc_checksum;
 use flutter_rust_bridge::frb;
 
 use crate::api::model::{
-    APIAbsoluteTimelock, APINetwork, APIPubKey, APIRelativeTimelock, APISpendPath, APISpendPathDef,
-    APIWalletType,
+    APIAbsoluteTimelock, APIAddress, APIConsolidationPlan, APIDustAnalysis, APIExpectedCost,
+    APIFeeEstimate, APIKeychainKind, APINetwork, APIPubKey, APIRelativeTimelock,
+    APIScriptTypeComparison, APISpendPath, APISpendPathCost, APISpendPathDef, APISpendPathDust,
+    APISpendPathLikelihood, APISpendability, APITaprootBuild, APIWalletType,
 };
+use crate::core::bsms::{BsmsKeyRecord, BsmsRecord, BsmsToken};
+use crate::core::caravan::CaravanWallet;
+use crate::core::coldcard::ColdcardMultisig;
 use crate::core::descriptor::DescriptorAnalyzer;
-use crate::core::descriptor_builder::{self, SpendPathDef};
+use crate::core::descriptor_builder::{self, SpendPathDef, WalletTypeBuild};
+use crate::core::electrum::ElectrumWallet;
+use crate::core::error::WalletError;
 use crate::core::pubkey::PubKey;
-use crate::core::spend_path;
+use crate::core::spend_path::{self, SpendPath};
+use crate::core::wallet::WalletType;
 
+#[derive(Clone)]
 pub struct APIAnalysisResult {
     pub descriptor: String,
     pub network: APINetwork,
     pub wallet_type: APIWalletType,
     pub keys: Vec<APIPubKey>,
     pub spend_paths: Vec<APISpendPath>,
+    pub spendability: APISpendability,
 }
 
-pub fn analyze_descriptor(descriptor: String) -> Result<APIAnalysisResult> {
-    let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+/// Outcome of analyzing one descriptor of a batch
+#[derive(Clone)]
+pub struct APIBatchAnalysis {
+    pub descriptor: String,
+    pub result: Option<APIAnalysisResult>,
+    pub error: Option<String>,
+}
+
+/// Wallet imported from another wallet's setup file
+#[derive(Clone)]
+pub struct APIImportedWallet {
+    /// Name given in the file, if any
+    pub name: Option<String>,
+    pub analysis: APIAnalysisResult,
+}
+
+/// Analyses of single descriptors by checksum, along with the descriptor
+/// they were made for
+static ANALYSIS_CACHE: LazyLock<Mutex<HashMap<String, (String, APIAnalysisResult)>>> =
+    LazyLock::new(Default::default);
+
+/// Cached analyses kept before the cache starts over
+const ANALYSIS_CACHE_SIZE: usize = 1024;
 
+/// Analyze a descriptor, or a receive/change pair when `change_descriptor` is set
+///
+/// A pair is reported as one wallet, with both halves merged into a single
+/// multipath (`<0;1>`) descriptor. Single descriptors are cached by checksum.
+pub fn analyze_descriptor(
+    descriptor: String,
+    change_descriptor: Option<String>,
+) -> Result<APIAnalysisResult> {
+    match &change_descriptor {
+        Some(change_descriptor) => analysis_result(DescriptorAnalyzer::analyze_pair(
+            &descriptor,
+            change_descriptor,
+        )?),
+        None => analyze_cached(&descriptor),
+    }
+}
+
+/// Analyze many descriptors in parallel
+///
+/// Results are in the same order as `descriptors`. A descriptor that fails
+/// has its error reported instead of failing the batch.
+pub fn analyze_descriptors(descriptors: Vec<String>) -> Result<Vec<APIBatchAnalysis>> {
+    let threads = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunk_size = descriptors.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = descriptors
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|descriptor| match analyze_cached(descriptor) {
+                            Ok(result) => APIBatchAnalysis {
+                                descriptor: descriptor.clone(),
+                                result: Some(result),
+                                error: None,
+                            },
+                            Err(e) => APIBatchAnalysis {
+                                descriptor: descriptor.clone(),
+                                result: None,
+                                error: Some(e.to_string()),
+                            },
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut analyses = Vec::with_capacity(descriptors.len());
+        for handle in handles {
+            analyses.extend(handle.join().map_err(|_| WalletError::UnexpectedError)?);
+        }
+        Ok(analyses)
+    })
+}
+
+/// Drop every cached analysis
+pub fn clear_analysis_cache() {
+    if let Ok(mut cache) = ANALYSIS_CACHE.lock() {
+        cache.clear();
+    }
+}
+
+/// Analyze a single descriptor, reusing a previous analysis when the cache
+/// has one for the same checksum
+///
+/// Descriptors with a wrong checksum always go through the analyzer, which
+/// rejects them.
+fn analyze_cached(descriptor: &str) -> Result<APIAnalysisResult> {
+    let (body, given_checksum) = match descriptor.trim().split_once('#') {
+        Some((body, checksum)) => (body, Some(checksum)),
+        None => (descriptor.trim(), None),
+    };
+    let checksum = desc_checksum(body)
+        .ok()
+        .filter(|checksum| given_checksum.is_none_or(|given| given == checksum));
+    let Some(checksum) = checksum else {
+        return analysis_result(DescriptorAnalyzer::analyze(descriptor)?);
+    };
+
+    if let Ok(cache) = ANALYSIS_CACHE.lock() {
+        if let Some((cached_body, result)) = cache.get(&checksum) {
+            if cached_body == body {
+                return Ok(APIAnalysisResult {
+                    descriptor: descriptor.to_owned(),
+                    ..result.clone()
+                });
+            }
+        }
+    }
+
+    let result = analysis_result(DescriptorAnalyzer::analyze(descriptor)?)?;
+    if let Ok(mut cache) = ANALYSIS_CACHE.lock() {
+        if cache.len() >= ANALYSIS_CACHE_SIZE {
+            cache.clear();
+        }
+        cache.insert(checksum, (body.to_owned(), result.clone()));
+    }
+    Ok(result)
+}
+
+fn analysis_result(analyzer: DescriptorAnalyzer) -> Result<APIAnalysisResult> {
     let keys: Vec<APIPubKey> = analyzer
         .public_keys()?
         .iter()
-        .map(|k| APIPubKey {
-            mfp: k.mfp().to_string(),
-            derivation_path: k
-                .derivation_path()
-                .map(|dp| dp.to_string())
-                .unwrap_or_default(),
-            xpub: k.xpub().map(|x| x.to_string()).unwrap_or_default(),
-        })
+        .map(APIPubKey::from)
         .collect();
 
     let spend_paths_core = analyzer.spend_paths()?;
     let spend_paths = APISpendPath::from_sorted(&spend_paths_core)?;
 
     Ok(APIAnalysisResult {
-        descriptor,
+        descriptor: analyzer.descriptor_str().to_owned(),
         network: APINetwork::from(analyzer.network()),
         wallet_type: APIWalletType::from(analyzer.wallet_type()),
         keys,
         spend_paths,
+        spendability: APISpendability::from(analyzer.spendability()),
+    })
+}
+
+/// Import a BIP-129 (BSMS) round 2 descriptor record
+///
+/// The record's first address must be the one the descriptor derives.
+pub fn import_bsms_record(record: String) -> Result<APIAnalysisResult> {
+    let record = BsmsRecord::parse(&record)?;
+    record.verify()?;
+    analyze_descriptor(record.descriptor()?, None)
+}
+
+/// BIP-129 (BSMS) round 2 descriptor record of a descriptor
+pub fn export_bsms_record(descriptor: String) -> Result<String> {
+    Ok(BsmsRecord::from_descriptor(&descriptor)?.to_string())
+}
+
+/// New BIP-129 (BSMS) session token in hex: 16 bytes when `extended`, 8 otherwise
+pub fn create_bsms_token(extended: bool) -> Result<String> {
+    Ok(BsmsToken::generate(extended)?.to_string())
+}
+
+/// Encrypt a BSMS payload for the session of `token`
+///
+/// A `00` token leaves the payload in the clear.
+pub fn encrypt_bsms_payload(token: String, data: String) -> Result<String> {
+    BsmsToken::parse(&token)?.encrypt(&data)
+}
+
+/// Decrypt a BSMS payload of the session of `token`, checking its MAC
+pub fn decrypt_bsms_payload(token: String, payload: String) -> Result<String> {
+    BsmsToken::parse(&token)?.decrypt(&payload)
+}
+
+/// Import a BIP-129 (BSMS) round 1 key record of the session of `token`
+///
+/// The record is decrypted first in encrypted sessions. The key is only
+/// returned once the record's signature by it checks out.
+pub fn import_bsms_key_record(token: String, record: String) -> Result<APIPubKey> {
+    let token = BsmsToken::parse(&token)?;
+    let record = BsmsKeyRecord::parse(&token.decrypt(&record)?)?;
+    Ok(APIPubKey::from(&record.verify(&token)?))
+}
+
+/// Import a Coldcard multisig setup file
+pub fn import_coldcard_multisig(file: String) -> Result<APIImportedWallet> {
+    let multisig = ColdcardMultisig::parse(&file)?;
+    Ok(APIImportedWallet {
+        analysis: analyze_descriptor(multisig.descriptor()?, None)?,
+        name: Some(multisig.name),
+    })
+}
+
+/// Coldcard multisig setup file of a descriptor, such as one from `build_descriptor`
+///
+/// Fails with `UnsupportedExport` for policies the format can't express,
+/// like timelocks or taproot.
+pub fn export_coldcard_multisig(name: String, descriptor: String) -> Result<String> {
+    Ok(ColdcardMultisig::from_descriptor(&name, &descriptor)?.to_string())
+}
+
+/// Import an unencrypted Electrum wallet file, single key or multisig
+///
+/// The script type comes from the SLIP-132 version of its xpubs.
+pub fn import_electrum_wallet(file: String) -> Result<APIImportedWallet> {
+    let wallet = ElectrumWallet::parse(&file)?;
+    Ok(APIImportedWallet {
+        name: None,
+        analysis: analyze_descriptor(wallet.descriptor()?, None)?,
+    })
+}
+
+/// Watch-only Electrum wallet file of a descriptor
+///
+/// Fails with `UnsupportedExport` for policies Electrum can't express,
+/// like timelocks or taproot.
+pub fn export_electrum_wallet(descriptor: String) -> Result<String> {
+    ElectrumWallet::from_descriptor(&descriptor)?.to_json()
+}
+
+/// Import a Caravan wallet configuration
+///
+/// Every key is checked against the configured network with `validate_key`.
+/// The analysis reports that network, which xpub versions alone can't tell
+/// apart from the other test networks.
+pub fn import_caravan_wallet(config: String) -> Result<APIImportedWallet> {
+    let wallet = CaravanWallet::parse(&config)?;
+    validate_caravan_keys(&wallet)?;
+
+    let mut analysis = analyze_descriptor(wallet.descriptor()?, None)?;
+    analysis.network = wallet.network.into();
+    Ok(APIImportedWallet {
+        name: Some(wallet.name),
+        analysis,
+    })
+}
+
+/// Caravan wallet configuration of a descriptor, on `network`
+///
+/// Fails with `UnsupportedExport` for policies Caravan can't express, like
+/// single keys, timelocks or taproot, and when a key isn't of `network`.
+pub fn export_caravan_wallet(
+    name: String,
+    descriptor: String,
+    network: APINetwork,
+) -> Result<String> {
+    let wallet = CaravanWallet::from_descriptor(&name, &descriptor, network.into())?;
+    validate_caravan_keys(&wallet)?;
+    wallet.to_json()
+}
+
+fn validate_caravan_keys(wallet: &CaravanWallet) -> Result<()> {
+    for key in &wallet.keys {
+        validate_key(
+            key.xfp.to_string(),
+            key.bip32_path.to_string(),
+            key.xpub.to_string(),
+            wallet.network.into(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Derive `count` addresses of one keychain, starting at index `start`
+///
+/// The network is detected from the descriptor keys. `count` must not exceed
+/// `MAX_ADDRESS_COUNT`.
+pub fn derive_addresses(
+    descriptor: String,
+    keychain: APIKeychainKind,
+    start: u32,
+    count: u32,
+) -> Result<Vec<APIAddress>> {
+    let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+
+    Ok(analyzer
+        .addresses(keychain.into(), start, count)?
+        .iter()
+        .map(APIAddress::from)
+        .collect())
+}
+
+/// Find which keychain and index `address` belongs to
+///
+/// Both keychains are scanned from index 0 up to `gap`, at most
+/// `MAX_ADDRESS_COUNT`. Returns `None` when the address is not found.
+pub fn find_address(descriptor: String, address: String, gap: u32) -> Result<Option<APIAddress>> {
+    let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+
+    Ok(analyzer
+        .find_address(&address, gap)?
+        .as_ref()
+        .map(APIAddress::from))
+}
+
+/// Size and fee of spending `inputs` inputs through the spend path
+/// `spend_path_id`, at each of `fee_rates` (sat/vB)
+///
+/// There is one output per `destinations` script type, plus a change output
+/// of the wallet's own type when `change` is set.
+pub fn estimate_spend_path_fees(
+    descriptor: String,
+    spend_path_id: u32,
+    inputs: u32,
+    destinations: Vec<APIWalletType>,
+    change: bool,
+    fee_rates: Vec<f64>,
+) -> Result<Vec<APIFeeEstimate>> {
+    let destinations: Vec<WalletType> = destinations.into_iter().map(Into::into).collect();
+    let spend_paths = DescriptorAnalyzer::analyze(&descriptor)?.spend_paths()?;
+    let spend_path = spend_paths
+        .iter()
+        .find(|sp| sp.id == spend_path_id)
+        .ok_or(WalletError::MissingSpendPath)?;
+
+    Ok(spend_path
+        .estimate_fees(inputs as usize, &destinations, change, &fee_rates)?
+        .iter()
+        .map(APIFeeEstimate::from)
+        .collect())
+}
+
+/// Expected size and fee of a spend at each of `fee_rates` (sat/vB), with
+/// each spend path used as often as `likelihoods` says
+///
+/// Spend paths missing from `likelihoods` are never used. Transactions are
+/// as in `estimate_spend_path_fees`.
+pub fn estimate_expected_cost(
+    descriptor: String,
+    likelihoods: Vec<APISpendPathLikelihood>,
+    inputs: u32,
+    destinations: Vec<APIWalletType>,
+    change: bool,
+    fee_rates: Vec<f64>,
+) -> Result<Vec<APIExpectedCost>> {
+    let destinations: Vec<WalletType> = destinations.into_iter().map(Into::into).collect();
+    let spend_paths = DescriptorAnalyzer::analyze(&descriptor)?.spend_paths()?;
+    let likelihoods = likelihoods
+        .iter()
+        .map(|l| {
+            let spend_path = spend_paths
+                .iter()
+                .find(|sp| sp.id == l.spend_path_id)
+                .ok_or(WalletError::MissingSpendPath)?;
+            Ok((spend_path, l.likelihood))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(SpendPath::expected_cost(
+        &likelihoods,
+        inputs as usize,
+        &destinations,
+        change,
+        &fee_rates,
+    )?
+    .iter()
+    .map(APIExpectedCost::from)
+    .collect())
+}
+
+/// Cost of consolidating `utxos` UTXOs with each spend path, at each of
+/// `fee_rates` (sat/vB)
+pub fn plan_consolidation(
+    descriptor: String,
+    utxos: u32,
+    fee_rates: Vec<f64>,
+) -> Result<Vec<APIConsolidationPlan>> {
+    DescriptorAnalyzer::analyze(&descriptor)?
+        .spend_paths()?
+        .iter()
+        .map(|sp| {
+            let plan = sp.plan_consolidation(utxos as usize, &fee_rates)?;
+            Ok(APIConsolidationPlan {
+                spend_path_id: sp.id,
+                max_inputs_per_tx: plan.max_inputs_per_tx as u32,
+                transactions: plan.transactions as u32,
+                fees: plan.fees.iter().map(APIFeeEstimate::from).collect(),
+            })
+        })
+        .collect()
+}
+
+/// Standard dust limit of the wallet's outputs and economic dust of each spend
+/// path at each of `fee_rates` (sat/vB)
+pub fn analyze_dust(descriptor: String, fee_rates: Vec<f64>) -> Result<APIDustAnalysis> {
+    let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+
+    let spend_paths = analyzer
+        .spend_paths()?
+        .iter()
+        .map(|sp| {
+            Ok(APISpendPathDust {
+                spend_path_id: sp.id,
+                economic_dust: fee_rates
+                    .iter()
+                    .map(|&fee_rate| sp.economic_dust(fee_rate))
+                    .collect::<Result<_>>()?,
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(APIDustAnalysis {
+        dust_limit: analyzer.wallet_type().dust_limit(),
+        spend_paths,
     })
 }
 
@@ -51,12 +448,99 @@ pub fn build_descriptor(
     keys: Vec<APIPubKey>,
     spend_paths: Vec<APISpendPathDef>,
 ) -> Result<String> {
-    let core_keys: Vec<PubKey> = keys
-        .iter()
+    descriptor_builder::build_descriptor(
+        wallet_type.into(),
+        &core_keys(&keys)?,
+        &core_spend_path_defs(&spend_paths),
+    )
+}
+
+/// Build a Taproot descriptor and report the depth and cost of each spend path
+///
+/// When the spend paths have probabilities, the script tree is the one with
+/// the lowest expected spend weight; `expected_wu_in` is averaged with them.
+pub fn build_taproot_descriptor(
+    keys: Vec<APIPubKey>,
+    spend_paths: Vec<APISpendPathDef>,
+) -> Result<APITaprootBuild> {
+    let build =
+        descriptor_builder::build_taproot(&core_keys(&keys)?, &core_spend_path_defs(&spend_paths))?;
+    Ok(APITaprootBuild::from(&build))
+}
+
+/// Build the policy as every wallet type and compare the cost of each spend
+/// path, spending `inputs` inputs at each of `fee_rates` (sat/vB)
+///
+/// Outputs are as in `estimate_spend_path_fees`. Every wallet type is
+/// reported, with the ones that can't express the policy marked unsupported.
+pub fn compare_script_types(
+    keys: Vec<APIPubKey>,
+    spend_paths: Vec<APISpendPathDef>,
+    inputs: u32,
+    destinations: Vec<APIWalletType>,
+    change: bool,
+    fee_rates: Vec<f64>,
+) -> Result<Vec<APIScriptTypeComparison>> {
+    let destinations: Vec<WalletType> = destinations.into_iter().map(Into::into).collect();
+    let builds = descriptor_builder::compare_wallet_types(
+        &core_keys(&keys)?,
+        &core_spend_path_defs(&spend_paths),
+    );
+
+    builds
+        .into_iter()
+        .map(|(wallet_type, build)| {
+            let wallet_type = APIWalletType::from(wallet_type);
+            match build {
+                WalletTypeBuild::Built {
+                    descriptor,
+                    spend_paths,
+                } => Ok(APIScriptTypeComparison {
+                    wallet_type,
+                    supported: true,
+                    unsupported_reason: None,
+                    descriptor: Some(descriptor),
+                    spend_paths: spend_paths
+                        .iter()
+                        .map(|sp| {
+                            Ok(APISpendPathCost {
+                                spend_path_id: sp.id,
+                                wu_in: sp.wu_in,
+                                wu_in_max: sp.wu_in_max,
+                                fees: sp
+                                    .estimate_fees(
+                                        inputs as usize,
+                                        &destinations,
+                                        change,
+                                        &fee_rates,
+                                    )?
+                                    .iter()
+                                    .map(APIFeeEstimate::from)
+                                    .collect(),
+                            })
+                        })
+                        .collect::<Result<_>>()?,
+                }),
+                WalletTypeBuild::Unsupported { reason } => Ok(APIScriptTypeComparison {
+                    wallet_type,
+                    supported: false,
+                    unsupported_reason: Some(reason),
+                    descriptor: None,
+                    spend_paths: vec![],
+                }),
+            }
+        })
+        .collect()
+}
+
+fn core_keys(keys: &[APIPubKey]) -> Result<Vec<PubKey>> {
+    keys.iter()
         .map(|k| PubKey::new(&k.mfp, &k.derivation_path, &k.xpub))
-        .collect::<Result<Vec<_>>>()?;
+        .collect()
+}
 
-    let core_paths: Vec<SpendPathDef> = spend_paths
+fn core_spend_path_defs(spend_paths: &[APISpendPathDef]) -> Vec<SpendPathDef> {
+    spend_paths
         .iter()
         .map(|sp| SpendPathDef {
             threshold: sp.threshold as usize,
@@ -65,10 +549,9 @@ pub fn build_descriptor(
             abs_timelock: sp.abs_timelock,
             is_key_path: sp.is_key_path,
             priority: sp.priority as usize,
+            probability: sp.probability,
         })
-        .collect();
-
-    descriptor_builder::build_descriptor(wallet_type.into(), &core_keys, &core_paths)
+        .collect()
 }
 
 /// Calculate the deterministic rustId for a spend path
@@ -180,10 +663,15 @@ mod tests {
     use super::*;
     use crate::core::error::WalletError;
 
+    const XPUB_A: &str = "xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn";
+    const XPUB_B: &str = "xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj";
+    const TPUB_A: &str = "tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba";
+    const TPUB_B: &str = "tpubDChwdeVd7pBThLN5uKs5m83Eqv6ozCiLibqpswK3VtMFZcGv8L9ZUq6V56UYMzKfM4Bfsgy2b9HrFhRSoSKp1f3omLp17G74m4CzkUKsicG";
+
     #[test]
     fn test_mainnet() -> Result<()> {
-        let descriptor = "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/<0;1>/*))#0wct5td0";
-        let result = analyze_descriptor(String::from(descriptor))?;
+        let descriptor = format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*))#0wct5td0");
+        let result = analyze_descriptor(descriptor, None)?;
 
         assert_eq!(result.network, APINetwork::Bitcoin);
         assert_eq!(result.wallet_type, APIWalletType::P2WSH);
@@ -209,8 +697,8 @@ mod tests {
 
     #[test]
     fn test_testnet_single_key() -> Result<()> {
-        let descriptor = "pkh([73c5da0a/44h/1h/0h]tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba/<0;1>/*)#0x5u8d5c";
-        let result = analyze_descriptor(String::from(descriptor))?;
+        let descriptor = format!("pkh([73c5da0a/44h/1h/0h]{TPUB_A}/<0;1>/*)#0x5u8d5c");
+        let result = analyze_descriptor(descriptor, None)?;
 
         assert_eq!(result.network, APINetwork::Testnet);
         assert_eq!(result.wallet_type, APIWalletType::P2PKH);
@@ -221,13 +709,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_derive_addresses() -> Result<()> {
+        let descriptor = format!("wpkh([089177d9/84h/1h/0h]{TPUB_B}/<0;1>/*)#uxw7vpfc");
+
+        let receive = derive_addresses(descriptor.clone(), APIKeychainKind::External, 0, 3)?;
+        let change = derive_addresses(descriptor, APIKeychainKind::Internal, 2, 1)?;
+
+        assert_eq!(receive.len(), 3);
+        assert_eq!(change.len(), 1);
+        assert_eq!(change[0].index, 2);
+        assert_eq!(change[0].keychain, APIKeychainKind::Internal);
+        assert!(receive.iter().all(|a| a.address.starts_with("tb1q")));
+        // P2WPKH script: OP_0 PUSH20 <hash>
+        assert!(receive
+            .iter()
+            .all(|a| a.script_pubkey.len() == 44 && a.script_pubkey.starts_with("0014")));
+        assert_ne!(receive[0].address, receive[1].address);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_address() -> Result<()> {
+        let descriptor = format!("wpkh([089177d9/84h/1h/0h]{TPUB_B}/<0;1>/*)#uxw7vpfc");
+
+        let receive = derive_addresses(descriptor.clone(), APIKeychainKind::External, 4, 1)?;
+        let found = find_address(descriptor.clone(), receive[0].address.clone(), 20)?
+            .expect("address should be found");
+
+        assert_eq!(found.keychain, APIKeychainKind::External);
+        assert_eq!(found.index, 4);
+        assert_eq!(found.script_pubkey, receive[0].script_pubkey);
+        assert!(find_address(descriptor, receive[0].address.clone(), 4)?.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_taproot_without_keypath_roundtrip() -> Result<()> {
         // Descriptor with raw NUMS point (no keypath spend)
-        let original_descriptor = "tr(50929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0,{pk([c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*),pk([73c5da0a/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/<0;1>/*)})#kvpt6nlf";
+        let original_descriptor = format!("tr(50929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0,{{pk([c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*),pk([73c5da0a/48h/0h/0h/2h]{XPUB_B}/<0;1>/*)}})#kvpt6nlf");
 
         // Analyze descriptor
-        let result = analyze_descriptor(String::from(original_descriptor))?;
+        let result = analyze_descriptor(original_descriptor, None)?;
 
         // Should extract only 2 script path keys (NUMS excluded)
         assert_eq!(result.keys.len(), 2, "Should have only 2 script path keys");
@@ -255,6 +780,7 @@ mod tests {
                     abs_timelock: sp.abs_timelock,
                     is_key_path,
                     priority: 0,
+                    probability: None,
                 }
             })
             .collect();
@@ -281,7 +807,7 @@ mod tests {
         );
 
         // Re-analyze the rebuilt descriptor to verify it's valid
-        let reanalyzed = analyze_descriptor(rebuilt)?;
+        let reanalyzed = analyze_descriptor(rebuilt, None)?;
         assert_eq!(
             reanalyzed.keys.len(),
             2,
@@ -295,4 +821,538 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_analyze_descriptor_pair() -> Result<()> {
+        let multipath = analyze_descriptor(
+            format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*))"),
+            None,
+        )?;
+        let pair = analyze_descriptor(
+            format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/0/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/0/*))"),
+            Some(format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/1/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/1/*))")),
+        )?;
+
+        // Reported as one wallet with a multipath descriptor
+        assert!(pair.descriptor.contains("/<0;1>/*"));
+        assert_eq!(pair.network, APINetwork::Bitcoin);
+        assert_eq!(pair.keys.len(), 2);
+        assert_eq!(pair.spend_paths.len(), multipath.spend_paths.len());
+        assert_eq!(pair.spend_paths[0].id, multipath.spend_paths[0].id);
+        assert_eq!(
+            pair.spend_paths[0].vb_sweep,
+            multipath.spend_paths[0].vb_sweep
+        );
+
+        let change = derive_addresses(pair.descriptor, APIKeychainKind::Internal, 0, 1)?;
+        let expected = derive_addresses(multipath.descriptor, APIKeychainKind::Internal, 0, 1)?;
+        assert_eq!(change[0].address, expected[0].address);
+
+        assert!(analyze_descriptor(
+            format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/0/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/0/*))"),
+            Some(format!("wsh(multi(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/1/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/1/*))")),
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_spend_path_fees() -> Result<()> {
+        let descriptor = format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*))#0wct5td0");
+        let result = analyze_descriptor(descriptor.clone(), None)?;
+        let spend_path = &result.spend_paths[0];
+
+        let estimates = estimate_spend_path_fees(
+            descriptor.clone(),
+            spend_path.id,
+            1,
+            vec![],
+            true,
+            vec![5.0, 20.0, 100.0],
+        )?;
+        assert_eq!(estimates.len(), 3);
+        let vbytes = spend_path.vb_sweep.ceil() as u64;
+        for (estimate, rate) in estimates.iter().zip([5, 20, 100]) {
+            assert_eq!(estimate.vbytes, vbytes);
+            assert_eq!(estimate.fee_sat, vbytes * rate);
+        }
+
+        let result = estimate_spend_path_fees(
+            descriptor.clone(),
+            spend_path.id ^ 1,
+            1,
+            vec![],
+            true,
+            vec![5.0],
+        );
+        assert!(matches!(
+            result.err().and_then(|e| e.downcast::<WalletError>().ok()),
+            Some(WalletError::MissingSpendPath)
+        ));
+
+        // A P2TR payment with change costs one P2TR output (43 vB) more
+        let with_payment = estimate_spend_path_fees(
+            descriptor,
+            spend_path.id,
+            1,
+            vec![APIWalletType::P2TR],
+            true,
+            vec![1.0],
+        )?;
+        assert_eq!(with_payment[0].vbytes, vbytes + 43);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_dust() -> Result<()> {
+        let a = format!("[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*");
+        let b = format!("[bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*");
+        let c = format!("[cccccccc/48h/0h/0h/2h]{XPUB_B}/0/<0;1>/*");
+        let d = format!("[dddddddd/48h/0h/0h/2h]{XPUB_B}/1/<0;1>/*");
+        // Daily 1-of-1, or 2-of-3 inheritance after a year
+        let descriptor = format!("wsh(or_d(pk({d}),and_v(v:multi(2,{a},{b},{c}),older(52560))))");
+
+        let result = analyze_dust(descriptor.clone(), vec![5.0, 200.0])?;
+        assert_eq!(result.dust_limit, Some(330));
+        assert_eq!(result.spend_paths.len(), 2);
+
+        let analysis = analyze_descriptor(descriptor, None)?;
+        for dust in &result.spend_paths {
+            let sp = analysis
+                .spend_paths
+                .iter()
+                .find(|sp| sp.id == dust.spend_path_id)
+                .expect("dust of an unknown path");
+            assert_eq!(dust.economic_dust[0], (sp.wu_in as u64 * 5).div_ceil(4));
+            assert_eq!(dust.economic_dust[1], sp.wu_in as u64 * 50);
+        }
+        // The multisig path needs larger UTXOs to be worth spending
+        let mut dusts: Vec<u64> = result
+            .spend_paths
+            .iter()
+            .map(|d| d.economic_dust[1])
+            .collect();
+        dusts.sort();
+        assert!(dusts[1] > dusts[0]);
+
+        assert!(analyze_dust(a, vec![]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_plan_consolidation() -> Result<()> {
+        let a = format!("[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*");
+        let b = format!("[bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*");
+        let c = format!("[cccccccc/48h/0h/0h/2h]{XPUB_B}/0/<0;1>/*");
+        let d = format!("[dddddddd/48h/0h/0h/2h]{XPUB_B}/1/<0;1>/*");
+        let descriptor = format!("wsh(or_d(pk({d}),and_v(v:multi(2,{a},{b},{c}),older(52560))))");
+
+        let plans = plan_consolidation(descriptor, 2000, vec![1.0, 50.0])?;
+        assert_eq!(plans.len(), 2);
+        let (cheap, heavy) = if plans[0].max_inputs_per_tx > plans[1].max_inputs_per_tx {
+            (&plans[0], &plans[1])
+        } else {
+            (&plans[1], &plans[0])
+        };
+
+        // The recovery multisig fits fewer inputs per transaction and costs more
+        assert!(heavy.transactions >= cheap.transactions);
+        for plan in &plans {
+            assert_eq!(plan.transactions, 2000u32.div_ceil(plan.max_inputs_per_tx));
+            assert_eq!(plan.fees.len(), 2);
+            assert!(plan.fees[1].fee_sat > plan.fees[0].fee_sat);
+        }
+        assert!(heavy.fees[0].fee_sat > cheap.fees[0].fee_sat);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_script_types() -> Result<()> {
+        let key = APIPubKey {
+            mfp: "c449c5c5".into(),
+            derivation_path: "48h/0h/0h/2h".into(),
+            xpub: XPUB_A.into(),
+            synthetic_mfp: false,
+        };
+        let other = APIPubKey {
+            mfp: "c61af686".into(),
+            xpub: XPUB_B.into(),
+            ..key.clone()
+        };
+        let path = |threshold: u32, mfps: Vec<String>| APISpendPathDef {
+            threshold,
+            mfps,
+            rel_timelock: APIRelativeTimelock::from_consensus(0),
+            abs_timelock: APIAbsoluteTimelock::from_consensus(0),
+            is_key_path: threshold == 1,
+            priority: 0,
+            probability: None,
+        };
+
+        // A single key fits every wallet type, taproot key path is the cheapest
+        let singlesig = compare_script_types(
+            vec![key.clone()],
+            vec![path(1, vec![key.mfp.clone()])],
+            2,
+            vec![APIWalletType::P2WPKH],
+            true,
+            vec![10.0],
+        )?;
+        assert_eq!(singlesig.len(), 7);
+        assert!(singlesig.iter().all(|c| c.supported));
+        let fee = |c: &APIScriptTypeComparison| c.spend_paths[0].fees[0].fee_sat;
+        let cheapest = singlesig
+            .iter()
+            .min_by_key(|c| fee(c))
+            .expect("types compared");
+        assert_eq!(cheapest.wallet_type, APIWalletType::P2TR);
+
+        // Single key types can't express a multisig, and say why
+        let multisig = compare_script_types(
+            vec![key.clone(), other.clone()],
+            vec![path(2, vec![key.mfp.clone(), other.mfp.clone()])],
+            1,
+            vec![],
+            true,
+            vec![1.0, 10.0],
+        )?;
+        for comparison in &multisig {
+            let single_key = matches!(
+                comparison.wallet_type,
+                APIWalletType::P2PKH | APIWalletType::P2WPKH | APIWalletType::P2SH_WPKH
+            );
+            assert_eq!(comparison.supported, !single_key);
+            assert_eq!(comparison.unsupported_reason.is_some(), single_key);
+            assert_eq!(comparison.descriptor.is_some(), !single_key);
+            if !single_key {
+                assert_eq!(comparison.spend_paths.len(), 1);
+                assert_eq!(comparison.spend_paths[0].fees.len(), 2);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_taproot_descriptor() -> Result<()> {
+        let key = APIPubKey {
+            mfp: "c449c5c5".into(),
+            derivation_path: "48h/0h/0h/2h".into(),
+            xpub: XPUB_A.into(),
+            synthetic_mfp: false,
+        };
+        let other = APIPubKey {
+            mfp: "c61af686".into(),
+            xpub: XPUB_B.into(),
+            ..key.clone()
+        };
+        let path =
+            |mfps: Vec<String>, rel: u32, is_key_path: bool, probability: f64| APISpendPathDef {
+                threshold: mfps.len() as u32,
+                mfps,
+                rel_timelock: APIRelativeTimelock::from_consensus(rel),
+                abs_timelock: APIAbsoluteTimelock::from_consensus(0),
+                is_key_path,
+                priority: 0,
+                probability: Some(probability),
+            };
+
+        let build = build_taproot_descriptor(
+            vec![key.clone(), other.clone()],
+            vec![
+                path(vec![key.mfp.clone()], 0, true, 90.0),
+                path(vec![other.mfp.clone()], 144, false, 9.0),
+                path(vec![key.mfp.clone(), other.mfp.clone()], 0, false, 1.0),
+            ],
+        )?;
+
+        assert!(build.descriptor.starts_with("tr([c449c5c5/"));
+        let depths: Vec<i32> = build.spend_paths.iter().map(|c| c.tr_depth).collect();
+        assert_eq!(depths, vec![-1, 1, 1]);
+        assert!((build.spend_paths[0].probability - 0.9).abs() < 1e-9);
+
+        // Mostly spent through the key path, so close to its weight
+        let key_path_wu = build.spend_paths[0].wu_in as f64;
+        assert!(build.expected_wu_in > key_path_wu);
+        assert!(build.expected_wu_in < key_path_wu * 1.2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_expected_cost() -> Result<()> {
+        let a = format!("[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*");
+        let b = format!("[bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*");
+        let descriptor = format!("tr({a},pk({b}))");
+        let spend_paths = analyze_descriptor(descriptor.clone(), None)?.spend_paths;
+        let likelihood = |spend_path_id: u32, likelihood: f64| APISpendPathLikelihood {
+            spend_path_id,
+            likelihood,
+        };
+
+        let key_path = spend_paths
+            .iter()
+            .find(|sp| sp.tr_depth == -1)
+            .ok_or(WalletError::MissingSpendPath)?;
+        let script_path = spend_paths
+            .iter()
+            .find(|sp| sp.tr_depth == 0)
+            .ok_or(WalletError::MissingSpendPath)?;
+        let fees = |id: u32| {
+            estimate_spend_path_fees(descriptor.clone(), id, 1, vec![], true, vec![5.0])
+                .map(|fees| fees[0].fee_sat as f64)
+        };
+
+        let costs = estimate_expected_cost(
+            descriptor.clone(),
+            vec![
+                likelihood(key_path.id, 0.99),
+                likelihood(script_path.id, 0.01),
+            ],
+            1,
+            vec![],
+            true,
+            vec![5.0],
+        )?;
+        assert_eq!(costs.len(), 1);
+        let expected = 0.99 * fees(key_path.id)? + 0.01 * fees(script_path.id)?;
+        assert!((costs[0].fee_sat - expected).abs() < 1e-9);
+
+        // Unknown spend path
+        assert!(estimate_expected_cost(
+            descriptor,
+            vec![likelihood(0, 1.0)],
+            1,
+            vec![],
+            true,
+            vec![5.0]
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_descriptors() -> Result<()> {
+        let mainnet = format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*))#0wct5td0");
+        let testnet = format!("pkh([73c5da0a/44h/1h/0h]{TPUB_A}/<0;1>/*)#0x5u8d5c");
+        let (body, _) = mainnet.split_once('#').expect("has a checksum");
+        let wrong_checksum = format!("{body}#qqqqqqqq");
+
+        let descriptors: Vec<String> = [&mainnet, "wpkh(nope)", &testnet, body, &wrong_checksum]
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+        let analyses = analyze_descriptors(descriptors.clone())?;
+
+        assert_eq!(analyses.len(), 5);
+        for (analysis, descriptor) in analyses.iter().zip(&descriptors) {
+            assert_eq!(&analysis.descriptor, descriptor);
+            assert_eq!(analysis.result.is_some(), analysis.error.is_none());
+        }
+        let network = |i: usize| analyses[i].result.as_ref().map(|r| r.network);
+        assert_eq!(network(0), Some(APINetwork::Bitcoin));
+        assert!(analyses[1].error.is_some());
+        assert_eq!(network(2), Some(APINetwork::Testnet));
+
+        // With or without its checksum, the same descriptor is analyzed the same
+        let cached = analyses[3].result.as_ref().expect("analyzed");
+        assert_eq!(cached.descriptor, body);
+        assert_eq!(cached.spend_paths.len(), 1);
+        assert_eq!(
+            cached.spend_paths[0].id,
+            analyses[0].result.as_ref().expect("analyzed").spend_paths[0].id
+        );
+
+        // A wrong checksum is never served from the cache
+        assert!(analyses[4].error.is_some());
+
+        clear_analysis_cache();
+        assert!(analyze_descriptor(mainnet.clone(), None).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bsms_record() -> Result<()> {
+        let descriptor = format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*))#0wct5td0");
+
+        let record = export_bsms_record(descriptor.clone())?;
+        let first_address = derive_addresses(descriptor.clone(), APIKeychainKind::External, 0, 1)?;
+        assert_eq!(
+            record.lines().last(),
+            Some(first_address[0].address.as_str())
+        );
+
+        let imported = import_bsms_record(record.clone())?;
+        let original = analyze_descriptor(descriptor.clone(), None)?;
+        assert_eq!(imported.wallet_type, original.wallet_type);
+        assert_eq!(imported.keys.len(), 2);
+        assert_eq!(imported.spend_paths[0].id, original.spend_paths[0].id);
+
+        // The first address must match
+        let change = derive_addresses(descriptor, APIKeychainKind::Internal, 0, 1)?;
+        let tampered = record.replace(&first_address[0].address, &change[0].address);
+        assert!(import_bsms_record(tampered).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bsms_key_record() -> Result<()> {
+        use bdk_wallet::bitcoin::bip32::{DerivationPath, Xpriv, Xpub};
+        use bdk_wallet::bitcoin::hashes::Hash;
+        use bdk_wallet::bitcoin::secp256k1::{Message, Secp256k1};
+        use bdk_wallet::bitcoin::sign_message::{signed_msg_hash, MessageSignature};
+        use bdk_wallet::bitcoin::Network;
+
+        let secp = Secp256k1::new();
+        let master = Xpriv::new_master(Network::Testnet, &[3; 32])?;
+        let path: DerivationPath = "48h/1h/0h/2h".parse()?;
+        let account = master.derive_priv(&secp, &path)?;
+        let xpub = Xpub::from_priv(&secp, &account);
+
+        let token = create_bsms_token(false)?;
+        let mut record = BsmsKeyRecord {
+            token: token.clone(),
+            key: format!("[{}/{}]{}", master.fingerprint(&secp), path, xpub),
+            description: "Cold storage".to_owned(),
+            signature: String::new(),
+        };
+        let digest = signed_msg_hash(&record.signed_message());
+        let signature = secp.sign_ecdsa_recoverable(
+            &Message::from_digest(digest.to_byte_array()),
+            &account.private_key,
+        );
+        record.signature = MessageSignature::new(signature, true).to_base64();
+
+        let encrypted = encrypt_bsms_payload(token.clone(), record.to_string())?;
+        assert_eq!(
+            decrypt_bsms_payload(token.clone(), encrypted.clone())?,
+            record.to_string()
+        );
+
+        let key = import_bsms_key_record(token, encrypted.clone())?;
+        assert_eq!(key.mfp, master.fingerprint(&secp).to_string());
+        assert_eq!(key.derivation_path, path.to_string());
+        assert_eq!(key.xpub, xpub.to_string());
+        assert!(!key.synthetic_mfp);
+
+        // Not readable with another session's token
+        assert!(import_bsms_key_record(create_bsms_token(false)?, encrypted).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coldcard_multisig() -> Result<()> {
+        let key = APIPubKey {
+            mfp: "c449c5c5".into(),
+            derivation_path: "48h/0h/0h/2h".into(),
+            xpub: "xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn".into(),
+            synthetic_mfp: false,
+        };
+        let other = APIPubKey {
+            mfp: "c61af686".into(),
+            xpub: "xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj".into(),
+            ..key.clone()
+        };
+        let multisig = APISpendPathDef {
+            threshold: 2,
+            mfps: vec![key.mfp.clone(), other.mfp.clone()],
+            rel_timelock: APIRelativeTimelock::from_consensus(0),
+            abs_timelock: APIAbsoluteTimelock::from_consensus(0),
+            is_key_path: false,
+            priority: 0,
+            probability: None,
+        };
+        let descriptor = build_descriptor(
+            APIWalletType::P2WSH,
+            vec![key.clone(), other.clone()],
+            vec![multisig.clone()],
+        )?;
+
+        let file = export_coldcard_multisig("Vault".into(), descriptor.clone())?;
+        let imported = import_coldcard_multisig(file)?;
+        let original = analyze_descriptor(descriptor, None)?;
+        assert_eq!(imported.name.as_deref(), Some("Vault"));
+        assert!(imported
+            .analysis
+            .descriptor
+            .starts_with(&original.descriptor));
+        assert_eq!(
+            imported.analysis.spend_paths[0].id,
+            original.spend_paths[0].id
+        );
+        assert_eq!(imported.analysis.wallet_type, APIWalletType::P2WSH);
+
+        // Timelocked recovery paths don't fit in the format
+        let recovery = APISpendPathDef {
+            threshold: 1,
+            mfps: vec![key.mfp.clone()],
+            rel_timelock: APIRelativeTimelock::from_consensus(144),
+            ..multisig.clone()
+        };
+        let descriptor = build_descriptor(
+            APIWalletType::P2WSH,
+            vec![key, other],
+            vec![multisig, recovery],
+        )?;
+        let error = export_coldcard_multisig("Vault".into(), descriptor).expect_err("timelock");
+        assert!(matches!(
+            error.downcast_ref::<WalletError>(),
+            Some(WalletError::UnsupportedExport(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_electrum_wallet() -> Result<()> {
+        let descriptor = "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/<0;1>/*))#0wct5td0";
+
+        let file = export_electrum_wallet(descriptor.into())?;
+        assert!(file.contains("\"wallet_type\": \"2of2\""));
+        assert!(file.contains("Zpub"));
+
+        let imported = import_electrum_wallet(file)?;
+        let original = analyze_descriptor(descriptor.into(), None)?;
+        assert_eq!(imported.name, None);
+        assert_eq!(imported.analysis.wallet_type, APIWalletType::P2WSH);
+        assert_eq!(imported.analysis.keys.len(), 2);
+        assert_eq!(
+            imported.analysis.spend_paths[0].id,
+            original.spend_paths[0].id
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_caravan_wallet() -> Result<()> {
+        let descriptor = "wsh(sortedmulti(2,[73c5da0a/48h/1h/0h/2h]tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba/<0;1>/*,[089177d9/48h/1h/0h/2h]tpubDChwdeVd7pBThLN5uKs5m83Eqv6ozCiLibqpswK3VtMFZcGv8L9ZUq6V56UYMzKfM4Bfsgy2b9HrFhRSoSKp1f3omLp17G74m4CzkUKsicG/<0;1>/*))";
+
+        let config = export_caravan_wallet("Vault".into(), descriptor.into(), APINetwork::Regtest)?;
+        assert!(config.contains("\"network\": \"regtest\""));
+
+        let imported = import_caravan_wallet(config.clone())?;
+        assert_eq!(imported.name.as_deref(), Some("Vault"));
+        assert_eq!(imported.analysis.network, APINetwork::Regtest);
+        assert_eq!(imported.analysis.wallet_type, APIWalletType::P2WSH);
+        assert_eq!(imported.analysis.keys.len(), 2);
+        assert_eq!(imported.analysis.keys[0].mfp, "73c5da0a");
+
+        // tpubs aren't mainnet keys
+        let mainnet = config.replace("regtest", "mainnet");
+        assert!(import_caravan_wallet(mainnet).is_err());
+        assert!(
+            export_caravan_wallet("Vault".into(), descriptor.into(), APINetwork::Bitcoin).is_err()
+        );
+
+        Ok(())
+    }
 }
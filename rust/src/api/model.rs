@@ -1,7 +1,14 @@
-use crate::core::spend_path::SpendPath;
+use crate::core::address::DerivedAddress;
+use crate::core::descriptor_builder::TaprootBuild;
+use crate::core::pubkey::PubKey;
+use crate::core::signer_group::SignerGroup;
+use crate::core::spend_path::{
+    ExpectedCost, FeeEstimate, HashLock, HashType, SignerSet, SpendPath, Spendability,
+};
 use crate::core::wallet::WalletType;
 use anyhow::Result;
 use bdk_wallet::bitcoin::Network;
+use bdk_wallet::KeychainKind;
 
 ////////////////
 // APINetwork //
@@ -221,6 +228,107 @@ impl APIRelativeTimelock {
     }
 }
 
+/////////////////
+// APIHashLock //
+/////////////////
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum APIHashType {
+    Sha256,
+    Hash256,
+    Ripemd160,
+    Hash160,
+}
+
+impl From<HashType> for APIHashType {
+    fn from(hash_type: HashType) -> Self {
+        match hash_type {
+            HashType::Sha256 => APIHashType::Sha256,
+            HashType::Hash256 => APIHashType::Hash256,
+            HashType::Ripemd160 => APIHashType::Ripemd160,
+            HashType::Hash160 => APIHashType::Hash160,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct APIHashLock {
+    pub hash_type: APIHashType,
+    pub digest: String,
+}
+
+impl From<&HashLock> for APIHashLock {
+    fn from(hashlock: &HashLock) -> Self {
+        Self {
+            hash_type: APIHashType::from(hashlock.hash_type),
+            digest: hashlock.digest.clone(),
+        }
+    }
+}
+
+////////////////////
+// APISignerGroup //
+////////////////////
+/// Nested group of signers with its own threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct APISignerGroup {
+    pub threshold: u32,
+    /// Direct signers of the group
+    pub mfps: Vec<String>,
+    /// Nested groups, each counting as one item towards `threshold`
+    pub groups: Vec<APISignerGroup>,
+}
+
+impl From<&SignerGroup> for APISignerGroup {
+    fn from(group: &SignerGroup) -> Self {
+        Self {
+            threshold: group.threshold as u32,
+            mfps: group.mfps.clone(),
+            groups: group.groups.iter().map(APISignerGroup::from).collect(),
+        }
+    }
+}
+
+//////////////////
+// APISignerSet //
+//////////////////
+/// Minimal combination of signers that can spend a path
+#[derive(Debug, Clone, PartialEq)]
+pub struct APISignerSet {
+    pub mfps: Vec<String>,
+    pub wu_in: u32,
+}
+
+impl From<&SignerSet> for APISignerSet {
+    fn from(set: &SignerSet) -> Self {
+        Self {
+            mfps: set.mfps.clone(),
+            wu_in: set.wu_in,
+        }
+    }
+}
+
+/////////////////////
+// APISpendability //
+/////////////////////
+/// Whether the spend paths tell how the outputs are spent
+#[derive(Clone)]
+pub enum APISpendability {
+    Known,
+    /// Only the output script is known (`addr()`, `raw()`)
+    Unknown,
+    Unspendable,
+}
+
+impl From<Spendability> for APISpendability {
+    fn from(spendability: Spendability) -> Self {
+        match spendability {
+            Spendability::Known => APISpendability::Known,
+            Spendability::Unknown => APISpendability::Unknown,
+            Spendability::Unspendable => APISpendability::Unspendable,
+        }
+    }
+}
+
 //////////////////
 // APISpendPath //
 //////////////////
@@ -229,13 +337,33 @@ pub struct APISpendPath {
     pub id: u32,
     pub policy_path: Vec<APIPolicyPath>,
     pub threshold: u32,
+    /// Every signer of the path, including those of nested groups
     pub mfps: Vec<String>,
+    /// Nested signer groups; empty for flat k-of-n paths
+    pub signer_groups: Vec<APISignerGroup>,
     pub rel_timelock: APIRelativeTimelock,
     pub abs_timelock: APIAbsoluteTimelock,
+    /// Every relative timelock of the path; `rel_timelock` is the strictest one
+    pub rel_timelocks: Vec<APIRelativeTimelock>,
+    /// Every absolute timelock of the path; `abs_timelock` is the strictest one
+    pub abs_timelocks: Vec<APIAbsoluteTimelock>,
+    /// Height and time based locks are mixed, so the path can't be satisfied
+    pub timelock_conflict: bool,
+    /// Preimages that must be revealed to spend
+    pub hashlocks: Vec<APIHashLock>,
 
     pub wu_base: u32,
     pub wu_in: u32,
+    /// Input weight with low-R ECDSA signatures (71 bytes)
+    pub wu_in_min: u32,
+    /// Input weight with high-R ECDSA signatures (72 bytes) and Schnorr
+    /// signatures with an explicit sighash type (65 bytes)
+    pub wu_in_max: u32,
     pub wu_out: u32,
+    /// Minimal signer combinations with their input weight, cheapest first
+    pub signer_sets: Vec<APISignerSet>,
+    /// More combinations exist than `signer_sets` lists
+    pub signer_sets_truncated: bool,
 
     pub tr_depth: i32,
 
@@ -252,11 +380,28 @@ impl TryFrom<&SpendPath> for APISpendPath {
             policy_path: APIPolicyPath::from_spendpath(sp)?,
             threshold: sp.threshold as u32,
             mfps: sp.mfps.clone(),
+            signer_groups: sp.signer_groups.iter().map(APISignerGroup::from).collect(),
             rel_timelock: APIRelativeTimelock::from_consensus(sp.rel_timelock),
             abs_timelock: APIAbsoluteTimelock::from_consensus(sp.abs_timelock),
+            rel_timelocks: sp
+                .rel_timelocks
+                .iter()
+                .map(|&value| APIRelativeTimelock::from_consensus(value))
+                .collect(),
+            abs_timelocks: sp
+                .abs_timelocks
+                .iter()
+                .map(|&value| APIAbsoluteTimelock::from_consensus(value))
+                .collect(),
+            timelock_conflict: sp.timelock_conflict,
+            hashlocks: sp.hashlocks.iter().map(APIHashLock::from).collect(),
             wu_base: sp.wu_base,
             wu_in: sp.wu_in,
+            wu_in_min: sp.wu_in_min,
+            wu_in_max: sp.wu_in_max,
             wu_out: sp.wu_out,
+            signer_sets: sp.signer_sets.iter().map(APISignerSet::from).collect(),
+            signer_sets_truncated: sp.signer_sets_truncated,
             tr_depth: (sp.tr_depth as i32) - 1,
             vb_sweep: sp.estimate_tx_vb(1, 1),
         })
@@ -310,6 +455,170 @@ impl APIPolicyPath {
     }
 }
 
+////////////////////
+// APIFeeEstimate //
+////////////////////
+/// Size and fee of a transaction at one fee rate
+#[derive(Clone)]
+pub struct APIFeeEstimate {
+    /// sat/vB
+    pub fee_rate: f64,
+    pub vbytes: u64,
+    pub fee_sat: u64,
+    /// Size and fee with the largest signatures, safe to budget for
+    pub vbytes_max: u64,
+    pub fee_sat_max: u64,
+}
+
+impl From<&FeeEstimate> for APIFeeEstimate {
+    fn from(estimate: &FeeEstimate) -> Self {
+        Self {
+            fee_rate: estimate.fee_rate,
+            vbytes: estimate.vbytes,
+            fee_sat: estimate.fee_sat,
+            vbytes_max: estimate.vbytes_max,
+            fee_sat_max: estimate.fee_sat_max,
+        }
+    }
+}
+
+/////////////////////
+// APIExpectedCost //
+/////////////////////
+/// Size and fee of a spend averaged over the spend paths by their likelihood
+#[derive(Clone)]
+pub struct APIExpectedCost {
+    /// sat/vB
+    pub fee_rate: f64,
+    pub vbytes: f64,
+    pub fee_sat: f64,
+    /// Same with the largest signatures
+    pub vbytes_max: f64,
+    pub fee_sat_max: f64,
+}
+
+impl From<&ExpectedCost> for APIExpectedCost {
+    fn from(cost: &ExpectedCost) -> Self {
+        Self {
+            fee_rate: cost.fee_rate,
+            vbytes: cost.vbytes,
+            fee_sat: cost.fee_sat,
+            vbytes_max: cost.vbytes_max,
+            fee_sat_max: cost.fee_sat_max,
+        }
+    }
+}
+
+/// How often a spend path is expected to be used, in any unit
+#[derive(Clone)]
+pub struct APISpendPathLikelihood {
+    pub spend_path_id: u32,
+    pub likelihood: f64,
+}
+
+//////////////////////////
+// APIConsolidationPlan //
+//////////////////////////
+/// Cost of sweeping UTXOs into the wallet through one spend path
+#[derive(Clone)]
+pub struct APIConsolidationPlan {
+    pub spend_path_id: u32,
+    /// Most inputs a standard transaction can spend, even with the largest signatures
+    pub max_inputs_per_tx: u32,
+    /// Transactions needed to consolidate every UTXO
+    pub transactions: u32,
+    /// Total size and fee of the transactions, one per fee rate
+    pub fees: Vec<APIFeeEstimate>,
+}
+
+/////////////////////////////
+// APIScriptTypeComparison //
+/////////////////////////////
+/// A policy built as one wallet type, for side-by-side comparison
+#[derive(Clone)]
+pub struct APIScriptTypeComparison {
+    pub wallet_type: APIWalletType,
+    /// Whether the wallet type can express the policy
+    pub supported: bool,
+    /// Why the wallet type can't express the policy
+    pub unsupported_reason: Option<String>,
+    pub descriptor: Option<String>,
+    /// Empty when unsupported; IDs match across wallet types
+    pub spend_paths: Vec<APISpendPathCost>,
+}
+
+/// Input weight and transaction fees of a spend path
+#[derive(Clone)]
+pub struct APISpendPathCost {
+    pub spend_path_id: u32,
+    pub wu_in: u32,
+    pub wu_in_max: u32,
+    /// One per requested fee rate, in the same order
+    pub fees: Vec<APIFeeEstimate>,
+}
+
+/////////////////////
+// APITaprootBuild //
+/////////////////////
+/// Taproot descriptor along with the expected cost of its script tree
+#[derive(Clone)]
+pub struct APITaprootBuild {
+    pub descriptor: String,
+    pub spend_paths: Vec<APITaprootPathCost>,
+    /// Input weight averaged over the spend paths by probability
+    pub expected_wu_in: f64,
+}
+
+/// Where a spend path ended up in the Taproot tree and what it costs
+#[derive(Clone)]
+pub struct APITaprootPathCost {
+    pub spend_path_id: u32,
+    /// Normalized, so all paths add up to 1
+    pub probability: f64,
+    /// -1 for the key path, leaf depth for script paths
+    pub tr_depth: i32,
+    pub wu_in: u32,
+}
+
+impl From<&TaprootBuild> for APITaprootBuild {
+    fn from(build: &TaprootBuild) -> Self {
+        Self {
+            descriptor: build.descriptor.clone(),
+            spend_paths: build
+                .spend_paths
+                .iter()
+                .map(|cost| APITaprootPathCost {
+                    spend_path_id: cost.spend_path_id,
+                    probability: cost.probability,
+                    tr_depth: (cost.tr_depth as i32) - 1,
+                    wu_in: cost.wu_in,
+                })
+                .collect(),
+            expected_wu_in: build.expected_wu_in,
+        }
+    }
+}
+
+//////////////////////
+// APIDustAnalysis //
+//////////////////////
+/// Dust thresholds of a wallet, in sats
+#[derive(Clone)]
+pub struct APIDustAnalysis {
+    /// Standard dust limit of the wallet's outputs; `None` for unknown output types
+    pub dust_limit: Option<u64>,
+    pub spend_paths: Vec<APISpendPathDust>,
+}
+
+/// Economic dust of a spend path: UTXOs worth this much or less give nothing
+/// back when spent through it
+#[derive(Clone)]
+pub struct APISpendPathDust {
+    pub spend_path_id: u32,
+    /// One value per requested fee rate, in the same order
+    pub economic_dust: Vec<u64>,
+}
+
 //////////////////////
 // APISpendPathDef //
 //////////////////////
@@ -323,6 +632,10 @@ pub struct APISpendPathDef {
     /// Taproot script tree priority (0 = deepest/least likely, higher = shallower/more likely).
     /// Ignored for non-Taproot descriptors.
     pub priority: u32,
+    /// Expected share of spends that use this path, in any unit. Either every
+    /// path has one or none does; when given, the Taproot script tree is built
+    /// from them instead of from `priority`.
+    pub probability: Option<f64>,
 }
 
 ///////////////
@@ -332,7 +645,79 @@ pub struct APISpendPathDef {
 pub struct APIPubKey {
     pub mfp: String,
     pub derivation_path: String,
+    /// Extended public key, or the hex of a raw public key
     pub xpub: String,
+    /// `mfp` is derived from the hash of a raw key that has no origin info
+    pub synthetic_mfp: bool,
+}
+
+impl From<&PubKey> for APIPubKey {
+    fn from(key: &PubKey) -> Self {
+        Self {
+            mfp: key.mfp().to_string(),
+            derivation_path: key
+                .derivation_path()
+                .map(|dp| dp.to_string())
+                .unwrap_or_default(),
+            xpub: key
+                .xpub()
+                .map(|x| x.to_string())
+                .or_else(|_| key.raw_key())
+                .unwrap_or_default(),
+            synthetic_mfp: !key.has_origin(),
+        }
+    }
+}
+
+/////////////////////
+// APIKeychainKind //
+/////////////////////
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum APIKeychainKind {
+    /// Receive addresses (first `<0;1>` branch)
+    External,
+    /// Change addresses (second `<0;1>` branch)
+    Internal,
+}
+
+impl From<KeychainKind> for APIKeychainKind {
+    fn from(keychain: KeychainKind) -> Self {
+        match keychain {
+            KeychainKind::External => APIKeychainKind::External,
+            KeychainKind::Internal => APIKeychainKind::Internal,
+        }
+    }
+}
+
+impl From<APIKeychainKind> for KeychainKind {
+    fn from(val: APIKeychainKind) -> Self {
+        match val {
+            APIKeychainKind::External => KeychainKind::External,
+            APIKeychainKind::Internal => KeychainKind::Internal,
+        }
+    }
+}
+
+////////////////
+// APIAddress //
+////////////////
+#[derive(Debug, Clone)]
+pub struct APIAddress {
+    pub keychain: APIKeychainKind,
+    pub index: u32,
+    pub address: String,
+    pub script_pubkey: String,
+}
+
+impl From<&DerivedAddress> for APIAddress {
+    fn from(addr: &DerivedAddress) -> Self {
+        Self {
+            keychain: APIKeychainKind::from(addr.keychain),
+            index: addr.index,
+            address: addr.address.to_string(),
+            script_pubkey: addr.script_pubkey.to_hex_string(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,352 @@
+use anyhow::Result;
+use bdk_wallet::bitcoin::address::NetworkUnchecked;
+use bdk_wallet::bitcoin::key::TweakedPublicKey;
+use bdk_wallet::bitcoin::{Address, Network, ScriptBuf};
+use bdk_wallet::keys::DescriptorPublicKey;
+use bdk_wallet::miniscript::{Descriptor, ToPublicKey};
+use bdk_wallet::KeychainKind;
+
+use crate::core::error::WalletError;
+use crate::core::special_descriptor::SpecialDescriptor;
+
+/// Most addresses derived in one call, so a single request can't stall the caller
+pub const MAX_ADDRESS_COUNT: u32 = 10_000;
+
+/// Networks sharing the test key version bytes, which a descriptor can't tell apart
+const TEST_NETWORKS: [Network; 4] = [
+    Network::Testnet,
+    Network::Testnet4,
+    Network::Signet,
+    Network::Regtest,
+];
+
+/// Address derived from one keychain of a descriptor
+#[derive(Debug, Clone)]
+pub struct DerivedAddress {
+    pub keychain: KeychainKind,
+    pub index: u32,
+    pub address: Address,
+    pub script_pubkey: ScriptBuf,
+}
+
+/// Output script source of a keychain
+#[derive(Debug, Clone)]
+enum Output {
+    Descriptor(Descriptor<DescriptorPublicKey>),
+    /// Taproot output key used as is, as in `rawtr()`
+    RawTr(DescriptorPublicKey),
+    /// Same script at every index, as in `addr()` and `raw()`
+    Fixed(ScriptBuf),
+}
+
+impl Output {
+    fn has_wildcard(&self) -> bool {
+        match self {
+            Output::Descriptor(descriptor) => descriptor.has_wildcard(),
+            Output::RawTr(key) => key.has_wildcard(),
+            Output::Fixed(_) => false,
+        }
+    }
+
+    fn script_pubkey(&self, index: u32) -> Result<ScriptBuf> {
+        match self {
+            Output::Descriptor(descriptor) => {
+                Ok(descriptor.at_derivation_index(index)?.script_pubkey())
+            }
+            Output::RawTr(key) => {
+                let output_key = key.clone().at_derivation_index(index)?.to_x_only_pubkey();
+                Ok(ScriptBuf::new_p2tr_tweaked(
+                    TweakedPublicKey::dangerous_assume_tweaked(output_key),
+                ))
+            }
+            Output::Fixed(script) => Ok(script.clone()),
+        }
+    }
+}
+
+/// Derives addresses straight from the descriptor, without creating a wallet
+///
+/// A multipath descriptor (`<0;1>`) is split into its external (first) and
+/// internal (second) single-path descriptors. A keychain may have several
+/// outputs per index, e.g. the script types of `combo()`.
+pub struct AddressDeriver {
+    keychains: Vec<Vec<Output>>,
+    network: Network,
+}
+
+impl AddressDeriver {
+    pub fn new(descriptor: &Descriptor<DescriptorPublicKey>, network: Network) -> Result<Self> {
+        let keychains = descriptor
+            .clone()
+            .into_single_descriptors()?
+            .into_iter()
+            .map(|descriptor| vec![Output::Descriptor(descriptor)])
+            .collect();
+        Ok(Self { keychains, network })
+    }
+
+    pub fn from_special(descriptor: &SpecialDescriptor, network: Network) -> Result<Self> {
+        let keychains = match descriptor {
+            SpecialDescriptor::Addr(address) => {
+                let address = address
+                    .clone()
+                    .require_network(network)
+                    .map_err(|_| WalletError::InvalidAddress)?;
+                vec![vec![Output::Fixed(address.script_pubkey())]]
+            }
+            SpecialDescriptor::Raw(script) => vec![vec![Output::Fixed(script.clone())]],
+            SpecialDescriptor::RawTr(key) => key
+                .clone()
+                .into_single_keys()
+                .into_iter()
+                .map(|key| vec![Output::RawTr(key)])
+                .collect(),
+            SpecialDescriptor::Combo(_) => {
+                let mut keychains: Vec<Vec<Output>> = Vec::new();
+                for descriptor in descriptor.equivalent_descriptors()? {
+                    for (i, single) in descriptor
+                        .into_single_descriptors()?
+                        .into_iter()
+                        .enumerate()
+                    {
+                        if keychains.len() <= i {
+                            keychains.push(Vec::new());
+                        }
+                        keychains[i].push(Output::Descriptor(single));
+                    }
+                }
+                keychains
+            }
+        };
+        Ok(Self { keychains, network })
+    }
+
+    fn keychain_outputs(&self, keychain: KeychainKind) -> Result<&[Output]> {
+        let idx = match keychain {
+            KeychainKind::External => 0,
+            KeychainKind::Internal => 1,
+        };
+        self.keychains
+            .get(idx)
+            .map(Vec::as_slice)
+            .ok_or(WalletError::MissingKeychain.into())
+    }
+
+    /// Addresses of a keychain at `index`, one per output
+    ///
+    /// Outputs without an address (e.g. OP_RETURN) are skipped.
+    pub fn derive(&self, keychain: KeychainKind, index: u32) -> Result<Vec<DerivedAddress>> {
+        let mut addresses = Vec::new();
+        for output in self.keychain_outputs(keychain)? {
+            let script_pubkey = output.script_pubkey(index)?;
+            if let Ok(address) = Address::from_script(&script_pubkey, self.network) {
+                addresses.push(DerivedAddress {
+                    keychain,
+                    index,
+                    address,
+                    script_pubkey,
+                });
+            }
+        }
+        Ok(addresses)
+    }
+
+    /// Derive the addresses of `count` consecutive indexes starting at `start`
+    ///
+    /// `count` is at most `MAX_ADDRESS_COUNT`.
+    pub fn derive_range(
+        &self,
+        keychain: KeychainKind,
+        start: u32,
+        count: u32,
+    ) -> Result<Vec<DerivedAddress>> {
+        if count > MAX_ADDRESS_COUNT {
+            Err(WalletError::TooManyAddresses)?;
+        }
+        let end = start
+            .checked_add(count)
+            .ok_or(WalletError::InvalidDerivationIndex)?;
+
+        let mut addresses = Vec::new();
+        for index in start..end {
+            addresses.extend(self.derive(keychain, index)?);
+        }
+        Ok(addresses)
+    }
+
+    /// Look up which keychain and index an address belongs to
+    ///
+    /// Scans indexes `0..gap` of the external keychain first, then the internal one.
+    /// Returns `None` when the address is not found within the gap, which is at
+    /// most `MAX_ADDRESS_COUNT`. Addresses of any test network are accepted
+    /// for a test network descriptor.
+    pub fn find(&self, address: &str, gap: u32) -> Result<Option<DerivedAddress>> {
+        if gap > MAX_ADDRESS_COUNT {
+            Err(WalletError::TooManyAddresses)?;
+        }
+        let address = address
+            .parse::<Address<NetworkUnchecked>>()
+            .map_err(|_| WalletError::InvalidAddress)?;
+        let valid = if self.network == Network::Bitcoin {
+            address.is_valid_for_network(Network::Bitcoin)
+        } else {
+            TEST_NETWORKS
+                .iter()
+                .any(|&network| address.is_valid_for_network(network))
+        };
+        if !valid {
+            Err(WalletError::InvalidAddress)?;
+        }
+        let address = address.assume_checked();
+        let target = address.script_pubkey();
+
+        for keychain in [KeychainKind::External, KeychainKind::Internal] {
+            let Ok(outputs) = self.keychain_outputs(keychain) else {
+                continue;
+            };
+            // Fixed keys derive the same script at every index
+            let limit = if outputs.iter().any(Output::has_wildcard) {
+                gap
+            } else {
+                1
+            };
+
+            for index in 0..limit {
+                for output in outputs {
+                    if output.script_pubkey(index)? == target {
+                        return Ok(Some(DerivedAddress {
+                            keychain,
+                            index,
+                            address,
+                            script_pubkey: target,
+                        }));
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::Wallet;
+
+    const WSH_MAINNET: &str = "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/<0;1>/*))#0wct5td0";
+    const WPKH_TESTNET: &str = "wpkh([089177d9/84h/1h/0h]tpubDChwdeVd7pBThLN5uKs5m83Eqv6ozCiLibqpswK3VtMFZcGv8L9ZUq6V56UYMzKfM4Bfsgy2b9HrFhRSoSKp1f3omLp17G74m4CzkUKsicG/<0;1>/*)#uxw7vpfc";
+
+    #[test]
+    fn test_derive_matches_wallet() -> Result<()> {
+        let descriptor: Descriptor<DescriptorPublicKey> = WSH_MAINNET.parse()?;
+        let deriver = AddressDeriver::new(&descriptor, Network::Bitcoin)?;
+        let wallet = Wallet::create_from_two_path_descriptor(WSH_MAINNET.to_owned())
+            .network(Network::Bitcoin)
+            .create_wallet_no_persist()?;
+
+        for keychain in [KeychainKind::External, KeychainKind::Internal] {
+            let addresses = deriver.derive_range(keychain, 0, 5)?;
+            assert_eq!(addresses.len(), 5);
+
+            for addr in addresses {
+                let expected = wallet.peek_address(keychain, addr.index);
+                assert_eq!(addr.address, expected.address);
+                assert_eq!(addr.script_pubkey, expected.address.script_pubkey());
+                assert!(addr.address.to_string().starts_with("bc1q"));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_address() -> Result<()> {
+        let descriptor: Descriptor<DescriptorPublicKey> = WSH_MAINNET.parse()?;
+        let deriver = AddressDeriver::new(&descriptor, Network::Bitcoin)?;
+
+        let change = deriver.derive(KeychainKind::Internal, 7)?.remove(0);
+        let found = deriver
+            .find(&change.address.to_string(), 20)?
+            .expect("address should be found");
+        assert_eq!(found.keychain, KeychainKind::Internal);
+        assert_eq!(found.index, 7);
+
+        // Outside the gap
+        let far = deriver.derive(KeychainKind::External, 25)?.remove(0);
+        assert!(deriver.find(&far.address.to_string(), 20)?.is_none());
+        assert!(deriver.find(&far.address.to_string(), 26)?.is_some());
+
+        // Foreign address
+        assert!(deriver
+            .find("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq", 20)?
+            .is_none());
+
+        // Wrong network or garbage
+        assert!(deriver
+            .find("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", 20)
+            .is_err());
+        assert!(deriver.find("not an address", 20).is_err());
+
+        // Gaps are capped
+        let error = deriver
+            .find(&far.address.to_string(), MAX_ADDRESS_COUNT + 1)
+            .expect_err("gap too large");
+        assert!(matches!(
+            error.downcast_ref::<WalletError>(),
+            Some(WalletError::TooManyAddresses)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_address_on_test_networks() -> Result<()> {
+        // Keys don't tell the test networks apart, their addresses are all accepted
+        let descriptor: Descriptor<DescriptorPublicKey> = WPKH_TESTNET.parse()?;
+        let deriver = AddressDeriver::new(&descriptor, Network::Testnet)?;
+
+        let script_pubkey = deriver
+            .derive(KeychainKind::External, 3)?
+            .remove(0)
+            .script_pubkey;
+        let regtest = Address::from_script(&script_pubkey, Network::Regtest)?;
+        assert!(regtest.to_string().starts_with("bcrt1"));
+        let found = deriver
+            .find(&regtest.to_string(), 20)?
+            .expect("address should be found");
+        assert_eq!(found.index, 3);
+        assert_eq!(found.address, regtest);
+
+        let signet = Address::from_script(&script_pubkey, Network::Signet)?;
+        assert!(deriver.find(&signet.to_string(), 20)?.is_some());
+
+        // Mainnet addresses still are not
+        let mainnet = Address::from_script(&script_pubkey, Network::Bitcoin)?;
+        assert!(deriver.find(&mainnet.to_string(), 20).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_range_overflow() -> Result<()> {
+        let descriptor: Descriptor<DescriptorPublicKey> = WSH_MAINNET.parse()?;
+        let deriver = AddressDeriver::new(&descriptor, Network::Bitcoin)?;
+
+        assert!(deriver
+            .derive_range(KeychainKind::External, u32::MAX, 2)
+            .is_err());
+        // Ranges are capped
+        let error = deriver
+            .derive_range(KeychainKind::External, 0, MAX_ADDRESS_COUNT + 1)
+            .expect_err("too many addresses");
+        assert!(matches!(
+            error.downcast_ref::<WalletError>(),
+            Some(WalletError::TooManyAddresses)
+        ));
+        // Hardened indexes can't be derived from public keys
+        assert!(deriver.derive(KeychainKind::External, 1 << 31).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,652 @@
+use std::fmt;
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use anyhow::Result;
+use bdk_wallet::bitcoin::hashes::hmac::{Hmac, HmacEngine};
+use bdk_wallet::bitcoin::hashes::{cmp, sha256, Hash, HashEngine};
+use bdk_wallet::bitcoin::secp256k1::Secp256k1;
+use bdk_wallet::bitcoin::sign_message::{signed_msg_hash, MessageSignature};
+use bdk_wallet::keys::DescriptorPublicKey;
+use bdk_wallet::miniscript::descriptor::checksum::desc_checksum;
+use bdk_wallet::miniscript::descriptor::Wildcard;
+use bdk_wallet::KeychainKind;
+use pbkdf2::pbkdf2_hmac_array;
+use rand::rngs::OsRng;
+use rand::TryRngCore;
+use sha2::Sha512;
+
+use crate::core::descriptor::DescriptorAnalyzer;
+use crate::core::error::WalletError;
+use crate::core::pubkey::PubKey;
+
+type Aes256Ctr = ctr::Ctr128BE<aes::Aes256>;
+
+const BSMS_VERSION: &str = "BSMS 1.0";
+const NO_PATH_RESTRICTIONS: &str = "No path restrictions";
+/// Stands for the path restrictions after each key of the template
+const TEMPLATE_PATHS: &str = "/**";
+const RECEIVE_CHANGE_PATHS: &str = "/<0;1>/*";
+
+/// Descriptor record of BIP-129 (BSMS) round 2, sent by the coordinator
+/// to every signer
+///
+/// ```text
+/// BSMS 1.0
+/// wsh(sortedmulti(2,[...]xpub.../**,[...]xpub.../**))#checksum
+/// /0/*,/1/*
+/// bc1q...
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BsmsRecord {
+    /// Descriptor where `/**` stands for the path restrictions
+    pub descriptor_template: String,
+    /// Paths allowed after each key, e.g. `/0/*`; empty when unrestricted
+    pub path_restrictions: Vec<String>,
+    /// First receive address of the descriptor
+    pub first_address: String,
+}
+
+impl BsmsRecord {
+    /// Parse a record, without verifying it
+    pub fn parse(record: &str) -> Result<Self> {
+        let lines: Vec<&str> = record
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        let [version, descriptor_template, path_restrictions, first_address] = lines[..] else {
+            return Err(WalletError::InvalidBsmsRecord.into());
+        };
+        if version != BSMS_VERSION {
+            Err(WalletError::InvalidBsmsRecord)?;
+        }
+
+        let path_restrictions = if path_restrictions == NO_PATH_RESTRICTIONS {
+            vec![]
+        } else {
+            path_restrictions
+                .split(',')
+                .map(|path| Self::restriction_index(path.trim()).map(|_| path.trim().to_owned()))
+                .collect::<Result<_>>()?
+        };
+
+        Ok(Self {
+            descriptor_template: descriptor_template.to_owned(),
+            path_restrictions,
+            first_address: first_address.to_owned(),
+        })
+    }
+
+    /// Index of a `/N/*` path restriction
+    fn restriction_index(path: &str) -> Result<u32> {
+        Ok(path
+            .strip_prefix('/')
+            .and_then(|path| path.strip_suffix("/*"))
+            .and_then(|index| index.parse().ok())
+            .ok_or(WalletError::InvalidBsmsRecord)?)
+    }
+
+    /// Descriptor with `/**` replaced by the path restrictions
+    ///
+    /// Without restrictions, `/**` is the usual receive and change paths.
+    pub fn descriptor(&self) -> Result<String> {
+        let template = match self.descriptor_template.split_once('#') {
+            Some((template, checksum)) => {
+                if desc_checksum(template)? != checksum {
+                    Err(WalletError::InvalidDescriptorSyntax)?;
+                }
+                template
+            }
+            None => self.descriptor_template.as_str(),
+        };
+
+        let paths = match &self.path_restrictions[..] {
+            [] => RECEIVE_CHANGE_PATHS.to_owned(),
+            [path] => path.clone(),
+            paths => {
+                let indexes = paths
+                    .iter()
+                    .map(|path| Ok(Self::restriction_index(path)?.to_string()))
+                    .collect::<Result<Vec<_>>>()?;
+                format!("/<{}>/*", indexes.join(";"))
+            }
+        };
+        let descriptor = template.replace(TEMPLATE_PATHS, &paths);
+        Ok(format!("{}#{}", descriptor, desc_checksum(&descriptor)?))
+    }
+
+    /// Check that the descriptor derives the record's first address
+    ///
+    /// Returns the analyzed descriptor.
+    pub fn verify(&self) -> Result<DescriptorAnalyzer> {
+        let analyzer = DescriptorAnalyzer::analyze(&self.descriptor()?)?;
+        // Index 0 of both keychains is scanned, only the receive one is the first address
+        let found = analyzer.find_address(&self.first_address, 1)?;
+        if found.is_none_or(|found| found.keychain != KeychainKind::External) {
+            Err(WalletError::FirstAddressMismatch)?;
+        }
+
+        Ok(analyzer)
+    }
+
+    /// Record of a descriptor, as the coordinator's final step
+    ///
+    /// Receive/change (`<0;1>`) keys are written as `/**`. Descriptors with
+    /// other multipath steps can't be expressed in BSMS.
+    pub fn from_descriptor(descriptor: &str) -> Result<Self> {
+        let analyzer = DescriptorAnalyzer::analyze(descriptor)?;
+        let parsed = analyzer.descriptor()?;
+
+        let descriptor = parsed.to_string();
+        let descriptor = descriptor
+            .split_once('#')
+            .map_or(descriptor.as_str(), |(descriptor, _)| descriptor);
+        let (template, path_restrictions) = if parsed.is_multipath() {
+            let template = descriptor.replace(RECEIVE_CHANGE_PATHS, TEMPLATE_PATHS);
+            (template, vec!["/0/*".to_owned(), "/1/*".to_owned()])
+        } else {
+            (descriptor.to_owned(), vec![])
+        };
+        if template.contains('<') {
+            Err(WalletError::UnsupportedDescriptor)?;
+        }
+
+        let first_address = analyzer
+            .addresses(KeychainKind::External, 0, 1)?
+            .into_iter()
+            .next()
+            .ok_or(WalletError::MissingKeychain)?
+            .address
+            .to_string();
+
+        Ok(Self {
+            descriptor_template: format!("{}#{}", template, desc_checksum(&template)?),
+            path_restrictions,
+            first_address,
+        })
+    }
+}
+
+impl fmt::Display for BsmsRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path_restrictions = if self.path_restrictions.is_empty() {
+            NO_PATH_RESTRICTIONS.to_owned()
+        } else {
+            self.path_restrictions.join(",")
+        };
+        write!(
+            f,
+            "{}\n{}\n{}\n{}",
+            BSMS_VERSION, self.descriptor_template, path_restrictions, self.first_address
+        )
+    }
+}
+
+/// Session token of a BIP-129 (BSMS) setup, shared by the coordinator
+/// with each signer
+///
+/// A zero token (`00`) means the session is not encrypted. Otherwise it is
+/// 8 bytes, or 16 in extended mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BsmsToken(Vec<u8>);
+
+impl BsmsToken {
+    /// New random token; 16 bytes when `extended`, 8 otherwise
+    pub fn generate(extended: bool) -> Result<Self> {
+        let mut token = vec![0u8; if extended { 16 } else { 8 }];
+        OsRng.try_fill_bytes(&mut token)?;
+        Ok(Self(token))
+    }
+
+    pub fn parse(token: &str) -> Result<Self> {
+        let token = hex::decode(token.trim()).map_err(|_| WalletError::InvalidBsmsToken)?;
+        if token != [0] && token.len() != 8 && token.len() != 16 {
+            Err(WalletError::InvalidBsmsToken)?;
+        }
+        Ok(Self(token))
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.0 != [0]
+    }
+
+    /// AES key: PBKDF2-SHA512 of the token with salt `No SPOF`
+    fn encryption_key(&self) -> [u8; 32] {
+        pbkdf2_hmac_array::<Sha512, 32>(&self.0, b"No SPOF", 2048)
+    }
+
+    /// HMAC-SHA256 of the token and `data`, keyed with the hash of the AES key
+    fn mac(&self, encryption_key: &[u8; 32], data: &[u8]) -> [u8; 32] {
+        let hmac_key = sha256::Hash::hash(encryption_key);
+        let mut engine = HmacEngine::<sha256::Hash>::new(hmac_key.as_byte_array());
+        engine.input(&self.0);
+        engine.input(data);
+        Hmac::<sha256::Hash>::from_engine(engine).to_byte_array()
+    }
+
+    /// Encrypt a payload for the session, as hex of the MAC and ciphertext
+    ///
+    /// The first 16 bytes of the MAC are the AES-256-CTR IV. Unencrypted
+    /// sessions send the payload as is.
+    pub fn encrypt(&self, data: &str) -> Result<String> {
+        if !self.is_encrypted() {
+            return Ok(data.to_owned());
+        }
+
+        let encryption_key = self.encryption_key();
+        let mac = self.mac(&encryption_key, data.as_bytes());
+        let mut ciphertext = data.as_bytes().to_vec();
+        Aes256Ctr::new(&encryption_key.into(), mac[..16].into()).apply_keystream(&mut ciphertext);
+
+        Ok(hex::encode([&mac[..], &ciphertext].concat()))
+    }
+
+    /// Decrypt a payload of the session, checking its MAC
+    pub fn decrypt(&self, payload: &str) -> Result<String> {
+        if !self.is_encrypted() {
+            return Ok(payload.to_owned());
+        }
+
+        let payload =
+            hex::decode(payload.trim()).map_err(|_| WalletError::BsmsAuthenticationFailed)?;
+        if payload.len() < 32 {
+            Err(WalletError::BsmsAuthenticationFailed)?;
+        }
+        let (mac, ciphertext) = payload.split_at(32);
+
+        let encryption_key = self.encryption_key();
+        let mut data = ciphertext.to_vec();
+        Aes256Ctr::new(&encryption_key.into(), mac[..16].into()).apply_keystream(&mut data);
+        if !cmp::fixed_time_eq(&self.mac(&encryption_key, &data), mac) {
+            Err(WalletError::BsmsAuthenticationFailed)?;
+        }
+
+        Ok(String::from_utf8(data).map_err(|_| WalletError::BsmsAuthenticationFailed)?)
+    }
+}
+
+impl fmt::Display for BsmsToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.0))
+    }
+}
+
+/// Key record of BIP-129 (BSMS) round 1, sent by each signer to the
+/// coordinator
+///
+/// ```text
+/// BSMS 1.0
+/// <token>
+/// [mfp/48h/0h/0h/2h]xpub...
+/// <description>
+/// <signature>
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BsmsKeyRecord {
+    pub token: String,
+    /// Key with its origin, without derivation steps after it
+    pub key: String,
+    pub description: String,
+    /// Bitcoin signed message of the other lines, by `key`, in base64
+    pub signature: String,
+}
+
+impl BsmsKeyRecord {
+    /// Parse a record, without verifying it
+    pub fn parse(record: &str) -> Result<Self> {
+        let lines: Vec<&str> = record.trim().lines().map(str::trim).collect();
+        let [version, token, key, description, signature] = lines[..] else {
+            return Err(WalletError::InvalidBsmsRecord.into());
+        };
+        if version != BSMS_VERSION {
+            Err(WalletError::InvalidBsmsRecord)?;
+        }
+
+        Ok(Self {
+            token: token.to_owned(),
+            key: key.to_owned(),
+            description: description.to_owned(),
+            signature: signature.to_owned(),
+        })
+    }
+
+    /// The lines covered by the signature
+    pub fn signed_message(&self) -> String {
+        format!(
+            "{}\n{}\n{}\n{}",
+            BSMS_VERSION, self.token, self.key, self.description
+        )
+    }
+
+    /// Check that the record belongs to the session of `token` and is signed
+    /// by its key, and return the key
+    pub fn verify(&self, token: &BsmsToken) -> Result<PubKey> {
+        if BsmsToken::parse(&self.token)? != *token {
+            Err(WalletError::InvalidBsmsToken)?;
+        }
+
+        let key: DescriptorPublicKey = self
+            .key
+            .parse()
+            .map_err(|_| WalletError::InvalidBsmsRecord)?;
+        let plain = match &key {
+            DescriptorPublicKey::XPub(xpub) => {
+                xpub.origin.is_some()
+                    && xpub.derivation_path.is_master()
+                    && xpub.wildcard == Wildcard::None
+            }
+            DescriptorPublicKey::Single(single) => single.origin.is_some(),
+            DescriptorPublicKey::MultiXPub(_) => false,
+        };
+        if !plain {
+            Err(WalletError::InvalidBsmsRecord)?;
+        }
+        let key = PubKey::try_from(key)?;
+
+        let signature = MessageSignature::from_base64(&self.signature)
+            .map_err(|_| WalletError::InvalidKeyRecordSignature)?;
+        let signer = signature
+            .recover_pubkey(
+                &Secp256k1::verification_only(),
+                signed_msg_hash(&self.signed_message()),
+            )
+            .map_err(|_| WalletError::InvalidKeyRecordSignature)?;
+        if signer.inner != key.public_key() {
+            Err(WalletError::InvalidKeyRecordSignature)?;
+        }
+
+        Ok(key)
+    }
+}
+
+impl fmt::Display for BsmsKeyRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\n{}", self.signed_message(), self.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::bitcoin::address::NetworkUnchecked;
+    use bdk_wallet::bitcoin::{Address, Network};
+    use bdk_wallet::keys::DescriptorPublicKey;
+    use bdk_wallet::miniscript::Descriptor;
+
+    const DESCRIPTOR: &str = "wsh(sortedmulti(2,[73c5da0a/48h/1h/0h/2h]tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba/<0;1>/*,[089177d9/48h/1h/0h/2h]tpubDChwdeVd7pBThLN5uKs5m83Eqv6ozCiLibqpswK3VtMFZcGv8L9ZUq6V56UYMzKfM4Bfsgy2b9HrFhRSoSKp1f3omLp17G74m4CzkUKsicG/<0;1>/*))";
+
+    #[test]
+    fn test_bsms_roundtrip() -> Result<()> {
+        let record = BsmsRecord::from_descriptor(DESCRIPTOR)?;
+        assert!(record.descriptor_template.contains("tpubDC5F"));
+        assert_eq!(record.descriptor_template.matches("/**").count(), 2);
+        assert_eq!(record.path_restrictions, vec!["/0/*", "/1/*"]);
+        assert!(record.first_address.starts_with("tb1q"));
+
+        let text = record.to_string();
+        assert!(text.starts_with("BSMS 1.0\n"));
+        assert!(text.contains("\n/0/*,/1/*\n"));
+
+        let parsed = BsmsRecord::parse(&text)?;
+        assert_eq!(parsed, record);
+        let analyzer = parsed.verify()?;
+        assert_eq!(analyzer.descriptor()?, &DESCRIPTOR.parse()?);
+
+        // Coordinators on regtest write the first address for it
+        let script_pubkey = record
+            .first_address
+            .parse::<Address<NetworkUnchecked>>()?
+            .assume_checked()
+            .script_pubkey();
+        let regtest = BsmsRecord {
+            first_address: Address::from_script(&script_pubkey, Network::Regtest)?.to_string(),
+            ..record
+        };
+        regtest.verify()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bsms_path_restrictions() -> Result<()> {
+        let parse = |descriptor: &str| descriptor.parse::<Descriptor<DescriptorPublicKey>>();
+
+        // A single path is written out, without `/**`
+        let receive = DESCRIPTOR.replace("<0;1>", "0");
+        let record = BsmsRecord::from_descriptor(&receive)?;
+        assert!(record.path_restrictions.is_empty());
+        assert!(record.to_string().contains("\nNo path restrictions\n"));
+        record.verify()?;
+
+        // A single restriction replaces `/**`
+        let template = BsmsRecord::from_descriptor(DESCRIPTOR)?;
+        let record = BsmsRecord {
+            path_restrictions: vec!["/0/*".to_owned()],
+            ..template.clone()
+        };
+        assert_eq!(parse(&record.descriptor()?)?, parse(&receive)?);
+        record.verify()?;
+
+        // Unrestricted `/**` means receive and change
+        let record = BsmsRecord {
+            path_restrictions: vec![],
+            ..template
+        };
+        assert_eq!(parse(&record.descriptor()?)?, parse(DESCRIPTOR)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bsms_rejects_invalid_records() -> Result<()> {
+        let record = BsmsRecord::from_descriptor(DESCRIPTOR)?;
+        let error = |record: &BsmsRecord| {
+            record
+                .verify()
+                .err()
+                .and_then(|e| e.downcast::<WalletError>().ok())
+        };
+
+        // First address of another wallet
+        let other = BsmsRecord::from_descriptor(&DESCRIPTOR.replace("2,", "1,"))?;
+        let tampered = BsmsRecord {
+            first_address: other.first_address,
+            ..record.clone()
+        };
+        assert!(matches!(
+            error(&tampered),
+            Some(WalletError::FirstAddressMismatch)
+        ));
+
+        // Template edited after the checksum was computed
+        let tampered = BsmsRecord {
+            descriptor_template: record.descriptor_template.replace("2,", "1,"),
+            ..record.clone()
+        };
+        assert!(matches!(
+            error(&tampered),
+            Some(WalletError::InvalidDescriptorSyntax)
+        ));
+
+        let text = record.to_string();
+        for invalid in [
+            text.replace("BSMS 1.0", "BSMS 2.0"),
+            text.replace("/0/*,/1/*", "/0/1"),
+            text.lines().take(3).collect::<Vec<_>>().join("\n"),
+        ] {
+            assert!(matches!(
+                BsmsRecord::parse(&invalid)
+                    .err()
+                    .and_then(|e| e.downcast::<WalletError>().ok()),
+                Some(WalletError::InvalidBsmsRecord)
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bsms_encryption() -> Result<()> {
+        let token = BsmsToken::generate(false)?;
+        assert_eq!(token.to_string().len(), 16);
+        assert_eq!(BsmsToken::generate(true)?.to_string().len(), 32);
+        assert_eq!(BsmsToken::parse(&token.to_string())?, token);
+
+        let record = BsmsRecord::from_descriptor(DESCRIPTOR)?.to_string();
+        let payload = token.encrypt(&record)?;
+        assert!(!payload.contains("BSMS"));
+        assert_eq!(payload.len(), 64 + 2 * record.len());
+        assert_eq!(token.decrypt(&payload)?, record);
+
+        // Tampered payloads and other sessions fail authentication
+        let mut tampered = hex::decode(&payload)?;
+        tampered[40] ^= 1;
+        let other = BsmsToken::generate(false)?;
+        for result in [
+            token.decrypt(&hex::encode(tampered)),
+            other.decrypt(&payload),
+        ] {
+            assert!(matches!(
+                result.err().and_then(|e| e.downcast::<WalletError>().ok()),
+                Some(WalletError::BsmsAuthenticationFailed)
+            ));
+        }
+
+        // A zero token leaves payloads in the clear
+        let clear = BsmsToken::parse("00")?;
+        assert!(!clear.is_encrypted());
+        assert_eq!(clear.encrypt(&record)?, record);
+
+        assert!(BsmsToken::parse("0102").is_err());
+        assert!(BsmsToken::parse("not hex").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bsms_token_vectors() -> Result<()> {
+        // Derived key, MAC and ciphertext computed apart from this module, with
+        // Python's hashlib/hmac and the cryptography package's AES-256-CTR
+        let data =
+            "BSMS 1.0\n/0/*,/1/*\nbc1qhs4u273g4azq7kqqpe6vh5wfhasfmrq7nheyzsnq77humd7rwtkqagvakf";
+        let vectors = [
+            (
+                "a54044308ceac9b7",
+                "847fba0c67fac00c7db56b106ac5040de236765fbb7e3bf7dedcbb6b3baeaa61",
+                "b241cc4e70efda9da91f20152288ad311d12638ae5f40ff2c6a3d971a70b64d4",
+                "d4bcd5404a5264305d2f6b5494285fab4d128d22c711733a673184782c6295f63d31822880e8644d3fe88f831b7bc4cdb30456976a435856e4a7cf35952af421a20a690ce9da1162e782e737918fe153e6",
+            ),
+            (
+                "06ee5e15bdf1ba3a3cb8ef7d2f0adc27",
+                "f24787291fd993fc34a678d2363f661b69ca34bc9bae18084022f676a8a1293e",
+                "3d689eccbb9a74cd2c5454c6d1c873fb5c84a66253bdbfa20a556eb2d2cd5b86",
+                "9ba4be19bc9107a82ed5445d18447bde3b58965a00f1778e81a3837582fc54c64275dd734fb22c17f9cbd2368f82790d59a4cc4a15e10aed0e91aa09a55a64c8a1ce33ff22e9a81d09d5976cab1bca0f36",
+            ),
+        ];
+
+        for (token, key, mac, ciphertext) in vectors {
+            let token = BsmsToken::parse(token)?;
+            let encryption_key = token.encryption_key();
+            assert_eq!(hex::encode(encryption_key), key);
+            assert_eq!(
+                hex::encode(token.mac(&encryption_key, data.as_bytes())),
+                mac
+            );
+
+            let payload = format!("{mac}{ciphertext}");
+            assert_eq!(token.encrypt(data)?, payload);
+            assert_eq!(token.decrypt(&payload)?, data);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bsms_key_record() -> Result<()> {
+        use bdk_wallet::bitcoin::bip32::{DerivationPath, Xpriv, Xpub};
+        use bdk_wallet::bitcoin::secp256k1::Message;
+
+        let secp = Secp256k1::new();
+        let master = Xpriv::new_master(Network::Bitcoin, &[7; 32])?;
+        let path: DerivationPath = "48h/0h/0h/2h".parse()?;
+        let account = master.derive_priv(&secp, &path)?;
+        let xpub = Xpub::from_priv(&secp, &account);
+        let token = BsmsToken::generate(false)?;
+
+        let sign = |record: &BsmsKeyRecord| {
+            let digest = signed_msg_hash(&record.signed_message());
+            let signature = secp.sign_ecdsa_recoverable(
+                &Message::from_digest(digest.to_byte_array()),
+                &account.private_key,
+            );
+            BsmsKeyRecord {
+                signature: MessageSignature::new(signature, true).to_base64(),
+                ..record.clone()
+            }
+        };
+        let record = sign(&BsmsKeyRecord {
+            token: token.to_string(),
+            key: format!("[{}/{}]{}", master.fingerprint(&secp), path, xpub),
+            description: "Signer 1".to_owned(),
+            signature: String::new(),
+        });
+
+        let parsed = BsmsKeyRecord::parse(&record.to_string())?;
+        assert_eq!(parsed, record);
+        let key = parsed.verify(&token)?;
+        assert_eq!(key.mfp(), master.fingerprint(&secp));
+        assert_eq!(key.xpub()?, xpub);
+        assert_eq!(key.derivation_path()?, path);
+
+        let error = |record: &BsmsKeyRecord, token: &BsmsToken| {
+            record
+                .verify(token)
+                .err()
+                .and_then(|e| e.downcast::<WalletError>().ok())
+        };
+
+        // Edited after signing
+        let tampered = BsmsKeyRecord {
+            description: "Signer 2".to_owned(),
+            ..record.clone()
+        };
+        assert!(matches!(
+            error(&tampered, &token),
+            Some(WalletError::InvalidKeyRecordSignature)
+        ));
+
+        // Signed by another key
+        let other = Xpub::from_priv(
+            &secp,
+            &master.derive_priv(&secp, &"48h/0h/1h/2h".parse::<DerivationPath>()?)?,
+        );
+        let swapped = BsmsKeyRecord {
+            key: format!("[{}/48h/0h/1h/2h]{}", master.fingerprint(&secp), other),
+            ..record.clone()
+        };
+        assert!(matches!(
+            error(&swapped, &token),
+            Some(WalletError::InvalidKeyRecordSignature)
+        ));
+
+        // From another session
+        assert!(matches!(
+            error(&record, &BsmsToken::generate(false)?),
+            Some(WalletError::InvalidBsmsToken)
+        ));
+
+        // Keys must have an origin and no derivation steps
+        for key in [xpub.to_string(), format!("{}/0/*", record.key)] {
+            let record = sign(&BsmsKeyRecord {
+                key,
+                ..record.clone()
+            });
+            assert!(matches!(
+                error(&record, &token),
+                Some(WalletError::InvalidBsmsRecord)
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt;
+
+use anyhow::Result;
+use bdk_wallet::bitcoin::bip32::{DerivationPath, Fingerprint, Xpub};
+use bdk_wallet::bitcoin::Network;
+use serde_json::{json, Value};
+
+use crate::core::descriptor_parser::DescriptorParser;
+use crate::core::error::WalletError;
+use crate::core::pubkey::{AccountXpub, PubKey};
+use crate::core::wallet::WalletType;
+
+/// Cosigner of a Caravan wallet
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaravanKey {
+    pub name: String,
+    pub xfp: Fingerprint,
+    pub bip32_path: DerivationPath,
+    pub xpub: Xpub,
+}
+
+/// Caravan multisig wallet configuration (JSON)
+///
+/// Caravan wallets are sortedmulti, deriving `/<0;1>/*` addresses. Keys
+/// must come with their fingerprint and path, Caravan's `Unknown` ones
+/// can't make a descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaravanWallet {
+    pub name: String,
+    pub network: Network,
+    pub threshold: usize,
+    /// P2SH, P2SH_WSH or P2WSH
+    pub wallet_type: WalletType,
+    pub keys: Vec<CaravanKey>,
+}
+
+impl CaravanWallet {
+    pub fn parse(config: &str) -> Result<Self> {
+        let json: Value =
+            serde_json::from_str(config).map_err(|_| WalletError::InvalidCaravanConfig)?;
+        let count = |value: &Value| {
+            value
+                .as_u64()
+                .map(|n| n as usize)
+                .ok_or(WalletError::InvalidCaravanConfig)
+        };
+
+        let wallet_type = match Self::field(&json["addressType"])? {
+            "P2SH" => WalletType::P2SH,
+            "P2SH-P2WSH" => WalletType::P2SH_WSH,
+            "P2WSH" => WalletType::P2WSH,
+            _ => Err(WalletError::InvalidCaravanConfig)?,
+        };
+        let network = match Self::field(&json["network"])? {
+            "mainnet" => Network::Bitcoin,
+            "testnet" => Network::Testnet,
+            "signet" => Network::Signet,
+            "regtest" => Network::Regtest,
+            _ => Err(WalletError::InvalidCaravanConfig)?,
+        };
+
+        let keys = json["extendedPublicKeys"]
+            .as_array()
+            .ok_or(WalletError::InvalidCaravanConfig)?
+            .iter()
+            .map(|key| {
+                Ok(CaravanKey {
+                    name: key["name"].as_str().unwrap_or_default().to_owned(),
+                    xfp: Self::field(&key["xfp"])?
+                        .parse()
+                        .map_err(|_| WalletError::InvalidCaravanConfig)?,
+                    bip32_path: Self::field(&key["bip32Path"])?
+                        .parse()
+                        .map_err(|_| WalletError::InvalidCaravanConfig)?,
+                    xpub: PubKey::xpub_from_slip132(Self::field(&key["xpub"])?)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let threshold = count(&json["quorum"]["requiredSigners"])?;
+        let total = count(&json["quorum"]["totalSigners"])?;
+        if total != keys.len() || threshold == 0 || threshold > total {
+            Err(WalletError::InvalidCaravanConfig)?;
+        }
+
+        Ok(Self {
+            name: json["name"].as_str().unwrap_or_default().to_owned(),
+            network,
+            threshold,
+            wallet_type,
+            keys,
+        })
+    }
+
+    fn field(value: &Value) -> Result<&str> {
+        Ok(value.as_str().ok_or(WalletError::InvalidCaravanConfig)?)
+    }
+
+    /// sortedmulti descriptor of the wallet, with checksum
+    pub fn descriptor(&self) -> Result<String> {
+        let keys: Vec<AccountXpub> = self
+            .keys
+            .iter()
+            .map(|key| (key.xfp, key.bip32_path.clone(), key.xpub))
+            .collect();
+        let parser = DescriptorParser::from_sorted_multi(&self.wallet_type, self.threshold, &keys)?;
+        Ok(parser.descriptor()?.to_string())
+    }
+
+    /// Caravan wallet of a descriptor, on `network`
+    ///
+    /// Keys are named by position.
+    pub fn from_descriptor(name: &str, descriptor: &str, network: Network) -> Result<Self> {
+        let (wallet_type, threshold, keys) =
+            DescriptorParser::parse(descriptor)?.export_keys("Caravan wallets", false)?;
+
+        Ok(Self {
+            name: name.to_owned(),
+            network,
+            threshold,
+            wallet_type,
+            keys: keys
+                .into_iter()
+                .enumerate()
+                .map(|(i, (xfp, bip32_path, xpub))| CaravanKey {
+                    name: format!("Key {}", i + 1),
+                    xfp,
+                    bip32_path,
+                    xpub,
+                })
+                .collect(),
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        let address_type = match self.wallet_type {
+            WalletType::P2SH => "P2SH",
+            WalletType::P2SH_WSH => "P2SH-P2WSH",
+            WalletType::P2WSH => "P2WSH",
+            _ => Err(WalletError::InvalidCaravanConfig)?,
+        };
+        // Caravan has no testnet4
+        let network = match self.network {
+            Network::Bitcoin => "mainnet",
+            Network::Testnet | Network::Testnet4 => "testnet",
+            Network::Signet => "signet",
+            Network::Regtest => "regtest",
+        };
+        let keys: Vec<Value> = self
+            .keys
+            .iter()
+            .map(|key| {
+                json!({
+                    "name": key.name,
+                    "xfp": key.xfp.to_string(),
+                    "bip32Path": PubKey::origin_path(&key.bip32_path),
+                    "xpub": key.xpub.to_string(),
+                    "method": "text",
+                })
+            })
+            .collect();
+
+        let json = json!({
+            "name": self.name,
+            "addressType": address_type,
+            "network": network,
+            "client": { "type": "public" },
+            "quorum": {
+                "requiredSigners": self.threshold,
+                "totalSigners": self.keys.len(),
+            },
+            "extendedPublicKeys": keys,
+            "startingAddressIndex": 0,
+        });
+        Ok(serde_json::to_string_pretty(&json)?)
+    }
+}
+
+impl fmt::Display for CaravanWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_json().map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"{
+        "name": "Vault",
+        "addressType": "P2WSH",
+        "network": "testnet",
+        "client": { "type": "public" },
+        "quorum": { "requiredSigners": 2, "totalSigners": 2 },
+        "extendedPublicKeys": [
+            {
+                "name": "Alice",
+                "bip32Path": "m/48'/1'/0'/2'",
+                "xpub": "tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba",
+                "xfp": "73c5da0a",
+                "method": "text"
+            },
+            {
+                "name": "Bob",
+                "bip32Path": "m/48'/1'/0'/2'",
+                "xpub": "tpubDChwdeVd7pBThLN5uKs5m83Eqv6ozCiLibqpswK3VtMFZcGv8L9ZUq6V56UYMzKfM4Bfsgy2b9HrFhRSoSKp1f3omLp17G74m4CzkUKsicG",
+                "xfp": "089177d9",
+                "method": "text"
+            }
+        ],
+        "startingAddressIndex": 0
+    }"#;
+
+    #[test]
+    fn test_caravan_roundtrip() -> Result<()> {
+        let wallet = CaravanWallet::parse(CONFIG)?;
+        assert_eq!(wallet.name, "Vault");
+        assert_eq!(wallet.network, Network::Testnet);
+        assert_eq!(wallet.threshold, 2);
+        assert_eq!(wallet.wallet_type, WalletType::P2WSH);
+        assert_eq!(wallet.keys[1].name, "Bob");
+
+        let descriptor = wallet.descriptor()?;
+        assert!(descriptor.starts_with("wsh(sortedmulti(2,[73c5da0a/48'/1'/0'/2']tpubDC5F"));
+
+        // Key names don't make it to descriptors
+        let exported = CaravanWallet::from_descriptor("Vault", &descriptor, Network::Testnet)?;
+        assert_eq!(exported.keys[1].name, "Key 2");
+        assert_eq!(exported.descriptor()?, descriptor);
+        assert_eq!(CaravanWallet::parse(&exported.to_json()?)?, exported);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_caravan_invalid_config() {
+        let is_invalid = |config: &str| {
+            matches!(
+                CaravanWallet::parse(config)
+                    .expect_err("invalid")
+                    .downcast_ref::<WalletError>(),
+                Some(WalletError::InvalidCaravanConfig)
+            )
+        };
+
+        assert!(is_invalid(
+            &CONFIG.replace("\"totalSigners\": 2", "\"totalSigners\": 3")
+        ));
+        assert!(is_invalid(
+            &CONFIG.replace("\"requiredSigners\": 2", "\"requiredSigners\": 0")
+        ));
+        assert!(is_invalid(&CONFIG.replace("P2WSH", "P2TR")));
+        assert!(is_invalid(
+            &CONFIG.replace("\"xfp\": \"73c5da0a\"", "\"xfp\": \"Unknown\"")
+        ));
+    }
+}
@@ -0,0 +1,278 @@
+use std::fmt;
+
+use anyhow::Result;
+use bdk_wallet::bitcoin::bip32::{DerivationPath, Fingerprint, Xpub};
+
+use crate::core::descriptor_parser::DescriptorParser;
+use crate::core::error::WalletError;
+use crate::core::pubkey::{AccountXpub, PubKey};
+use crate::core::wallet::WalletType;
+
+/// Longest wallet name a Coldcard accepts
+const MAX_NAME_LEN: usize = 20;
+
+/// Cosigner of a Coldcard multisig
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColdcardKey {
+    pub xfp: Fingerprint,
+    pub derivation: DerivationPath,
+    pub xpub: Xpub,
+}
+
+/// Coldcard multisig setup file, also read by many other hardware wallets
+///
+/// ```text
+/// Name: Vault
+/// Policy: 2 of 3
+/// Derivation: m/48'/0'/0'/2'
+/// Format: P2WSH
+///
+/// 0F056943: xpub...
+/// ```
+///
+/// A `Derivation:` line applies to the keys after it, so keys may have their
+/// own. Keys always derive receive and change addresses with `/<0;1>/*`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColdcardMultisig {
+    pub name: String,
+    pub threshold: usize,
+    /// P2SH, P2SH_WSH or P2WSH
+    pub wallet_type: WalletType,
+    pub keys: Vec<ColdcardKey>,
+}
+
+impl ColdcardMultisig {
+    pub fn parse(file: &str) -> Result<Self> {
+        let mut name = None;
+        let mut policy = None;
+        let mut derivation = None;
+        // Coldcard's default
+        let mut wallet_type = WalletType::P2SH;
+        let mut keys = Vec::new();
+
+        for line in file.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (label, value) = line
+                .split_once(':')
+                .map(|(label, value)| (label.trim(), value.trim()))
+                .ok_or(WalletError::InvalidColdcardFile)?;
+
+            match label.to_ascii_lowercase().as_str() {
+                "name" => name = Some(value.to_owned()),
+                "policy" => policy = Some(Self::parse_policy(value)?),
+                "derivation" => {
+                    derivation = Some(
+                        value
+                            .parse::<DerivationPath>()
+                            .map_err(|_| WalletError::InvalidColdcardFile)?,
+                    )
+                }
+                "format" => wallet_type = Self::parse_format(value)?,
+                _ => {
+                    let xfp = label
+                        .parse::<Fingerprint>()
+                        .map_err(|_| WalletError::InvalidColdcardFile)?;
+                    keys.push(ColdcardKey {
+                        xfp,
+                        derivation: derivation.clone().ok_or(WalletError::InvalidColdcardFile)?,
+                        xpub: PubKey::xpub_from_slip132(value)?,
+                    });
+                }
+            }
+        }
+
+        let (threshold, total) = policy.ok_or(WalletError::InvalidColdcardFile)?;
+        if total != keys.len() || threshold == 0 || threshold > total {
+            Err(WalletError::InvalidColdcardFile)?;
+        }
+
+        Ok(Self {
+            name: name.ok_or(WalletError::InvalidColdcardFile)?,
+            threshold,
+            wallet_type,
+            keys,
+        })
+    }
+
+    /// `M of N`
+    fn parse_policy(policy: &str) -> Result<(usize, usize)> {
+        let (threshold, total) = policy
+            .split_once(" of ")
+            .ok_or(WalletError::InvalidColdcardFile)?;
+        let parse = |n: &str| {
+            n.trim()
+                .parse()
+                .map_err(|_| WalletError::InvalidColdcardFile)
+        };
+        Ok((parse(threshold)?, parse(total)?))
+    }
+
+    fn parse_format(format: &str) -> Result<WalletType> {
+        match format.to_ascii_uppercase().as_str() {
+            "P2SH" => Ok(WalletType::P2SH),
+            "P2SH-P2WSH" | "P2WSH-P2SH" => Ok(WalletType::P2SH_WSH),
+            "P2WSH" => Ok(WalletType::P2WSH),
+            _ => Err(WalletError::InvalidColdcardFile.into()),
+        }
+    }
+
+    fn format(&self) -> Result<&'static str> {
+        match self.wallet_type {
+            WalletType::P2SH => Ok("P2SH"),
+            WalletType::P2SH_WSH => Ok("P2SH-P2WSH"),
+            WalletType::P2WSH => Ok("P2WSH"),
+            _ => Err(WalletError::InvalidColdcardFile.into()),
+        }
+    }
+
+    /// sortedmulti descriptor of the multisig, with checksum
+    pub fn descriptor(&self) -> Result<String> {
+        let keys: Vec<AccountXpub> = self
+            .keys
+            .iter()
+            .map(|key| (key.xfp, key.derivation.clone(), key.xpub))
+            .collect();
+        let parser = DescriptorParser::from_sorted_multi(&self.wallet_type, self.threshold, &keys)?;
+        Ok(parser.descriptor()?.to_string())
+    }
+
+    /// Setup file of a descriptor, named `name`
+    ///
+    /// Names are cut to the 20 characters a Coldcard accepts.
+    pub fn from_descriptor(name: &str, descriptor: &str) -> Result<Self> {
+        let (wallet_type, threshold, keys) =
+            DescriptorParser::parse(descriptor)?.export_keys("Coldcard multisig files", false)?;
+
+        Ok(Self {
+            name: name.chars().take(MAX_NAME_LEN).collect(),
+            threshold,
+            wallet_type,
+            keys: keys
+                .into_iter()
+                .map(|(xfp, derivation, xpub)| ColdcardKey {
+                    xfp,
+                    derivation,
+                    xpub,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl fmt::Display for ColdcardMultisig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // One shared derivation, unless the keys have their own
+        let shared = self
+            .keys
+            .first()
+            .map(|first| &first.derivation)
+            .filter(|first| self.keys.iter().all(|key| &key.derivation == *first));
+
+        writeln!(f, "# Coldcard Multisig setup file")?;
+        writeln!(f, "#")?;
+        writeln!(f, "Name: {}", self.name)?;
+        writeln!(f, "Policy: {} of {}", self.threshold, self.keys.len())?;
+        if let Some(shared) = shared {
+            writeln!(f, "Derivation: {}", PubKey::origin_path(shared))?;
+        }
+        writeln!(f, "Format: {}", self.format().map_err(|_| fmt::Error)?)?;
+        for key in &self.keys {
+            writeln!(f)?;
+            if shared.is_none() {
+                writeln!(f, "Derivation: {}", PubKey::origin_path(&key.derivation))?;
+            }
+            writeln!(f, "{}: {}", key.xfp.to_string().to_uppercase(), key.xpub)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::bitcoin::base58;
+
+    const XPUB_A: &str = "xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn";
+    const XPUB_B: &str = "xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj";
+
+    fn file() -> String {
+        format!(
+            "# Coldcard Multisig setup file\n\
+             Name: Vault\n\
+             Policy: 2 of 2\n\
+             Derivation: m/48'/0'/0'/2'\n\
+             Format: P2WSH\n\
+             \n\
+             C449C5C5: {XPUB_A}\n\
+             C61AF686: {XPUB_B}\n"
+        )
+    }
+
+    #[test]
+    fn test_coldcard_roundtrip() -> Result<()> {
+        let multisig = ColdcardMultisig::parse(&file())?;
+        assert_eq!(multisig.name, "Vault");
+        assert_eq!(multisig.threshold, 2);
+        assert_eq!(multisig.wallet_type, WalletType::P2WSH);
+        assert_eq!(multisig.keys.len(), 2);
+
+        let descriptor = multisig.descriptor()?;
+        assert!(descriptor.starts_with("wsh(sortedmulti(2,[c449c5c5/48'/0'/0'/2']"));
+
+        let exported = ColdcardMultisig::from_descriptor("Vault", &descriptor)?;
+        assert_eq!(exported, multisig);
+        assert_eq!(ColdcardMultisig::parse(&exported.to_string())?, multisig);
+
+        // Names are cut to what a Coldcard accepts
+        let exported = ColdcardMultisig::from_descriptor("A very long wallet name", &descriptor)?;
+        assert_eq!(exported.name.len(), MAX_NAME_LEN);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coldcard_key_derivations() -> Result<()> {
+        let file = format!(
+            "Name: Mixed\n\
+             Policy: 1 of 2\n\
+             Format: P2SH-P2WSH\n\
+             Derivation: m/48'/0'/0'/1'\n\
+             C449C5C5: {XPUB_A}\n\
+             Derivation: m/45'\n\
+             C61AF686: {XPUB_B}\n"
+        );
+        let multisig = ColdcardMultisig::parse(&file)?;
+        assert_eq!(multisig.wallet_type, WalletType::P2SH_WSH);
+        assert_eq!(multisig.keys[0].derivation, "m/48'/0'/0'/1'".parse()?);
+        assert_eq!(multisig.keys[1].derivation, "m/45'".parse()?);
+        assert!(multisig.descriptor()?.starts_with("sh(wsh(sortedmulti(1,"));
+
+        // Each key keeps its own derivation line
+        let text = multisig.to_string();
+        assert_eq!(text.matches("Derivation:").count(), 2);
+        assert_eq!(ColdcardMultisig::parse(&text)?, multisig);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coldcard_slip132_keys() -> Result<()> {
+        // Same key as XPUB_A, with its Zpub version
+        let mut data = base58::decode_check(XPUB_A)?;
+        data[..4].copy_from_slice(&[0x02, 0xaa, 0x7e, 0xd3]);
+        let zpub = base58::encode_check(&data);
+        assert!(zpub.starts_with("Zpub"));
+
+        let file = file().replace(XPUB_A, &zpub);
+        let multisig = ColdcardMultisig::parse(&file)?;
+        assert_eq!(multisig, ColdcardMultisig::parse(&self::file())?);
+
+        // Keys must match the policy
+        let file = file.replace("2 of 2", "2 of 3");
+        assert!(ColdcardMultisig::parse(&file).is_err());
+
+        Ok(())
+    }
+}
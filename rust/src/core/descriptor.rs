@@ -1,9 +1,13 @@
 use anyhow::Result;
 use bdk_wallet::bitcoin::Network;
+use bdk_wallet::keys::DescriptorPublicKey;
+use bdk_wallet::miniscript::Descriptor;
+use bdk_wallet::KeychainKind;
 
-use crate::core::descriptor_parser::DescriptorParser;
+use crate::core::address::{AddressDeriver, DerivedAddress};
+use crate::core::descriptor_parser::{DescriptorParser, ParsedDescriptor};
 use crate::core::pubkey::PubKey;
-use crate::core::spend_path::SpendPath;
+use crate::core::spend_path::{SpendPath, Spendability};
 use crate::core::wallet::WalletType;
 
 /// High-level descriptor analysis without wallet creation
@@ -11,10 +15,10 @@ use crate::core::wallet::WalletType;
 /// This facade provides a clean API for analyzing descriptors without
 /// requiring creation of a full persistent wallet. It orchestrates:
 /// - Descriptor parsing and validation
-/// - Network detection (with minimal wallet creation)
+/// - Network detection (from the key version bytes)
 /// - Wallet type detection
 /// - Public key extraction
-/// - Spend path analysis (creates temporary wallet for weight calculation)
+/// - Spend path analysis (policies and weights from the descriptor itself)
 pub struct DescriptorAnalyzer {
     parser: DescriptorParser,
     network: Network,
@@ -25,12 +29,19 @@ impl DescriptorAnalyzer {
     ///
     /// Performs parsing, validation, and network detection.
     /// This is much faster than creating a full wallet.
-    ///
-    /// Network detection avoids wallet creation for mainnet descriptors
-    /// (uses xpub prefix detection). Testnet variants may create 1-4 temporary
-    /// wallets for detection, which is still better than the old approach (5 wallets).
     pub fn analyze(descriptor: &str) -> Result<Self> {
-        let parser = DescriptorParser::parse(descriptor)?;
+        Self::from_parser(DescriptorParser::parse(descriptor)?)
+    }
+
+    /// Analyze a receive/change descriptor pair as a single wallet
+    ///
+    /// The pair is merged into one multipath descriptor, which is what
+    /// `descriptor_str` returns.
+    pub fn analyze_pair(external: &str, internal: &str) -> Result<Self> {
+        Self::from_parser(DescriptorParser::parse_pair(external, internal)?)
+    }
+
+    fn from_parser(parser: DescriptorParser) -> Result<Self> {
         let network = parser.detect_network()?;
 
         Ok(Self { parser, network })
@@ -54,34 +65,103 @@ impl DescriptorAnalyzer {
     /// Uses the ForEachKey trait directly on the descriptor.
     /// No wallet creation required.
     pub fn public_keys(&self) -> Result<Vec<PubKey>> {
-        PubKey::extract_from_descriptor(self.parser.descriptor())
+        match self.parser.parsed() {
+            ParsedDescriptor::Miniscript(descriptor) => PubKey::extract_from_descriptor(descriptor),
+            ParsedDescriptor::Special(special) => {
+                let mut keys = Vec::new();
+                for key in special.keys() {
+                    let pk = PubKey::try_from(key.clone())?;
+                    if !pk.is_unspendable() {
+                        keys.push(pk);
+                    }
+                }
+                Ok(keys)
+            }
+        }
     }
 
     /// Extract spend paths with weight calculations
     ///
-    /// NOTE: This creates a temporary wallet for weight calculation,
-    /// which requires actual transaction building via build_tx().
-    /// This is unavoidable but acceptable - we create ONE temporary
-    /// wallet instead of keeping a persistent wallet.
+    /// Policies are extracted from the descriptor, and weights computed from
+    /// the miniscript satisfactions, without building transactions.
     pub fn spend_paths(&self) -> Result<Vec<SpendPath>> {
-        SpendPath::extract_from_descriptor(self.parser.descriptor(), self.network)
+        match self.parser.parsed() {
+            ParsedDescriptor::Miniscript(descriptor) => {
+                SpendPath::extract_from_descriptor(descriptor, self.network)
+            }
+            ParsedDescriptor::Special(special) => special.spend_paths(self.network),
+        }
+    }
+
+    /// Whether the spend paths tell how the outputs are spent
+    ///
+    /// `addr()` and `raw()` descriptors only know the output script, so
+    /// they have no spend paths and are `Unknown`.
+    pub fn spendability(&self) -> Spendability {
+        match self.parser.parsed() {
+            ParsedDescriptor::Miniscript(_) => Spendability::Known,
+            ParsedDescriptor::Special(special) => special.spendability(),
+        }
+    }
+
+    fn address_deriver(&self) -> Result<AddressDeriver> {
+        match self.parser.parsed() {
+            ParsedDescriptor::Miniscript(descriptor) => {
+                AddressDeriver::new(descriptor, self.network)
+            }
+            ParsedDescriptor::Special(special) => {
+                AddressDeriver::from_special(special, self.network)
+            }
+        }
+    }
+
+    /// Derive `count` addresses of a keychain starting at `start`
+    ///
+    /// Uses the detected network. No wallet creation required.
+    pub fn addresses(
+        &self,
+        keychain: KeychainKind,
+        start: u32,
+        count: u32,
+    ) -> Result<Vec<DerivedAddress>> {
+        self.address_deriver()?.derive_range(keychain, start, count)
+    }
+
+    /// Find the keychain and index of an address, scanning up to `gap` indexes
+    ///
+    /// Returns `None` if the address doesn't belong to the descriptor within the gap.
+    pub fn find_address(&self, address: &str, gap: u32) -> Result<Option<DerivedAddress>> {
+        self.address_deriver()?.find(address, gap)
     }
 
     /// Get the original descriptor string
     pub fn descriptor_str(&self) -> &str {
         self.parser.descriptor_str()
     }
+
+    /// Access to the miniscript descriptor
+    ///
+    /// Fails for special descriptors.
+    pub fn descriptor(&self) -> Result<&Descriptor<DescriptorPublicKey>> {
+        self.parser.descriptor()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::error::WalletError;
+
+    const XPUB_A: &str = "xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn";
+    const XPUB_B: &str = "xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj";
+    const TPUB_A: &str = "tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba";
+    const TPUB_B: &str = "tpubDChwdeVd7pBThLN5uKs5m83Eqv6ozCiLibqpswK3VtMFZcGv8L9ZUq6V56UYMzKfM4Bfsgy2b9HrFhRSoSKp1f3omLp17G74m4CzkUKsicG";
 
     #[test]
     fn test_analyze_p2pkh_testnet() -> Result<()> {
-        let descriptor = "pkh([73c5da0a/44h/1h/0h]tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba/<0;1>/*)#0x5u8d5c";
+        let descriptor = format!("pkh([73c5da0a/44h/1h/0h]{TPUB_A}/<0;1>/*)#0x5u8d5c");
 
-        let analyzer = DescriptorAnalyzer::analyze(descriptor)?;
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
 
         assert_eq!(analyzer.network(), Network::Testnet);
         assert_eq!(analyzer.wallet_type(), WalletType::P2PKH);
@@ -98,9 +178,9 @@ mod tests {
 
     #[test]
     fn test_analyze_p2wpkh_testnet() -> Result<()> {
-        let descriptor = "wpkh([089177d9/84h/1h/0h]tpubDChwdeVd7pBThLN5uKs5m83Eqv6ozCiLibqpswK3VtMFZcGv8L9ZUq6V56UYMzKfM4Bfsgy2b9HrFhRSoSKp1f3omLp17G74m4CzkUKsicG/<0;1>/*)#uxw7vpfc";
+        let descriptor = format!("wpkh([089177d9/84h/1h/0h]{TPUB_B}/<0;1>/*)#uxw7vpfc");
 
-        let analyzer = DescriptorAnalyzer::analyze(descriptor)?;
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
 
         assert_eq!(analyzer.network(), Network::Testnet);
         assert_eq!(analyzer.wallet_type(), WalletType::P2WPKH);
@@ -116,9 +196,9 @@ mod tests {
 
     #[test]
     fn test_analyze_p2wsh_multisig_mainnet() -> Result<()> {
-        let descriptor = "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/<0;1>/*))#0wct5td0";
+        let descriptor = format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*))#0wct5td0");
 
-        let analyzer = DescriptorAnalyzer::analyze(descriptor)?;
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
 
         assert_eq!(analyzer.network(), Network::Bitcoin);
         assert_eq!(analyzer.wallet_type(), WalletType::P2WSH);
@@ -132,4 +212,425 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_spend_paths_on_every_network() -> Result<()> {
+        // Keys are checked against the network the paths are extracted for
+        let xpub = format!("wpkh([c449c5c5/84h/0h/0h]{XPUB_A}/<0;1>/*)").parse()?;
+        let tpub = format!("wpkh([73c5da0a/84h/1h/0h]{TPUB_A}/<0;1>/*)").parse()?;
+
+        for (descriptor, network) in [
+            (&xpub, Network::Bitcoin),
+            (&tpub, Network::Testnet),
+            (&tpub, Network::Signet),
+            (&tpub, Network::Regtest),
+        ] {
+            let spend_paths = SpendPath::extract_from_descriptor(descriptor, network)?;
+            assert_eq!(spend_paths.len(), 1, "{network}");
+            assert!(spend_paths[0].wu_in > 0, "{network}");
+        }
+        assert!(SpendPath::extract_from_descriptor(&xpub, Network::Testnet).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_hashlock_paths() -> Result<()> {
+        use crate::core::spend_path::HashType;
+
+        // HTLC: A with the preimage, or B after 144 blocks
+        let digest = "9f64a747e1b97f131fabb6b447296c9b6f0201e79fb3c5356e6c77e89b6a806a";
+        let descriptor = format!("wsh(andor(pk([c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*),sha256({digest}),and_v(v:pk([c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*),older(144))))");
+
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+        let spend_paths = analyzer.spend_paths()?;
+        assert_eq!(spend_paths.len(), 2);
+
+        let hash_path = spend_paths
+            .iter()
+            .find(|sp| !sp.hashlocks.is_empty())
+            .expect("hashlock path");
+        assert_eq!(hash_path.mfps, vec!["c449c5c5".to_string()]);
+        assert_eq!(hash_path.hashlocks.len(), 1);
+        assert_eq!(hash_path.hashlocks[0].hash_type, HashType::Sha256);
+        assert_eq!(hash_path.hashlocks[0].digest, digest);
+
+        let timelock_path = spend_paths
+            .iter()
+            .find(|sp| sp.hashlocks.is_empty())
+            .expect("timelock path");
+        assert_eq!(timelock_path.rel_timelock, 144);
+        assert_ne!(hash_path.id, timelock_path.id);
+
+        // The 32-byte preimage (plus its length prefix) is part of the witness,
+        // on top of the empty push that skips B's branch for the timelock path
+        assert_eq!(hash_path.wu_in, timelock_path.wu_in + 32);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_nested_thresholds() -> Result<()> {
+        // 2-of-(A, 2-of-(B,C,D))
+        let descriptor = format!("wsh(and_v(v:pk([aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*),multi(2,[bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*,[cccccccc/48h/0h/0h/2h]{XPUB_B}/0/<0;1>/*,[dddddddd/48h/0h/0h/2h]{XPUB_B}/1/<0;1>/*)))");
+
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+        let spend_paths = analyzer.spend_paths()?;
+        assert_eq!(spend_paths.len(), 1);
+
+        let sp = &spend_paths[0];
+        assert_eq!(sp.threshold, 2);
+        assert_eq!(sp.mfps.len(), 4);
+        assert_eq!(sp.signer_groups.len(), 1);
+        assert_eq!(sp.signer_groups[0].threshold, 2);
+        assert_eq!(sp.signer_groups[0].mfps.len(), 3);
+
+        // A nested group gets a different ID than a flat 2-of-4
+        let flat_id = crate::core::spend_path::calculate_spend_path_id(2, &sp.mfps, 0, 0);
+        assert_ne!(sp.id, flat_id);
+        // Re-analysis is stable
+        assert_eq!(analyzer.spend_paths()?[0].id, sp.id);
+
+        // Witness: 3 signatures + multisig dummy + script
+        assert!(sp.wu_in > 3 * 72);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_nested_threshold_taproot() -> Result<()> {
+        // 2-of-(A, B, 2-of-(C,D)) in a single leaf
+        let descriptor = format!("tr(50929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0,thresh(2,pk([aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*),s:pk([bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*),a:multi_a(2,[cccccccc/48h/0h/0h/2h]{XPUB_B}/0/<0;1>/*,[dddddddd/48h/0h/0h/2h]{XPUB_B}/1/<0;1>/*)))");
+
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+        let spend_paths = analyzer.spend_paths()?;
+        assert_eq!(spend_paths.len(), 1);
+
+        let sp = &spend_paths[0];
+        assert_eq!(sp.threshold, 2);
+        assert_eq!(sp.mfps.len(), 4);
+        assert_eq!(sp.signer_groups.len(), 1);
+        assert_eq!(sp.tr_depth, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_signer_sets() -> Result<()> {
+        let analyzer = DescriptorAnalyzer::analyze(&format!("wsh(sortedmulti(2,[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*,[bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*,[cccccccc/48h/0h/0h/2h]{XPUB_B}/0/<0;1>/*))"))?;
+        let spend_paths = analyzer.spend_paths()?;
+        assert_eq!(spend_paths.len(), 1);
+
+        // AB, AC, BC with the same weight as the path itself
+        let sp = &spend_paths[0];
+        assert_eq!(sp.signer_sets.len(), 3);
+        assert!(sp.signer_sets.iter().all(|set| set.mfps.len() == 2));
+        assert!(sp.signer_sets.iter().all(|set| set.wu_in == sp.wu_in));
+        assert!(!sp.signer_sets_truncated);
+
+        // C alone, or A and B together
+        let analyzer = DescriptorAnalyzer::analyze(&format!("wsh(andor(pk([aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*),pk([bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*),pk([cccccccc/48h/0h/0h/2h]{XPUB_B}/0/<0;1>/*)))"))?;
+        let spend_paths = analyzer.spend_paths()?;
+        assert_eq!(spend_paths.len(), 1);
+
+        let sets = &spend_paths[0].signer_sets;
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].mfps, vec!["cccccccc".to_string()]);
+        assert_eq!(sets[1].mfps.len(), 2);
+        assert!(sets[0].wu_in < sets[1].wu_in);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_signer_sets_truncated() -> Result<()> {
+        let key = |i: usize| format!("[{i:08x}/48h/0h/0h/2h]{XPUB_A}/{i}/<0;1>/*");
+
+        // 4 of 11 keys, or 3 of them with both X and Y, for 495 minimal sets
+        let items: Vec<String> = (1..=10).map(|i| format!("s:pk({})", key(i))).collect();
+        let descriptor = format!(
+            "wsh(thresh(4,pk({}),{},aj:and_v(v:pk({}),pk({}))))",
+            key(0),
+            items.join(","),
+            key(11),
+            key(12),
+        );
+        let spend_paths = DescriptorAnalyzer::analyze(&descriptor)?.spend_paths()?;
+        let sp = spend_paths
+            .iter()
+            .find(|sp| sp.threshold == 4)
+            .expect("threshold path");
+
+        assert!(sp.signer_sets_truncated);
+        assert_eq!(sp.signer_sets.len(), 32);
+        // The cheapest sets are kept, none of them needs both X and Y
+        assert!(sp.signer_sets.iter().all(|set| set.mfps.len() == 4));
+        assert!(sp.signer_sets.iter().all(|set| set.wu_in == sp.wu_in));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_threshold_with_timelock() -> Result<()> {
+        // A+B now, or either one alone after 30 days
+        let descriptor = format!("wsh(thresh(2,pk([c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*),s:pk([c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*),sln:older(4320)))");
+
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+        let spend_paths = analyzer.spend_paths()?;
+        assert_eq!(spend_paths.len(), 3);
+
+        let both = &spend_paths[0];
+        assert_eq!(both.threshold, 2);
+        assert_eq!(both.mfps.len(), 2);
+        assert_eq!(both.rel_timelock, 0);
+
+        for single in &spend_paths[1..] {
+            assert_eq!(single.threshold, 1);
+            assert_eq!(single.mfps.len(), 1);
+            assert_eq!(single.rel_timelock, 4320);
+            // One signature less than the 2-key path
+            assert!(single.wu_in < both.wu_in);
+        }
+        assert_ne!(spend_paths[1].mfps, spend_paths[2].mfps);
+        assert_ne!(spend_paths[1].id, spend_paths[2].id);
+
+        // Every path selects two of the three threshold items
+        for sp in &spend_paths {
+            assert_eq!(sp.policy_path.len(), 1);
+            assert!(sp.policy_path.values().all(|path| path.len() == 2));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_taproot_threshold_with_timelock() -> Result<()> {
+        // Same policy as a single tapleaf, behind a spendable internal key
+        let descriptor = format!("tr([aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*,thresh(2,pk([c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*),s:pk([c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*),sln:older(4320)))");
+
+        let spend_paths = DescriptorAnalyzer::analyze(&descriptor)?.spend_paths()?;
+        assert_eq!(spend_paths.len(), 4);
+
+        let key_path = &spend_paths[0];
+        assert_eq!(key_path.mfps, vec!["aaaaaaaa".to_string()]);
+        assert_eq!(key_path.tr_depth, 0);
+
+        let both = &spend_paths[1];
+        assert_eq!(both.threshold, 2);
+        assert_eq!(both.mfps.len(), 2);
+        assert_eq!(both.rel_timelock, 0);
+        assert_eq!(both.tr_depth, 1);
+        assert!(key_path.wu_in < both.wu_in);
+
+        for single in &spend_paths[2..] {
+            assert_eq!(single.threshold, 1);
+            assert_eq!(single.mfps.len(), 1);
+            assert_eq!(single.rel_timelock, 4320);
+            assert_eq!(single.tr_depth, 1);
+            // One signature less than the 2-key path
+            assert!(single.wu_in < both.wu_in);
+            assert_eq!(single.signer_sets.len(), 1);
+            assert_eq!(single.signer_sets[0].wu_in, single.wu_in);
+        }
+        assert_ne!(spend_paths[2].mfps, spend_paths[3].mfps);
+        assert_eq!(spend_paths[2].wu_in, spend_paths[3].wu_in);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_too_many_spend_paths() -> Result<()> {
+        let key = |i: usize| format!("[{i:08x}/48h/0h/0h/2h]{XPUB_A}/{i}/<0;1>/*");
+
+        // 5 of 10 keys and a timelock, for 462 combinations
+        let items: Vec<String> = (1..10).map(|i| format!("s:pk({})", key(i))).collect();
+        let descriptor = format!(
+            "wsh(thresh(5,pk({}),{},sln:older(4320)))",
+            key(0),
+            items.join(","),
+        );
+        let error = DescriptorAnalyzer::analyze(&descriptor)?
+            .spend_paths()
+            .expect_err("too many paths");
+        assert!(matches!(
+            error.downcast_ref::<WalletError>(),
+            Some(WalletError::TooManySpendPaths)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_multiple_timelocks() -> Result<()> {
+        let key = format!("[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*");
+
+        let descriptor =
+            format!("wsh(and_v(v:pk({key}),and_v(v:after(100),and_v(v:after(200),older(6)))))");
+        let spend_paths = DescriptorAnalyzer::analyze(&descriptor)?.spend_paths()?;
+        assert_eq!(spend_paths.len(), 1);
+
+        let sp = &spend_paths[0];
+        assert_eq!(sp.abs_timelocks, vec![100, 200]);
+        assert_eq!(sp.abs_timelock, 200);
+        assert_eq!(sp.rel_timelocks, vec![6]);
+        assert_eq!(sp.rel_timelock, 6);
+        assert!(!sp.timelock_conflict);
+
+        // Block height and timestamp locks can't be satisfied together
+        let other = format!("[c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*");
+        let mixed = format!(
+            "wsh(or_d(pk({other}),and_v(v:pk({key}),and_v(v:after(100),after(500000001)))))"
+        );
+        let spend_paths = DescriptorAnalyzer::analyze(&mixed)?.spend_paths()?;
+        assert_eq!(spend_paths.len(), 2);
+
+        let (conflicting, others): (Vec<_>, Vec<_>) =
+            spend_paths.iter().partition(|sp| sp.timelock_conflict);
+        assert_eq!(conflicting.len(), 1);
+        assert_eq!(conflicting[0].abs_timelocks, vec![100, 500000001]);
+        assert_eq!(conflicting[0].mfps, vec!["c449c5c5"]);
+        assert!(conflicting[0].wu_in > 0);
+        assert_eq!(others[0].mfps, vec!["c61af686"]);
+        assert!(others[0].abs_timelocks.is_empty());
+
+        // Weighed like the same path with a height lock of the same size
+        let heights = format!(
+            "wsh(or_d(pk({other}),and_v(v:pk({key}),and_v(v:after(100),after(499999999)))))"
+        );
+        let expected = DescriptorAnalyzer::analyze(&heights)?.spend_paths()?;
+        let expected = expected
+            .iter()
+            .find(|sp| sp.mfps == conflicting[0].mfps)
+            .expect("height locked path");
+        assert_eq!(conflicting[0].wu_in, expected.wu_in);
+        assert_eq!(conflicting[0].signer_sets, expected.signer_sets);
+        assert_ne!(conflicting[0].id, expected.id);
+
+        let mixed = format!("wsh(and_v(v:pk({key}),and_v(v:older(100),older(4194305))))");
+        let spend_paths = DescriptorAnalyzer::analyze(&mixed)?.spend_paths()?;
+        assert_eq!(spend_paths.len(), 1);
+        assert!(spend_paths[0].timelock_conflict);
+        assert_eq!(spend_paths[0].rel_timelocks, vec![100, 4194305]);
+
+        let heights = format!("wsh(and_v(v:pk({key}),and_v(v:older(100),older(2097153))))");
+        let expected = DescriptorAnalyzer::analyze(&heights)?.spend_paths()?;
+        assert_eq!(spend_paths[0].wu_in, expected[0].wu_in);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_single_path_descriptors() -> Result<()> {
+        let multipath = DescriptorAnalyzer::analyze(&format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/<0;1>/*))"))?;
+        let single = DescriptorAnalyzer::analyze(&format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/0/*,[c61af686/48h/0h/0h/2h]{XPUB_B}/0/*))"))?;
+        assert_eq!(single.network(), Network::Bitcoin);
+
+        let expected = &multipath.spend_paths()?[0];
+        let sp = &single.spend_paths()?[0];
+        assert_eq!(sp.id, expected.id);
+        assert_eq!(sp.wu_in, expected.wu_in);
+        assert_eq!(sp.wu_out, expected.wu_out);
+
+        // Receive addresses match, there is no separate change keychain
+        assert_eq!(
+            single.addresses(KeychainKind::External, 0, 3)?[2].address,
+            multipath.addresses(KeychainKind::External, 0, 3)?[2].address
+        );
+        assert!(single.addresses(KeychainKind::Internal, 0, 1).is_err());
+
+        // Fixed keys give the same address at every index
+        let fixed = DescriptorAnalyzer::analyze(&format!("wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/0/3,[c61af686/48h/0h/0h/2h]{XPUB_B}/0/3))"))?;
+        assert_eq!(fixed.spend_paths()?.len(), 1);
+        let addresses = fixed.addresses(KeychainKind::External, 0, 2)?;
+        assert_eq!(addresses[0].address, addresses[1].address);
+        assert_eq!(
+            addresses[0].address,
+            multipath.addresses(KeychainKind::External, 3, 1)?[0].address
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_raw_keys() -> Result<()> {
+        let descriptor = format!("wsh(multi(2,02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13,[deadbeef/48h/0h/0h/2h]02c6047f9441ed7d6d3034a46e1e3ec1e8e84c6b8c9b3c1c0c6c8c27c1e6a5a1bc,[c449c5c5/48h/0h/0h/2h]{XPUB_A}/<0;1>/*))");
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+
+        let key_mfps: Vec<String> = analyzer
+            .public_keys()?
+            .iter()
+            .map(|k| k.mfp().to_string())
+            .collect();
+        let spend_paths = analyzer.spend_paths()?;
+        assert_eq!(spend_paths.len(), 1);
+
+        // Spend paths name raw keys the same way as the key list
+        let mut sorted = key_mfps.clone();
+        sorted.sort();
+        assert_eq!(spend_paths[0].mfps, sorted);
+        assert!(spend_paths[0].mfps.contains(&"deadbeef".to_string()));
+        // Every pair of keys can sign
+        assert_eq!(spend_paths[0].signer_sets.len(), 3);
+
+        // Taproot with raw x-only keys, spendable by key path
+        let descriptor = "tr(e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13,pk(c6047f9441ed7d6d3034a46e1e3ec1e8e84c6b8c9b3c1c0c6c8c27c1e6a5a1bc))";
+        let spend_paths = DescriptorAnalyzer::analyze(descriptor)?.spend_paths()?;
+        assert_eq!(spend_paths.len(), 2);
+        assert!(spend_paths.iter().all(|sp| sp.mfps.len() == 1));
+        assert_ne!(spend_paths[0].mfps, spend_paths[1].mfps);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_special_descriptors() -> Result<()> {
+        let xpub = format!("[c449c5c5/86h/0h/0h]{XPUB_A}/<0;1>/*");
+
+        // addr(): only the address is known
+        let analyzer =
+            DescriptorAnalyzer::analyze("addr(bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq)")?;
+        assert_eq!(analyzer.network(), Network::Bitcoin);
+        assert_eq!(analyzer.wallet_type(), WalletType::P2WPKH);
+        assert_eq!(analyzer.spendability(), Spendability::Unknown);
+        assert!(analyzer.public_keys()?.is_empty());
+        assert!(analyzer.spend_paths()?.is_empty());
+        let addresses = analyzer.addresses(KeychainKind::External, 0, 1)?;
+        assert_eq!(
+            addresses[0].address.to_string(),
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+        );
+        assert!(analyzer
+            .find_address("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq", 20)?
+            .is_some());
+
+        // rawtr(): a single key path spend, on an untweaked output key
+        let rawtr = DescriptorAnalyzer::analyze(&format!("rawtr({xpub})"))?;
+        let tr = DescriptorAnalyzer::analyze(&format!("tr({xpub})"))?;
+        assert_eq!(rawtr.wallet_type(), WalletType::P2TR);
+        assert_eq!(rawtr.spendability(), Spendability::Known);
+        assert_eq!(rawtr.public_keys()?.len(), 1);
+        let spend_paths = rawtr.spend_paths()?;
+        assert_eq!(spend_paths.len(), 1);
+        assert_eq!(spend_paths[0].wu_in, tr.spend_paths()?[0].wu_in);
+        let rawtr_address = &rawtr.addresses(KeychainKind::Internal, 0, 1)?[0];
+        let tr_address = &tr.addresses(KeychainKind::Internal, 0, 1)?[0];
+        assert!(rawtr_address.address.script_pubkey().is_p2tr());
+        assert_ne!(rawtr_address.address, tr_address.address);
+
+        // combo(): one path per script type, told apart by their IDs
+        let combo = DescriptorAnalyzer::analyze(&format!("combo({xpub})"))?;
+        assert_eq!(combo.wallet_type(), WalletType::Unknown);
+        let spend_paths = combo.spend_paths()?;
+        assert_eq!(spend_paths.len(), 3);
+        assert_ne!(spend_paths[0].id, spend_paths[1].id);
+        assert_ne!(spend_paths[1].id, spend_paths[2].id);
+        assert_ne!(spend_paths[0].id, spend_paths[2].id);
+        let addresses = combo.addresses(KeychainKind::External, 0, 2)?;
+        assert_eq!(addresses.len(), 6);
+        assert!(combo
+            .find_address(&addresses[4].address.to_string(), 20)?
+            .is_some_and(|found| found.index == 1));
+
+        Ok(())
+    }
 }
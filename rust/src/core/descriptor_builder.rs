@@ -7,8 +7,10 @@ use bdk_wallet::miniscript::policy::concrete::{DescriptorCtx, Policy as Concrete
 use bdk_wallet::miniscript::{Legacy, Segwitv0};
 
 use crate::api::model::{APIAbsoluteTimelock, APIRelativeTimelock};
+use crate::core::descriptor::DescriptorAnalyzer;
 use crate::core::error::WalletError;
 use crate::core::pubkey::PubKey;
+use crate::core::spend_path::{calculate_spend_path_id, SpendPath};
 use crate::core::wallet::WalletType;
 
 /// Definition of a spend path for descriptor building
@@ -21,6 +23,10 @@ pub struct SpendPathDef {
     /// Taproot script tree priority (0 = deepest/least likely, higher = shallower/more likely).
     /// Ignored for non-Taproot descriptors.
     pub priority: usize,
+    /// Expected share of spends that use this path, in any unit. Either every
+    /// path has one or none does; when given, the Taproot script tree is built
+    /// from them instead of from `priority`.
+    pub probability: Option<f64>,
 }
 
 /// Build a descriptor string from wallet type, keys, and spend path definitions.
@@ -52,10 +58,148 @@ pub fn build_descriptor(
     }
 }
 
+/// Wallet types tried when comparing a policy across script types
+pub const COMPARED_WALLET_TYPES: [WalletType; 7] = [
+    WalletType::P2PKH,
+    WalletType::P2SH,
+    WalletType::P2SH_WPKH,
+    WalletType::P2WPKH,
+    WalletType::P2SH_WSH,
+    WalletType::P2WSH,
+    WalletType::P2TR,
+];
+
+/// Outcome of building a policy as one wallet type
+pub enum WalletTypeBuild {
+    Built {
+        descriptor: String,
+        spend_paths: Vec<SpendPath>,
+    },
+    /// The wallet type can't express the policy
+    Unsupported { reason: String },
+}
+
+/// Build the same policy as each of `COMPARED_WALLET_TYPES` and analyze it
+///
+/// Types that fail to build or analyze are reported as unsupported rather
+/// than failing the whole comparison.
+pub fn compare_wallet_types(
+    keys: &[PubKey],
+    spend_paths: &[SpendPathDef],
+) -> Vec<(WalletType, WalletTypeBuild)> {
+    COMPARED_WALLET_TYPES
+        .into_iter()
+        .map(|wallet_type| {
+            let built =
+                build_descriptor(wallet_type.clone(), keys, spend_paths).and_then(|descriptor| {
+                    let spend_paths = DescriptorAnalyzer::analyze(&descriptor)?.spend_paths()?;
+                    Ok(WalletTypeBuild::Built {
+                        descriptor,
+                        spend_paths,
+                    })
+                });
+            let build = built.unwrap_or_else(|e| WalletTypeBuild::Unsupported {
+                reason: e.to_string(),
+            });
+            (wallet_type, build)
+        })
+        .collect()
+}
+
+/// Probabilities of the spend paths, if they have them
+///
+/// They must be given for all paths or none, be finite and non-negative, and
+/// not all be zero.
+fn path_probabilities(spend_paths: &[SpendPathDef]) -> Result<Option<Vec<f64>>> {
+    let probabilities: Vec<f64> = spend_paths.iter().filter_map(|sp| sp.probability).collect();
+    if probabilities.is_empty() {
+        return Ok(None);
+    }
+    if probabilities.len() != spend_paths.len() {
+        return Err(WalletError::BuilderError(
+            "Either every spend path or none must have a probability".into(),
+        )
+        .into());
+    }
+    if probabilities.iter().any(|p| !p.is_finite() || *p < 0.0)
+        || probabilities.iter().sum::<f64>() <= 0.0
+    {
+        return Err(WalletError::BuilderError(
+            "Spend path probabilities must be non-negative and not all zero".into(),
+        )
+        .into());
+    }
+    Ok(Some(probabilities))
+}
+
+/// Cost of spending a Taproot descriptor through one spend path
+pub struct TaprootPathCost {
+    pub spend_path_id: u32,
+    /// Normalized, so all paths add up to 1
+    pub probability: f64,
+    /// 0 for the key path, leaf depth + 1 for script paths
+    pub tr_depth: usize,
+    pub wu_in: u32,
+}
+
+/// Taproot descriptor along with the expected cost of its script tree
+pub struct TaprootBuild {
+    pub descriptor: String,
+    pub spend_paths: Vec<TaprootPathCost>,
+    /// Input weight averaged over the spend paths by probability
+    pub expected_wu_in: f64,
+}
+
+/// Build a Taproot descriptor and report where each path ended up
+///
+/// Paths without probabilities are taken as equally likely.
+pub fn build_taproot(keys: &[PubKey], spend_paths: &[SpendPathDef]) -> Result<TaprootBuild> {
+    let descriptor = build_descriptor(WalletType::P2TR, keys, spend_paths)?;
+    let probabilities =
+        path_probabilities(spend_paths)?.unwrap_or_else(|| vec![1.0; spend_paths.len()]);
+    let total: f64 = probabilities.iter().sum();
+
+    let analyzed = DescriptorAnalyzer::analyze(&descriptor)?.spend_paths()?;
+    let mut costs = Vec::new();
+    for (def, probability) in spend_paths.iter().zip(probabilities) {
+        let id = calculate_spend_path_id(
+            def.threshold,
+            &def.mfps,
+            def.rel_timelock.to_consensus()?,
+            def.abs_timelock.to_consensus()?,
+        );
+        let sp = analyzed
+            .iter()
+            .find(|sp| sp.id == id)
+            .ok_or(WalletError::MissingSpendPath)?;
+        costs.push(TaprootPathCost {
+            spend_path_id: id,
+            probability: probability / total,
+            tr_depth: sp.tr_depth,
+            wu_in: sp.wu_in,
+        });
+    }
+
+    let expected_wu_in = costs
+        .iter()
+        .map(|cost| cost.probability * cost.wu_in as f64)
+        .sum();
+    Ok(TaprootBuild {
+        descriptor,
+        spend_paths: costs,
+        expected_wu_in,
+    })
+}
+
 // --- Key helpers ---
 
 /// Construct key string with standard multipath wildcard
+///
+/// Raw keys can't be derived and are used as they are.
 fn key_with_wildcard(key: &PubKey) -> String {
+    if key.is_raw() {
+        return key.to_string();
+    }
     format!("{}/<0;1>/*", key)
 }
 
@@ -63,6 +207,9 @@ fn key_with_wildcard(key: &PubKey) -> String {
 /// Tracks usage by xpub (not MFP) so that two different MFPs sharing the
 /// same xpub receive different derivation slots and don't produce duplicates.
 fn key_with_derivation(key: &PubKey, keys_uses: &mut HashMap<String, usize>) -> String {
+    if key.is_raw() {
+        return key.to_string();
+    }
     let xpub_id = key
         .xpub()
         .map(|x| x.to_string())
@@ -232,19 +379,24 @@ fn build_tr(keys: &[PubKey], spend_paths: &[SpendPathDef]) -> Result<String> {
             .collect();
     } else {
         // No key-path: generate NUMS xpub from script path keys
-        // Infer network from first key
+        // Infer network from first extended key
         use crate::core::pubkey::PubKey;
         use bdk_wallet::bitcoin::{Network, NetworkKind};
 
-        let network_kind = keys[0].xpub()?.network;
-        let network = match network_kind {
-            NetworkKind::Main => Network::Bitcoin,
-            NetworkKind::Test => Network::Testnet,
-        };
+        internal_key_str = match keys.iter().find_map(|k| k.xpub().ok()) {
+            Some(xpub) => {
+                let network = match xpub.network {
+                    NetworkKind::Main => Network::Bitcoin,
+                    NetworkKind::Test => Network::Testnet,
+                };
 
-        // Generate NUMS xpub (without fingerprint/derivation path, but with wildcard)
-        let nums_xpub = PubKey::generate_unspendable_xpub(keys, network)?;
-        internal_key_str = format!("{}/<0;1>/*", nums_xpub);
+                // Generate NUMS xpub (without fingerprint/derivation path, but with wildcard)
+                let nums_xpub = PubKey::generate_unspendable_xpub(keys, network)?;
+                format!("{}/<0;1>/*", nums_xpub)
+            }
+            // Only raw keys: nothing to derive, so the bare NUMS point will do
+            None => PubKey::nums_xonly().to_string(),
+        };
         script_paths = spend_paths.iter().collect();
     }
 
@@ -259,20 +411,28 @@ fn build_tr(keys: &[PubKey], spend_paths: &[SpendPathDef]) -> Result<String> {
 
         Ok(validated.to_string())
     } else {
-        // Build each script path separately and group by priority
-        let mut scripts_by_priority: BTreeMap<usize, Vec<String>> = BTreeMap::new();
-        for sp in script_paths.iter() {
-            let script_str = build_taproot_script_path(sp, keys, &mut keys_uses)?;
-            scripts_by_priority
-                .entry(sp.priority)
-                .or_default()
-                .push(script_str);
-        }
+        let tree_str = if path_probabilities(spend_paths)?.is_some() {
+            let mut weighted_scripts = Vec::new();
+            for sp in script_paths.iter() {
+                let script_str = build_taproot_script_path(sp, keys, &mut keys_uses)?;
+                weighted_scripts.push((sp.probability.unwrap_or_default(), script_str));
+            }
+            build_huffman_tree(weighted_scripts)?
+        } else {
+            // Build each script path separately and group by priority
+            let mut scripts_by_priority: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+            for sp in script_paths.iter() {
+                let script_str = build_taproot_script_path(sp, keys, &mut keys_uses)?;
+                scripts_by_priority
+                    .entry(sp.priority)
+                    .or_default()
+                    .push(script_str);
+            }
 
-        let scripts_layered = scripts_by_priority.into_values().collect();
+            build_layered_tree(scripts_by_priority.into_values().collect())?
+        };
 
         // Build descriptor string
-        let tree_str = build_layered_tree(scripts_layered)?;
         let descriptor_str = format!("tr({},{})", internal_key_str, tree_str);
 
         // Validate by parsing with BDK and return with checksum
@@ -326,6 +486,27 @@ fn build_taproot_tree(scripts: &[String]) -> Result<String> {
     }
 }
 
+/// Build a Huffman tree from scripts and their probabilities
+///
+/// The two least likely subtrees are merged until one is left, so likely
+/// leaves end up shallow. Leaf scripts don't change with their depth, so this
+/// minimizes the expected control block size and with it the expected weight.
+fn build_huffman_tree(mut weighted_scripts: Vec<(f64, String)>) -> Result<String> {
+    loop {
+        // Stable sort, so ties are merged in a deterministic order
+        weighted_scripts.sort_by(|a, b| b.0.total_cmp(&a.0));
+        match (weighted_scripts.pop(), weighted_scripts.pop()) {
+            (Some((p1, s1)), Some((p2, s2))) => weighted_scripts.push((p1 + p2, join_tree(s2, s1))),
+            (Some((_, tree)), None) => return Ok(tree),
+            _ => {
+                return Err(
+                    WalletError::BuilderError("Cannot build tree with no scripts".into()).into(),
+                )
+            }
+        }
+    }
+}
+
 fn build_layered_tree(layered_scripts: Vec<Vec<String>>) -> Result<String> {
     layered_scripts
         .into_iter()
@@ -443,7 +624,6 @@ fn build_path_policy(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::descriptor::DescriptorAnalyzer;
 
     fn mainnet_keys() -> Vec<PubKey> {
         vec![
@@ -472,6 +652,7 @@ mod tests {
             abs_timelock: APIAbsoluteTimelock::from_consensus(0),
             is_key_path: false,
             priority: 0,
+            probability: None,
         }];
 
         let descriptor = build_descriptor(WalletType::P2WSH, &keys, &spend_paths)?;
@@ -500,6 +681,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -508,6 +690,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -534,6 +717,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -542,6 +726,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -567,6 +752,7 @@ mod tests {
             abs_timelock: APIAbsoluteTimelock::from_consensus(0),
             is_key_path: false,
             priority: 0,
+            probability: None,
         }];
 
         let descriptor = build_descriptor(WalletType::P2WPKH, &keys, &spend_paths)?;
@@ -589,6 +775,7 @@ mod tests {
             abs_timelock: APIAbsoluteTimelock::from_consensus(0),
             is_key_path: false,
             priority: 0,
+            probability: None,
         }];
 
         let descriptor = build_descriptor(WalletType::P2SH_WSH, &keys, &spend_paths)?;
@@ -648,6 +835,7 @@ mod tests {
             abs_timelock: APIAbsoluteTimelock::from_consensus(0),
             is_key_path: false,
             priority: 0,
+            probability: None,
         }];
 
         let descriptor = build_descriptor(WalletType::P2WSH, &keys, &spend_paths)?;
@@ -677,6 +865,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -685,6 +874,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -693,6 +883,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -726,6 +917,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -734,6 +926,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(800000), // Block height
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -764,6 +957,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -772,6 +966,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -792,6 +987,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(sp.abs_timelock),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             })
             .collect();
 
@@ -822,6 +1018,7 @@ mod tests {
             abs_timelock: APIAbsoluteTimelock::from_consensus(0),
             is_key_path: false,
             priority: 0,
+            probability: None,
         }];
 
         let descriptor = build_descriptor(WalletType::P2SH_WPKH, &keys, &spend_paths)?;
@@ -844,6 +1041,7 @@ mod tests {
             abs_timelock: APIAbsoluteTimelock::from_consensus(0),
             is_key_path: false,
             priority: 0,
+            probability: None,
         }];
 
         let descriptor = build_descriptor(WalletType::P2PKH, &keys, &spend_paths)?;
@@ -869,6 +1067,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: true, // Mark as key-path
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -877,6 +1076,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -918,6 +1118,7 @@ mod tests {
             abs_timelock: APIAbsoluteTimelock::from_consensus(0),
             is_key_path: true,
             priority: 0,
+            probability: None,
         }];
 
         let descriptor = build_descriptor(WalletType::P2TR, &keys, &spend_paths)?;
@@ -946,7 +1147,7 @@ mod tests {
         let result = build_descriptor(
             WalletType::P2TR,
             &keys,
-            &vec![
+            &[
                 SpendPathDef {
                     threshold: 1,
                     mfps: vec!["c449c5c5".into()],
@@ -954,6 +1155,7 @@ mod tests {
                     abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                     is_key_path: true,
                     priority: 0,
+                    probability: None,
                 },
                 SpendPathDef {
                     threshold: 1,
@@ -962,6 +1164,7 @@ mod tests {
                     abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                     is_key_path: true, // Second key-path - error
                     priority: 0,
+                    probability: None,
                 },
             ],
         );
@@ -972,13 +1175,14 @@ mod tests {
         let result = build_descriptor(
             WalletType::P2TR,
             &keys,
-            &vec![SpendPathDef {
+            &[SpendPathDef {
                 threshold: 2,
                 mfps: vec!["c449c5c5".into(), "c61af686".into()],
                 rel_timelock: APIRelativeTimelock::from_consensus(0),
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: true, // Multisig cannot be key-path
                 priority: 0,
+                probability: None,
             }],
         );
         assert!(result.is_err());
@@ -988,13 +1192,14 @@ mod tests {
         let result = build_descriptor(
             WalletType::P2TR,
             &keys,
-            &vec![SpendPathDef {
+            &[SpendPathDef {
                 threshold: 1,
                 mfps: vec!["c449c5c5".into()],
                 rel_timelock: APIRelativeTimelock::from_consensus(144),
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: true, // Timelock cannot be key-path
                 priority: 0,
+                probability: None,
             }],
         );
         assert!(result.is_err());
@@ -1014,6 +1219,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false, // Script path
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1022,6 +1228,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false, // Script path
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -1032,7 +1239,7 @@ mod tests {
         let paths = analyzer.spend_paths()?;
 
         // Check how many paths we get back
-        assert!(paths.len() > 0);
+        assert!(!paths.is_empty());
 
         Ok(())
     }
@@ -1045,13 +1252,14 @@ mod tests {
         let result = build_descriptor(
             WalletType::P2WSH,
             &[],
-            &vec![SpendPathDef {
+            &[SpendPathDef {
                 threshold: 1,
                 mfps: vec!["c449c5c5".into()],
                 rel_timelock: APIRelativeTimelock::from_consensus(0),
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             }],
         );
         assert!(result.is_err());
@@ -1064,13 +1272,14 @@ mod tests {
         let result = build_descriptor(
             WalletType::P2WPKH,
             &keys,
-            &vec![SpendPathDef {
+            &[SpendPathDef {
                 threshold: 2,
                 mfps: vec!["c449c5c5".into(), "c61af686".into()],
                 rel_timelock: APIRelativeTimelock::from_consensus(0),
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             }],
         );
         assert!(result.is_err());
@@ -1079,13 +1288,14 @@ mod tests {
         let result = build_descriptor(
             WalletType::Unknown,
             &keys,
-            &vec![SpendPathDef {
+            &[SpendPathDef {
                 threshold: 1,
                 mfps: vec!["c449c5c5".into()],
                 rel_timelock: APIRelativeTimelock::from_consensus(0),
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             }],
         );
         assert!(result.is_err());
@@ -1094,13 +1304,14 @@ mod tests {
         let result = build_descriptor(
             WalletType::P2WSH,
             &keys,
-            &vec![SpendPathDef {
+            &[SpendPathDef {
                 threshold: 1,
                 mfps: vec!["deadbeef".into()],
                 rel_timelock: APIRelativeTimelock::from_consensus(0),
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             }],
         );
         assert!(result.is_err());
@@ -1119,6 +1330,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1127,6 +1339,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -1194,6 +1407,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1202,6 +1416,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -1247,6 +1462,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: true, // Explicit key-path
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1255,6 +1471,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false, // Singlesig script path, no timelock
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -1315,6 +1532,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: true,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 2,
@@ -1323,6 +1541,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1331,6 +1550,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -1389,6 +1609,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1397,6 +1618,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1405,6 +1627,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -1444,6 +1667,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1452,6 +1676,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: true, // THIS is the key-path
                 priority: 0,
+                probability: None,
             },
             SpendPathDef {
                 threshold: 1,
@@ -1460,6 +1685,7 @@ mod tests {
                 abs_timelock: APIAbsoluteTimelock::from_consensus(0),
                 is_key_path: false,
                 priority: 0,
+                probability: None,
             },
         ];
 
@@ -1489,4 +1715,164 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_build_with_raw_keys() -> Result<()> {
+        let raw =
+            PubKey::try_from("02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13")?;
+        let raw_mfp = raw.mfp().to_string();
+        let mut keys = mainnet_keys();
+        keys.push(raw);
+
+        let spend_paths = vec![SpendPathDef {
+            threshold: 2,
+            mfps: vec!["c449c5c5".into(), raw_mfp.clone()],
+            rel_timelock: APIRelativeTimelock::from_consensus(0),
+            abs_timelock: APIAbsoluteTimelock::from_consensus(0),
+            is_key_path: false,
+            priority: 0,
+            probability: None,
+        }];
+
+        let descriptor = build_descriptor(WalletType::P2WSH, &keys, &spend_paths)?;
+        assert!(descriptor
+            .contains("02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13"));
+        assert!(!descriptor
+            .contains("02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13/"));
+
+        let analyzer = DescriptorAnalyzer::analyze(&descriptor)?;
+        let paths = analyzer.spend_paths()?;
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].mfps.contains(&raw_mfp));
+
+        // Taproot without key path and only raw keys uses the bare NUMS point
+        let raw =
+            PubKey::try_from("e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13")?;
+        let raw_mfp_tr = raw.mfp().to_string();
+        let spend_paths = vec![SpendPathDef {
+            threshold: 1,
+            mfps: vec![raw_mfp_tr.clone()],
+            rel_timelock: APIRelativeTimelock::from_consensus(0),
+            abs_timelock: APIAbsoluteTimelock::from_consensus(0),
+            is_key_path: false,
+            priority: 0,
+            probability: None,
+        }];
+        let descriptor = build_descriptor(WalletType::P2TR, &[raw], &spend_paths)?;
+        assert!(descriptor.starts_with(&format!("tr({},", PubKey::nums_xonly())));
+
+        let paths = DescriptorAnalyzer::analyze(&descriptor)?.spend_paths()?;
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].mfps, vec![raw_mfp_tr]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_wallet_types() -> Result<()> {
+        let keys = mainnet_keys();
+        let spend_paths = vec![SpendPathDef {
+            threshold: 2,
+            mfps: vec!["c449c5c5".into(), "c61af686".into()],
+            rel_timelock: APIRelativeTimelock::from_consensus(0),
+            abs_timelock: APIAbsoluteTimelock::from_consensus(0),
+            is_key_path: false,
+            priority: 0,
+            probability: None,
+        }];
+
+        let builds = compare_wallet_types(&keys, &spend_paths);
+        assert_eq!(builds.len(), COMPARED_WALLET_TYPES.len());
+
+        let wu_in = |wallet_type: WalletType| -> Option<u32> {
+            builds
+                .iter()
+                .find(|(wt, _)| *wt == wallet_type)
+                .and_then(|(_, build)| match build {
+                    WalletTypeBuild::Built { spend_paths, .. } => Some(spend_paths[0].wu_in),
+                    WalletTypeBuild::Unsupported { .. } => None,
+                })
+        };
+
+        // Single key types can't express a 2-of-2
+        assert!(wu_in(WalletType::P2PKH).is_none());
+        assert!(wu_in(WalletType::P2WPKH).is_none());
+        assert!(wu_in(WalletType::P2SH_WPKH).is_none());
+
+        // Witness discount makes nested segwit cheaper than legacy, and native
+        // cheaper than nested
+        let sh = wu_in(WalletType::P2SH).expect("P2SH should build");
+        let sh_wsh = wu_in(WalletType::P2SH_WSH).expect("P2SH-WSH should build");
+        let wsh = wu_in(WalletType::P2WSH).expect("P2WSH should build");
+        let tr = wu_in(WalletType::P2TR).expect("P2TR should build");
+        assert!(sh > sh_wsh);
+        assert!(sh_wsh > wsh);
+        // Without a key path, the control block makes taproot the heavier one
+        assert!(tr > wsh);
+
+        // Paths keep their IDs across types, so they line up side by side
+        let ids: Vec<Vec<u32>> = builds
+            .iter()
+            .filter_map(|(_, build)| match build {
+                WalletTypeBuild::Built { spend_paths, .. } => {
+                    Some(spend_paths.iter().map(|sp| sp.id).collect())
+                }
+                WalletTypeBuild::Unsupported { .. } => None,
+            })
+            .collect();
+        assert!(ids.windows(2).all(|w| w[0] == w[1]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_taproot_huffman_tree() -> Result<()> {
+        let keys = mainnet_keys();
+        let path =
+            |threshold: usize, mfps: &[&str], rel: u32, abs: u32, probability: Option<f64>| {
+                SpendPathDef {
+                    threshold,
+                    mfps: mfps.iter().map(|mfp| mfp.to_string()).collect(),
+                    rel_timelock: APIRelativeTimelock::from_consensus(rel),
+                    abs_timelock: APIAbsoluteTimelock::from_consensus(abs),
+                    is_key_path: false,
+                    priority: 0,
+                    probability,
+                }
+            };
+        let paths = |probabilities: [Option<f64>; 4]| {
+            vec![
+                path(2, &["c449c5c5", "c61af686"], 0, 0, probabilities[0]),
+                path(1, &["c449c5c5"], 144, 0, probabilities[1]),
+                path(1, &["c61af686"], 144, 0, probabilities[2]),
+                path(1, &["c449c5c5"], 0, 900_000, probabilities[3]),
+            ]
+        };
+
+        let huffman = build_taproot(&keys, &paths([Some(8.0), Some(4.0), Some(2.0), Some(2.0)]))?;
+        let depths: Vec<usize> = huffman.spend_paths.iter().map(|c| c.tr_depth).collect();
+        assert_eq!(depths, vec![2, 3, 4, 4]);
+        let probabilities: Vec<f64> = huffman.spend_paths.iter().map(|c| c.probability).collect();
+        assert_eq!(probabilities, vec![0.5, 0.25, 0.125, 0.125]);
+
+        // Same paths in a balanced tree cost more on average
+        let balanced = build_taproot(&keys, &paths([None; 4]))?;
+        assert!(balanced.spend_paths.iter().all(|c| c.tr_depth == 3));
+        let balanced_expected: f64 = balanced
+            .spend_paths
+            .iter()
+            .zip(&probabilities)
+            .map(|(c, p)| p * c.wu_in as f64)
+            .sum();
+        assert!(huffman.expected_wu_in < balanced_expected);
+
+        // Probabilities are all or nothing, and can't all be zero
+        assert!(build_taproot(&keys, &paths([Some(1.0), None, None, None])).is_err());
+        assert!(build_taproot(&keys, &paths([Some(0.0); 4])).is_err());
+        assert!(
+            build_taproot(&keys, &paths([Some(-1.0), Some(1.0), Some(1.0), Some(1.0)])).is_err()
+        );
+
+        Ok(())
+    }
 }
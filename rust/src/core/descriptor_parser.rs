@@ -1,29 +1,52 @@
 use anyhow::Result;
-use bdk_wallet::bitcoin::Network;
+use bdk_wallet::bitcoin::{Network, NetworkKind};
 use bdk_wallet::keys::DescriptorPublicKey;
-use bdk_wallet::miniscript::descriptor::ShInner;
-use bdk_wallet::miniscript::Descriptor;
-use bdk_wallet::Wallet;
-use regex::Regex;
+use bdk_wallet::miniscript::descriptor::{DerivPaths, DescriptorMultiXKey, ShInner, WshInner};
+use bdk_wallet::miniscript::{
+    translate_hash_clone, Descriptor, ForEachKey, TranslatePk, Translator,
+};
 
 use crate::core::error::WalletError;
+use crate::core::pubkey::{AccountXpub, PubKey};
+use crate::core::special_descriptor::SpecialDescriptor;
 use crate::core::wallet::WalletType;
 
 /// Lightweight descriptor parser that works without creating wallets
 pub struct DescriptorParser {
     descriptor_str: String,
-    parsed: Descriptor<DescriptorPublicKey>,
+    parsed: ParsedDescriptor,
+}
+
+/// Result of parsing a descriptor string
+pub enum ParsedDescriptor {
+    Miniscript(Descriptor<DescriptorPublicKey>),
+    /// `addr()`, `raw()`, `rawtr()` or `combo()`, unknown to miniscript
+    Special(SpecialDescriptor),
+}
+
+impl From<Descriptor<DescriptorPublicKey>> for DescriptorParser {
+    fn from(descriptor: Descriptor<DescriptorPublicKey>) -> Self {
+        Self {
+            descriptor_str: descriptor.to_string(),
+            parsed: ParsedDescriptor::Miniscript(descriptor),
+        }
+    }
 }
 
 impl DescriptorParser {
     /// Parse descriptor from string without creating a wallet
     ///
     /// Uses BDK's built-in descriptor parser. This validates the descriptor
-    /// syntax but doesn't require creating a full wallet.
+    /// syntax but doesn't require creating a full wallet. Descriptors miniscript
+    /// doesn't know are tried as `SpecialDescriptor`.
     pub fn parse(descriptor: &str) -> Result<Self> {
-        let parsed: Descriptor<DescriptorPublicKey> = descriptor
-            .parse()
-            .map_err(|_| WalletError::InvalidDescriptorSyntax)?;
+        let parsed = match descriptor.parse::<Descriptor<DescriptorPublicKey>>() {
+            std::result::Result::Ok(parsed) => ParsedDescriptor::Miniscript(parsed),
+            Err(_) => ParsedDescriptor::Special(
+                SpecialDescriptor::parse(descriptor)?
+                    .ok_or(WalletError::InvalidDescriptorSyntax)?,
+            ),
+        };
 
         Ok(Self {
             descriptor_str: descriptor.to_string(),
@@ -31,90 +54,152 @@ impl DescriptorParser {
         })
     }
 
-    /// Detect network from descriptor WITHOUT creating wallets
-    ///
-    /// Strategy: Parse xpub prefixes from the descriptor string
-    /// - xpub/ypub/zpub = Bitcoin mainnet
-    /// - tpub/upub/vpub = Testnet family (requires further detection)
+    /// Parse a receive/change descriptor pair as a single wallet
     ///
-    /// This avoids creating up to 5 temporary wallets like the old approach.
-    /// Falls back to wallet creation only if ambiguous.
-    pub fn detect_network(&self) -> Result<Network> {
-        // Extract all xpub-like prefixes from descriptor
-        let re = Regex::new(r"\b([xyztvu]pub[1-9A-HJ-NP-Za-km-z]+)\b")
-            .map_err(|_| WalletError::NetworkDetectionFailed)?;
+    /// Most wallets export `.../0/*` and `.../1/*` as two descriptors. Both halves
+    /// must have the same script and keys, differing only in the derivation steps
+    /// after the key, and are merged into one multipath (`<0;1>`) descriptor.
+    pub fn parse_pair(external: &str, internal: &str) -> Result<Self> {
+        let parse = |descriptor: &str| -> Result<Descriptor<DescriptorPublicKey>> {
+            let parsed: Descriptor<DescriptorPublicKey> = descriptor
+                .parse()
+                .map_err(|_| WalletError::InvalidDescriptorSyntax)?;
+            if parsed.is_multipath() {
+                Err(WalletError::MismatchedDescriptors)?;
+            }
+            Ok(parsed)
+        };
+        let external = parse(external)?;
+        let internal = parse(internal)?;
 
-        let mainnet_prefixes = ["xpub", "ypub", "zpub"];
-        let testnet_prefixes = ["tpub", "upub", "vpub"];
+        Ok(Self::merge_pair(&external, &internal)?.into())
+    }
 
-        let mut found_mainnet = false;
-        let mut found_testnet = false;
+    fn merge_pair(
+        external: &Descriptor<DescriptorPublicKey>,
+        internal: &Descriptor<DescriptorPublicKey>,
+    ) -> Result<Descriptor<DescriptorPublicKey>> {
+        /// Replaces each receive key by its multipath receive/change key
+        struct PairMerger(Vec<(DescriptorPublicKey, DescriptorPublicKey)>);
 
-        for cap in re.captures_iter(&self.descriptor_str) {
-            if let Some(xpub_match) = cap.get(1) {
-                let xpub = xpub_match.as_str();
-                if mainnet_prefixes.iter().any(|p| xpub.starts_with(p)) {
-                    found_mainnet = true;
-                }
-                if testnet_prefixes.iter().any(|p| xpub.starts_with(p)) {
-                    found_testnet = true;
-                }
+        impl Translator<DescriptorPublicKey, DescriptorPublicKey, WalletError> for PairMerger {
+            fn pk(&mut self, pk: &DescriptorPublicKey) -> Result<DescriptorPublicKey, WalletError> {
+                self.0
+                    .iter()
+                    .find(|(external, _)| external == pk)
+                    .map(|(_, merged)| merged.clone())
+                    .ok_or(WalletError::MismatchedDescriptors)
             }
+
+            translate_hash_clone!(DescriptorPublicKey, DescriptorPublicKey, WalletError);
         }
 
-        match (found_mainnet, found_testnet) {
-            (true, false) => Ok(Network::Bitcoin),
-            (false, true) => {
-                // Could be Testnet, Signet, Testnet4, or Regtest
-                // Need to try wallet creation to distinguish
-                self.detect_testnet_variant()
+        let merge_keys = |external: DescriptorPublicKey, internal: DescriptorPublicKey| {
+            if external == internal {
+                return Ok(external);
             }
-            _ => {
-                // Ambiguous or no xpubs found, fallback to wallet creation
-                self.detect_network_via_wallet()
+            match (&external, internal) {
+                (DescriptorPublicKey::XPub(ext), DescriptorPublicKey::XPub(int))
+                    if ext.origin == int.origin
+                        && ext.xkey == int.xkey
+                        && ext.wildcard == int.wildcard =>
+                {
+                    let derivation_paths =
+                        DerivPaths::new(vec![ext.derivation_path.clone(), int.derivation_path])
+                            .ok_or(WalletError::MismatchedDescriptors)?;
+                    Ok(DescriptorPublicKey::MultiXPub(DescriptorMultiXKey {
+                        origin: ext.origin.clone(),
+                        xkey: ext.xkey,
+                        derivation_paths,
+                        wildcard: ext.wildcard,
+                    }))
+                }
+                _ => Err(WalletError::MismatchedDescriptors),
             }
+        };
+
+        let keys = |descriptor: &Descriptor<DescriptorPublicKey>| {
+            let mut keys = Vec::new();
+            descriptor.for_each_key(|key| {
+                keys.push(key.clone());
+                true
+            });
+            keys
+        };
+        let external_keys = keys(external);
+        let internal_keys = keys(internal);
+        if external_keys.len() != internal_keys.len() {
+            Err(WalletError::MismatchedDescriptors)?;
         }
-    }
 
-    /// Try each network in order, returning the first one that parses the descriptor successfully.
-    fn try_networks(&self, networks: &[Network]) -> Result<Network> {
-        for &network in networks {
-            if Wallet::create_from_two_path_descriptor(self.descriptor_str.clone())
-                .network(network)
-                .create_wallet_no_persist()
-                .is_ok()
-            {
-                return Ok(network);
+        let mut pairs: Vec<(DescriptorPublicKey, DescriptorPublicKey)> = Vec::new();
+        for (ext, int) in external_keys.into_iter().zip(internal_keys) {
+            let merged = merge_keys(ext.clone(), int)?;
+            match pairs.iter().find(|(key, _)| *key == ext) {
+                Some((_, other)) if *other != merged => Err(WalletError::MismatchedDescriptors)?,
+                Some(_) => {}
+                None => pairs.push((ext, merged)),
             }
         }
-        Err(WalletError::NetworkDetectionFailed.into())
+
+        let merged = external
+            .translate_pk(&mut PairMerger(pairs))
+            .map_err(|_| WalletError::MismatchedDescriptors)?;
+
+        // Splitting the merged descriptor must give back both halves, which
+        // also checks that their scripts have the same shape
+        if !merged.is_multipath()
+            || merged.clone().into_single_descriptors()? != [external.clone(), internal.clone()]
+        {
+            Err(WalletError::MismatchedDescriptors)?;
+        }
+
+        Ok(merged)
     }
 
-    /// Detect which testnet variant (Testnet, Signet, Testnet4, Regtest)
+    /// Detect network from the version bytes of the extended keys
     ///
-    /// This still requires wallet creation but only tries testnet variants,
-    /// reducing from 5 attempts to at most 4.
-    fn detect_testnet_variant(&self) -> Result<Network> {
-        self.try_networks(&[
-            Network::Testnet,
-            Network::Signet,
-            Network::Testnet4,
-            Network::Regtest,
-        ])
-    }
-
-    /// Fallback: detect network by trying wallet creation on all networks
-    ///
-    /// This is the old approach - only used when xpub parsing fails.
-    /// Kept for correctness on unusual descriptors.
-    fn detect_network_via_wallet(&self) -> Result<Network> {
-        self.try_networks(&[
-            Network::Bitcoin,
-            Network::Testnet,
-            Network::Testnet4,
-            Network::Signet,
-            Network::Regtest,
-        ])
+    /// xpubs are mainnet and tpubs the test networks. Testnet, signet,
+    /// testnet4 and regtest share their version bytes, so they are all
+    /// reported as testnet. Single keys don't tell: descriptors without
+    /// extended keys are taken as mainnet. Mixing both is an error.
+    pub fn detect_network(&self) -> Result<Network> {
+        let mut keys = Vec::new();
+        match &self.parsed {
+            ParsedDescriptor::Miniscript(descriptor) => {
+                descriptor.for_each_key(|key| {
+                    keys.push(key.clone());
+                    true
+                });
+            }
+            ParsedDescriptor::Special(special) => {
+                // Addresses carry their network
+                if let Some(network) = special.network() {
+                    return Ok(network);
+                }
+                keys.extend(special.keys().into_iter().cloned());
+            }
+        }
+
+        let mut found_mainnet = false;
+        let mut found_testnet = false;
+        for key in &keys {
+            let network = match key {
+                DescriptorPublicKey::XPub(xpub) => xpub.xkey.network,
+                DescriptorPublicKey::MultiXPub(xpub) => xpub.xkey.network,
+                DescriptorPublicKey::Single(_) => continue,
+            };
+            match network {
+                NetworkKind::Main => found_mainnet = true,
+                NetworkKind::Test => found_testnet = true,
+            }
+        }
+
+        match (found_mainnet, found_testnet) {
+            (true, true) => Err(WalletError::NetworkDetectionFailed.into()),
+            (false, true) => Ok(Network::Testnet),
+            _ => Ok(Network::Bitcoin),
+        }
     }
 
     /// Get wallet type by pattern matching on descriptor enum
@@ -122,7 +207,12 @@ impl DescriptorParser {
     /// Uses BDK's Descriptor::Pkh/Wpkh/Wsh/Sh/Tr variants.
     /// This works without creating a wallet.
     pub fn wallet_type(&self) -> WalletType {
-        match &self.parsed {
+        let descriptor = match &self.parsed {
+            ParsedDescriptor::Miniscript(descriptor) => descriptor,
+            ParsedDescriptor::Special(special) => return special.wallet_type(),
+        };
+
+        match descriptor {
             Descriptor::Pkh(_) => WalletType::P2PKH,
             Descriptor::Sh(sh) => match sh.as_inner() {
                 ShInner::Wsh(_) => WalletType::P2SH_WSH,
@@ -136,11 +226,101 @@ impl DescriptorParser {
         }
     }
 
+    /// Wallet type and threshold of a plain `sortedmulti` descriptor
+    ///
+    /// None for anything else, like multisigs with timelocks or other
+    /// conditions. These are the only multisigs most wallet setup files hold.
+    pub fn sorted_multi(&self) -> Option<(WalletType, usize)> {
+        let ParsedDescriptor::Miniscript(descriptor) = &self.parsed else {
+            return None;
+        };
+
+        match descriptor {
+            Descriptor::Sh(sh) => match sh.as_inner() {
+                ShInner::SortedMulti(multi) => Some((WalletType::P2SH, multi.k())),
+                ShInner::Wsh(wsh) => match wsh.as_inner() {
+                    WshInner::SortedMulti(multi) => Some((WalletType::P2SH_WSH, multi.k())),
+                    WshInner::Ms(_) => None,
+                },
+                _ => None,
+            },
+            Descriptor::Wsh(wsh) => match wsh.as_inner() {
+                WshInner::SortedMulti(multi) => Some((WalletType::P2WSH, multi.k())),
+                WshInner::Ms(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Plain `sortedmulti` descriptor of account keys, see `PubKey::account_key`
+    ///
+    /// Inverse of `sorted_multi`, for P2SH, P2SH-P2WSH and P2WSH only.
+    pub fn from_sorted_multi(
+        wallet_type: &WalletType,
+        threshold: usize,
+        keys: &[AccountXpub],
+    ) -> Result<Self> {
+        let keys: Vec<DescriptorPublicKey> = keys
+            .iter()
+            .map(|(mfp, path, xpub)| PubKey::account_key(*mfp, path.clone(), *xpub))
+            .collect();
+        let descriptor = match wallet_type {
+            WalletType::P2SH => Descriptor::new_sh_sortedmulti(threshold, keys)?,
+            WalletType::P2SH_WSH => Descriptor::new_sh_wsh_sortedmulti(threshold, keys)?,
+            WalletType::P2WSH => Descriptor::new_wsh_sortedmulti(threshold, keys)?,
+            _ => Err(WalletError::UnsupportedDescriptor)?,
+        };
+        Ok(descriptor.into())
+    }
+
+    /// Wallet type, threshold and account keys of a descriptor to export
+    ///
+    /// Wallet files only hold `sorted_multi` descriptors of `account_xpub` keys,
+    /// and also single keys when `single_key`. Anything else fails with an
+    /// `UnsupportedExport` naming `format`, e.g. "Electrum wallets".
+    pub fn export_keys(
+        &self,
+        format: &str,
+        single_key: bool,
+    ) -> Result<(WalletType, usize, Vec<AccountXpub>)> {
+        let (wallet_type, threshold) = match (self.wallet_type(), self.sorted_multi()) {
+            (_, Some(multisig)) => multisig,
+            (
+                wallet_type @ (WalletType::P2PKH | WalletType::P2SH_WPKH | WalletType::P2WPKH),
+                None,
+            ) if single_key => (wallet_type, 1),
+            (WalletType::P2TR, None) => Err(WalletError::UnsupportedExport(format!(
+                "{format} can't hold taproot descriptors"
+            )))?,
+            _ => Err(WalletError::UnsupportedExport(format!(
+                "{format} only hold {}sortedmulti descriptors, without timelocks or other conditions",
+                if single_key { "single key or " } else { "" }
+            )))?,
+        };
+
+        let keys = PubKey::account_xpubs(self.descriptor()?).ok_or_else(|| {
+            WalletError::UnsupportedExport(format!(
+                "{format} only hold xpubs with origin, deriving /<0;1>/*"
+            ))
+        })?;
+        Ok((wallet_type, threshold, keys))
+    }
+
     /// Access to parsed descriptor for further operations
-    pub fn descriptor(&self) -> &Descriptor<DescriptorPublicKey> {
+    pub fn parsed(&self) -> &ParsedDescriptor {
         &self.parsed
     }
 
+    /// Access to the miniscript descriptor for further operations
+    ///
+    /// Fails for special descriptors, see `parsed`.
+    pub fn descriptor(&self) -> Result<&Descriptor<DescriptorPublicKey>> {
+        match &self.parsed {
+            ParsedDescriptor::Miniscript(descriptor) => Ok(descriptor),
+            ParsedDescriptor::Special(_) => Err(WalletError::UnsupportedDescriptor.into()),
+        }
+    }
+
     /// Get the original descriptor string
     pub fn descriptor_str(&self) -> &str {
         &self.descriptor_str
@@ -188,4 +368,125 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_pair() -> Result<()> {
+        let external = "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/0/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/0/*))";
+        let internal = "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/1/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/1/*))";
+        let multipath = "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/<0;1>/*))";
+
+        let parser = DescriptorParser::parse_pair(external, internal)?;
+        assert_eq!(
+            parser.descriptor()?,
+            DescriptorParser::parse(multipath)?.descriptor()?
+        );
+        assert_eq!(parser.wallet_type(), WalletType::P2WSH);
+        assert_eq!(parser.detect_network()?, Network::Bitcoin);
+
+        // The merged descriptor string can be parsed back
+        let reparsed = DescriptorParser::parse(parser.descriptor_str())?;
+        assert_eq!(reparsed.descriptor()?, parser.descriptor()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_mismatched_pair() {
+        let external = "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/0/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/0/*))";
+        let mismatched = [
+            // Different script
+            "wsh(multi(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/1/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/1/*))",
+            // Different keys
+            "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/1/*,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/2/*))",
+            // Different threshold
+            "wsh(sortedmulti(1,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/1/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/1/*))",
+            // Same descriptor twice
+            external,
+            // Already a multipath descriptor
+            "wsh(sortedmulti(2,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*,[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj/<0;1>/*))",
+        ];
+
+        for internal in mismatched {
+            let err = DescriptorParser::parse_pair(external, internal)
+                .err()
+                .expect("pair should be rejected");
+            assert!(matches!(
+                err.downcast_ref::<WalletError>(),
+                Some(WalletError::MismatchedDescriptors)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_detect_network_from_key_versions() -> Result<()> {
+        let xpub = "[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*";
+        let tpub = "[73c5da0a/44h/1h/0h]tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba/<0;1>/*";
+        let raw = "02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13";
+
+        let network = |descriptor: &str| DescriptorParser::parse(descriptor)?.detect_network();
+        assert_eq!(network(&format!("tr({tpub})"))?, Network::Testnet);
+        assert_eq!(network(&format!("rawtr({tpub})"))?, Network::Testnet);
+        assert_eq!(
+            network(&format!("wsh(multi(1,{raw},{tpub}))"))?,
+            Network::Testnet
+        );
+
+        // Single keys alone don't tell the network
+        assert_eq!(network(&format!("wpkh({raw})"))?, Network::Bitcoin);
+        assert_eq!(network("raw(6a0568656c6c6f)")?, Network::Bitcoin);
+
+        // Mainnet and testnet keys can't be mixed
+        let err = network(&format!("wsh(multi(1,{xpub},{tpub}))"))
+            .expect_err("mixed networks should be rejected");
+        assert!(matches!(
+            err.downcast_ref::<WalletError>(),
+            Some(WalletError::NetworkDetectionFailed)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_keys() -> Result<()> {
+        let a = "[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn";
+        let b = "[c61af686/48h/0h/0h/2h]xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj";
+        let export = |descriptor: &str, single_key: bool| {
+            DescriptorParser::parse(descriptor)?.export_keys("Test files", single_key)
+        };
+        let unsupported = |descriptor: &str, single_key: bool| match export(descriptor, single_key)
+            .expect_err("unsupported")
+            .downcast::<WalletError>()
+        {
+            std::result::Result::Ok(WalletError::UnsupportedExport(message)) => message,
+            other => panic!("unexpected {other:?}"),
+        };
+
+        // Keys and wallet type give back the descriptor
+        let descriptor = format!("sh(wsh(sortedmulti(2,{a}/<0;1>/*,{b}/<0;1>/*)))");
+        let (wallet_type, threshold, keys) = export(&descriptor, false)?;
+        assert_eq!(wallet_type, WalletType::P2SH_WSH);
+        assert_eq!(threshold, 2);
+        assert_eq!(keys.len(), 2);
+        assert_eq!(
+            DescriptorParser::from_sorted_multi(&wallet_type, threshold, &keys)?.descriptor()?,
+            DescriptorParser::parse(&descriptor)?.descriptor()?
+        );
+
+        let single = format!("wpkh({a}/<0;1>/*)");
+        assert_eq!(export(&single, true)?.0, WalletType::P2WPKH);
+        assert!(unsupported(&single, false).contains("only hold sortedmulti"));
+
+        let timelocked = format!(
+            "wsh(or_d(multi(2,{a}/<0;1>/*,{b}/<0;1>/*),and_v(v:pkh({a}/<2;3>/*),older(144))))"
+        );
+        assert!(unsupported(&timelocked, true).starts_with("Test files only hold"));
+
+        let taproot = format!("tr({a}/<0;1>/*,pk({b}/<0;1>/*))");
+        assert!(unsupported(&taproot, true).contains("taproot"));
+
+        let receive_only = format!("wsh(sortedmulti(2,{a}/0/*,{b}/0/*))");
+        assert!(unsupported(&receive_only, false).contains("xpubs with origin"));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,382 @@
+use std::fmt;
+
+use anyhow::Result;
+use bdk_wallet::bitcoin::base58;
+use bdk_wallet::bitcoin::bip32::{DerivationPath, Fingerprint, Xpub};
+use bdk_wallet::bitcoin::NetworkKind;
+use bdk_wallet::miniscript::Descriptor;
+use serde_json::{json, Map, Value};
+
+use crate::core::descriptor_parser::DescriptorParser;
+use crate::core::error::WalletError;
+use crate::core::pubkey::{AccountXpub, PubKey};
+use crate::core::wallet::WalletType;
+
+/// Version bytes Electrum gives the xpubs of each wallet type, mainnet and testnet
+///
+/// Single key and multisig wallets share xpub/tpub, told apart by `wallet_type`.
+const SCRIPT_VERSIONS: [(WalletType, [u8; 4], [u8; 4]); 6] = [
+    // xpub, tpub
+    (
+        WalletType::P2PKH,
+        [0x04, 0x88, 0xb2, 0x1e],
+        [0x04, 0x35, 0x87, 0xcf],
+    ),
+    // ypub, upub
+    (
+        WalletType::P2SH_WPKH,
+        [0x04, 0x9d, 0x7c, 0xb2],
+        [0x04, 0x4a, 0x52, 0x62],
+    ),
+    // zpub, vpub
+    (
+        WalletType::P2WPKH,
+        [0x04, 0xb2, 0x47, 0x46],
+        [0x04, 0x5f, 0x1c, 0xf6],
+    ),
+    // xpub, tpub
+    (
+        WalletType::P2SH,
+        [0x04, 0x88, 0xb2, 0x1e],
+        [0x04, 0x35, 0x87, 0xcf],
+    ),
+    // Ypub, Upub
+    (
+        WalletType::P2SH_WSH,
+        [0x02, 0x95, 0xb4, 0x3f],
+        [0x02, 0x42, 0x89, 0xef],
+    ),
+    // Zpub, Vpub
+    (
+        WalletType::P2WSH,
+        [0x02, 0xaa, 0x7e, 0xd3],
+        [0x02, 0x57, 0x54, 0x83],
+    ),
+];
+
+/// Oldest wallet file version with keystore origins; Electrum upgrades it on open
+const SEED_VERSION: u32 = 17;
+
+/// BIP32 keystore of an Electrum wallet
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElectrumKeystore {
+    pub root_fingerprint: Fingerprint,
+    pub derivation: DerivationPath,
+    pub xpub: Xpub,
+}
+
+/// Unencrypted Electrum wallet file, single key (`standard`) or multisig (`MofN`)
+///
+/// Electrum has no script type field: it's given by the SLIP-132 version of
+/// the xpubs (zpub for P2WPKH, Zpub for P2WSH...). Multisig wallets are
+/// sortedmulti, and all of them derive `/<0;1>/*` addresses.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElectrumWallet {
+    pub threshold: usize,
+    /// P2PKH, P2SH_WPKH or P2WPKH for single key wallets, P2SH, P2SH_WSH or
+    /// P2WSH for multisig ones
+    pub wallet_type: WalletType,
+    pub keystores: Vec<ElectrumKeystore>,
+}
+
+impl ElectrumWallet {
+    pub fn parse(file: &str) -> Result<Self> {
+        let json: Value =
+            serde_json::from_str(file).map_err(|_| WalletError::InvalidElectrumFile)?;
+        let kind = json["wallet_type"]
+            .as_str()
+            .ok_or(WalletError::InvalidElectrumFile)?;
+
+        let (threshold, keystores) = if kind == "standard" {
+            (1, vec![&json["keystore"]])
+        } else {
+            let (threshold, total) = kind
+                .split_once("of")
+                .ok_or(WalletError::InvalidElectrumFile)?;
+            let parse = |n: &str| n.parse().map_err(|_| WalletError::InvalidElectrumFile);
+            let (threshold, total): (usize, usize) = (parse(threshold)?, parse(total)?);
+            let keystores = (1..=total).map(|i| &json[format!("x{}/", i)]).collect();
+            (threshold, keystores)
+        };
+        if threshold == 0 || threshold > keystores.len() {
+            Err(WalletError::InvalidElectrumFile)?;
+        }
+
+        let multisig = kind != "standard";
+        let mut wallet_type = None;
+        let keystores = keystores
+            .into_iter()
+            .map(|keystore| {
+                let (keystore, keystore_type) = Self::parse_keystore(keystore, multisig)?;
+                match &wallet_type {
+                    Some(wallet_type) if *wallet_type != keystore_type => {
+                        Err(WalletError::InvalidElectrumFile)?
+                    }
+                    _ => wallet_type = Some(keystore_type),
+                }
+                Ok(keystore)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            threshold,
+            wallet_type: wallet_type.ok_or(WalletError::InvalidElectrumFile)?,
+            keystores,
+        })
+    }
+
+    /// Keystore, and the wallet type its xpub version stands for
+    fn parse_keystore(keystore: &Value, multisig: bool) -> Result<(ElectrumKeystore, WalletType)> {
+        let field = |name: &str| {
+            keystore[name]
+                .as_str()
+                .ok_or(WalletError::InvalidElectrumFile)
+        };
+        let xpub = field("xpub")?;
+
+        let version = base58::decode_check(xpub).map_err(|_| WalletError::InvalidElectrumFile)?;
+        let wallet_type = SCRIPT_VERSIONS
+            .iter()
+            .filter(|(wallet_type, _, _)| Self::is_multisig(wallet_type) == multisig)
+            .find(|(_, main, test)| version.get(..4).is_some_and(|v| v == main || v == test))
+            .map(|(wallet_type, _, _)| wallet_type.clone())
+            .ok_or(WalletError::InvalidElectrumFile)?;
+
+        let keystore = ElectrumKeystore {
+            root_fingerprint: field("root_fingerprint")?
+                .parse()
+                .map_err(|_| WalletError::InvalidElectrumFile)?,
+            derivation: field("derivation")?
+                .parse()
+                .map_err(|_| WalletError::InvalidElectrumFile)?,
+            xpub: PubKey::xpub_from_slip132(xpub)?,
+        };
+        Ok((keystore, wallet_type))
+    }
+
+    fn is_multisig(wallet_type: &WalletType) -> bool {
+        matches!(
+            wallet_type,
+            WalletType::P2SH | WalletType::P2SH_WSH | WalletType::P2WSH
+        )
+    }
+
+    /// Descriptor of the wallet, with checksum
+    ///
+    /// Checked with `DescriptorParser`, so keys of mixed networks fail here.
+    pub fn descriptor(&self) -> Result<String> {
+        let keys: Vec<AccountXpub> = self
+            .keystores
+            .iter()
+            .map(|keystore| {
+                (
+                    keystore.root_fingerprint,
+                    keystore.derivation.clone(),
+                    keystore.xpub,
+                )
+            })
+            .collect();
+        let parser = match (&self.wallet_type, keys.as_slice()) {
+            (WalletType::P2PKH | WalletType::P2SH_WPKH | WalletType::P2WPKH, [key]) => {
+                let key = PubKey::account_key(key.0, key.1.clone(), key.2);
+                match self.wallet_type {
+                    WalletType::P2PKH => Descriptor::new_pkh(key)?,
+                    WalletType::P2SH_WPKH => Descriptor::new_sh_wpkh(key)?,
+                    _ => Descriptor::new_wpkh(key)?,
+                }
+                .into()
+            }
+            _ if Self::is_multisig(&self.wallet_type) => {
+                DescriptorParser::from_sorted_multi(&self.wallet_type, self.threshold, &keys)?
+            }
+            _ => Err(WalletError::InvalidElectrumFile)?,
+        };
+
+        parser.detect_network()?;
+        Ok(parser.descriptor()?.to_string())
+    }
+
+    /// Electrum wallet of a descriptor
+    pub fn from_descriptor(descriptor: &str) -> Result<Self> {
+        let (wallet_type, threshold, keystores) =
+            DescriptorParser::parse(descriptor)?.export_keys("Electrum wallets", true)?;
+
+        Ok(Self {
+            threshold,
+            wallet_type,
+            keystores: keystores
+                .into_iter()
+                .map(|(root_fingerprint, derivation, xpub)| ElectrumKeystore {
+                    root_fingerprint,
+                    derivation,
+                    xpub,
+                })
+                .collect(),
+        })
+    }
+
+    /// xpub in the SLIP-132 version Electrum expects for the wallet type
+    fn electrum_xpub(&self, xpub: &Xpub) -> Result<String> {
+        let (_, main, test) = SCRIPT_VERSIONS
+            .iter()
+            .find(|(wallet_type, _, _)| *wallet_type == self.wallet_type)
+            .ok_or(WalletError::InvalidElectrumFile)?;
+        let mut data = xpub.encode();
+        data[..4].copy_from_slice(match xpub.network {
+            NetworkKind::Main => main,
+            NetworkKind::Test => test,
+        });
+        Ok(base58::encode_check(&data))
+    }
+
+    fn keystore_json(&self, keystore: &ElectrumKeystore) -> Result<Value> {
+        Ok(json!({
+            "type": "bip32",
+            "xpub": self.electrum_xpub(&keystore.xpub)?,
+            "xprv": null,
+            "root_fingerprint": keystore.root_fingerprint.to_string(),
+            "derivation": PubKey::origin_path(&keystore.derivation),
+            "label": "",
+        }))
+    }
+
+    /// Watch-only wallet file for Electrum
+    pub fn to_json(&self) -> Result<String> {
+        let mut json = Map::new();
+        if Self::is_multisig(&self.wallet_type) {
+            let kind = format!("{}of{}", self.threshold, self.keystores.len());
+            json.insert("wallet_type".into(), kind.into());
+            for (i, keystore) in self.keystores.iter().enumerate() {
+                json.insert(format!("x{}/", i + 1), self.keystore_json(keystore)?);
+            }
+        } else {
+            let [keystore] = self.keystores.as_slice() else {
+                Err(WalletError::InvalidElectrumFile)?
+            };
+            json.insert("wallet_type".into(), "standard".into());
+            json.insert("keystore".into(), self.keystore_json(keystore)?);
+        }
+        json.insert("use_encryption".into(), false.into());
+        json.insert("seed_version".into(), SEED_VERSION.into());
+
+        Ok(serde_json::to_string_pretty(&json)?)
+    }
+}
+
+impl fmt::Display for ElectrumWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_json().map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XPUB_A: &str = "xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn";
+    const XPUB_B: &str = "xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj";
+
+    /// `xpub` with the version bytes of another SLIP-132 prefix
+    fn slip132(xpub: &str, version: [u8; 4]) -> String {
+        let mut data = base58::decode_check(xpub).expect("valid xpub");
+        data[..4].copy_from_slice(&version);
+        base58::encode_check(&data)
+    }
+
+    fn keystore(xpub: &str, fingerprint: &str, derivation: &str) -> Value {
+        json!({
+            "type": "bip32",
+            "xpub": xpub,
+            "root_fingerprint": fingerprint,
+            "derivation": derivation,
+        })
+    }
+
+    #[test]
+    fn test_electrum_multisig() -> Result<()> {
+        let zpub = |xpub| slip132(xpub, [0x02, 0xaa, 0x7e, 0xd3]);
+        let file = json!({
+            "wallet_type": "2of2",
+            "x1/": keystore(&zpub(XPUB_A), "c449c5c5", "m/48'/0'/0'/2'"),
+            "x2/": keystore(&zpub(XPUB_B), "c61af686", "m/48'/0'/0'/2'"),
+            "seed_version": 52,
+        })
+        .to_string();
+
+        let wallet = ElectrumWallet::parse(&file)?;
+        assert_eq!(wallet.threshold, 2);
+        assert_eq!(wallet.wallet_type, WalletType::P2WSH);
+
+        let descriptor = wallet.descriptor()?;
+        assert!(descriptor.starts_with("wsh(sortedmulti(2,[c449c5c5/48'/0'/0'/2']xpub6Dtni7"));
+
+        let exported = ElectrumWallet::from_descriptor(&descriptor)?;
+        assert_eq!(exported, wallet);
+        let json = exported.to_json()?;
+        assert!(json.contains(&zpub(XPUB_A)));
+        assert_eq!(ElectrumWallet::parse(&json)?, wallet);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_electrum_single_key() -> Result<()> {
+        let cases = [
+            (XPUB_A.to_owned(), "pkh("),
+            (slip132(XPUB_A, [0x04, 0x9d, 0x7c, 0xb2]), "sh(wpkh("),
+            (slip132(XPUB_A, [0x04, 0xb2, 0x47, 0x46]), "wpkh("),
+        ];
+        for (xpub, prefix) in cases {
+            let file = json!({
+                "wallet_type": "standard",
+                "keystore": keystore(&xpub, "c449c5c5", "m/84'/0'/0'"),
+            })
+            .to_string();
+
+            let wallet = ElectrumWallet::parse(&file)?;
+            let descriptor = wallet.descriptor()?;
+            assert!(descriptor.starts_with(prefix));
+            assert!(descriptor.contains(XPUB_A));
+            assert_eq!(ElectrumWallet::from_descriptor(&descriptor)?, wallet);
+            assert!(wallet.to_json()?.contains(&xpub));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_electrum_invalid_files() -> Result<()> {
+        let is_invalid = |file: String| {
+            matches!(
+                ElectrumWallet::parse(&file)
+                    .expect_err("invalid")
+                    .downcast_ref::<WalletError>(),
+                Some(WalletError::InvalidElectrumFile)
+            )
+        };
+
+        // Encrypted files aren't JSON
+        assert!(is_invalid("QklFMQ...".into()));
+        // zpub is for single key wallets
+        let zpub = slip132(XPUB_B, [0x04, 0xb2, 0x47, 0x46]);
+        assert!(is_invalid(
+            json!({
+                "wallet_type": "1of2",
+                "x1/": keystore(XPUB_A, "c449c5c5", "m/45'"),
+                "x2/": keystore(&zpub, "c61af686", "m/45'"),
+            })
+            .to_string()
+        ));
+        // Missing cosigner
+        assert!(is_invalid(
+            json!({
+                "wallet_type": "2of3",
+                "x1/": keystore(XPUB_A, "c449c5c5", "m/45'"),
+                "x2/": keystore(XPUB_B, "c61af686", "m/45'"),
+            })
+            .to_string()
+        ));
+
+        Ok(())
+    }
+}
@@ -14,6 +14,8 @@ pub enum WalletError {
     MissingFingerprint,
     #[error("UnsupportedDescriptor")]
     UnsupportedDescriptor,
+    #[error("TooManySpendPaths")]
+    TooManySpendPaths,
     #[error("UnsupportedKey")]
     UnsupportedKey,
     #[error("UnexpectedError")]
@@ -22,6 +24,44 @@ pub enum WalletError {
     InvalidDescriptorSyntax,
     #[error("NetworkDetectionFailed")]
     NetworkDetectionFailed,
+    #[error("MissingKeychain")]
+    MissingKeychain,
+    #[error("InvalidDerivationIndex")]
+    InvalidDerivationIndex,
+    #[error("TooManyAddresses")]
+    TooManyAddresses,
+    #[error("InvalidAddress")]
+    InvalidAddress,
+    #[error("MismatchedDescriptors")]
+    MismatchedDescriptors,
+    #[error("EmptyTransaction")]
+    EmptyTransaction,
+    #[error("InvalidFeeRate")]
+    InvalidFeeRate,
+    #[error("UnknownOutputType")]
+    UnknownOutputType,
+    #[error("TransactionTooHeavy")]
+    TransactionTooHeavy,
+    #[error("InvalidLikelihood")]
+    InvalidLikelihood,
+    #[error("InvalidBsmsRecord")]
+    InvalidBsmsRecord,
+    #[error("FirstAddressMismatch")]
+    FirstAddressMismatch,
+    #[error("InvalidBsmsToken")]
+    InvalidBsmsToken,
+    #[error("BsmsAuthenticationFailed")]
+    BsmsAuthenticationFailed,
+    #[error("InvalidKeyRecordSignature")]
+    InvalidKeyRecordSignature,
+    #[error("InvalidColdcardFile")]
+    InvalidColdcardFile,
+    #[error("InvalidElectrumFile")]
+    InvalidElectrumFile,
+    #[error("InvalidCaravanConfig")]
+    InvalidCaravanConfig,
+    #[error("UnsupportedExport: {0}")]
+    UnsupportedExport(String),
     #[error("BuilderError: {0}")]
     BuilderError(String),
 
@@ -1,7 +1,14 @@
+pub mod address;
+pub mod bsms;
+pub mod caravan;
+pub mod coldcard;
 pub mod descriptor;
 pub mod descriptor_builder;
 pub mod descriptor_parser;
+pub mod electrum;
 pub mod error;
 pub mod pubkey;
+pub mod signer_group;
+pub mod special_descriptor;
 pub mod spend_path;
 pub mod wallet;
@@ -5,17 +5,37 @@ use std::sync::OnceLock;
 use anyhow::Result;
 use bdk_wallet::bitcoin::bip32::{ChildNumber, DerivationPath, Fingerprint, Xpub};
 use bdk_wallet::bitcoin::hashes::{sha256, Hash, HashEngine};
-use bdk_wallet::bitcoin::secp256k1::PublicKey;
-use bdk_wallet::bitcoin::{Network, NetworkKind};
+use bdk_wallet::bitcoin::secp256k1::{Parity, PublicKey, XOnlyPublicKey};
+use bdk_wallet::bitcoin::{base58, Network, NetworkKind};
 use bdk_wallet::keys::DescriptorPublicKey;
+use bdk_wallet::miniscript::descriptor::{DerivPaths, DescriptorMultiXKey, SinglePubKey, Wildcard};
 use bdk_wallet::miniscript::{Descriptor, ForEachKey};
 use bdk_wallet::{KeychainKind, Wallet};
 
 use crate::core::error::WalletError;
 
+/// Origin and xpub of an account key, see `PubKey::account_xpub`
+pub type AccountXpub = (Fingerprint, DerivationPath, Xpub);
+
 /// BIP341 NUMS point as compressed pubkey (02 prefix + x-coordinate)
 const NUMS_PUBKEY_HEX: &str = "0250929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0";
 
+/// Version bytes of xpub and tpub
+const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xb2, 0x1e];
+const TPUB_VERSION: [u8; 4] = [0x04, 0x35, 0x87, 0xcf];
+
+/// SLIP-132 version bytes of extended public keys and their network
+const SLIP132_VERSIONS: [([u8; 4], NetworkKind); 8] = [
+    ([0x04, 0x9d, 0x7c, 0xb2], NetworkKind::Main), // ypub
+    ([0x04, 0xb2, 0x47, 0x46], NetworkKind::Main), // zpub
+    ([0x02, 0x95, 0xb4, 0x3f], NetworkKind::Main), // Ypub
+    ([0x02, 0xaa, 0x7e, 0xd3], NetworkKind::Main), // Zpub
+    ([0x04, 0x4a, 0x52, 0x62], NetworkKind::Test), // upub
+    ([0x04, 0x5f, 0x1c, 0xf6], NetworkKind::Test), // vpub
+    ([0x02, 0x42, 0x89, 0xef], NetworkKind::Test), // Upub
+    ([0x02, 0x57, 0x54, 0x83], NetworkKind::Test), // Vpub
+];
+
 /// Singleton NUMS public key (initialized once, thread-safe)
 static NUMS_PUBKEY: OnceLock<PublicKey> = OnceLock::new();
 
@@ -58,16 +78,11 @@ impl TryFrom<DescriptorPublicKey> for PubKey {
     type Error = anyhow::Error;
 
     fn try_from(key: DescriptorPublicKey) -> Result<PubKey, Self::Error> {
-        match &key {
-            DescriptorPublicKey::XPub(_) | DescriptorPublicKey::MultiXPub(_) => {
-                let is_unspendable = Self::check_is_unspendable(&key)?;
-                Ok(PubKey {
-                    inner: key,
-                    is_unspendable,
-                })
-            }
-            DescriptorPublicKey::Single(_) => Err(WalletError::UnsupportedKey.into()),
-        }
+        let is_unspendable = Self::check_is_unspendable(&key)?;
+        Ok(PubKey {
+            inner: key,
+            is_unspendable,
+        })
     }
 }
 
@@ -78,7 +93,21 @@ impl fmt::Display for PubKey {
 }
 
 impl PubKey {
+    /// Build a key from its parts; `xpub` may also be a raw hex public key
+    ///
+    /// A raw key given with its synthetic fingerprint and no derivation path
+    /// is kept without origin, as it was analyzed.
     pub fn new(mfp: &str, derivation_path: &str, xpub: &str) -> Result<Self> {
+        if derivation_path.is_empty() {
+            if let std::result::Result::Ok(key @ DescriptorPublicKey::Single(_)) =
+                xpub.parse::<DescriptorPublicKey>()
+            {
+                if key.master_fingerprint().to_string() == mfp {
+                    return Self::try_from(key);
+                }
+            }
+        }
+
         let keystr = if derivation_path.is_empty() {
             format!("[{}]{}", mfp, xpub)
         } else {
@@ -87,10 +116,56 @@ impl PubKey {
         Self::try_from(&keystr)
     }
 
+    /// Master fingerprint of the key
+    ///
+    /// Raw keys without origin get a synthetic one from the hash of the key,
+    /// see `has_origin`.
     pub fn mfp(&self) -> Fingerprint {
         self.inner.master_fingerprint()
     }
 
+    /// Whether the key carries origin info (`[mfp/path]`)
+    ///
+    /// Extended keys always have a fingerprint, their own one if there's no origin.
+    pub fn has_origin(&self) -> bool {
+        match &self.inner {
+            DescriptorPublicKey::Single(k) => k.origin.is_some(),
+            DescriptorPublicKey::XPub(_) | DescriptorPublicKey::MultiXPub(_) => true,
+        }
+    }
+
+    /// Whether this is a raw public key instead of an extended one
+    pub fn is_raw(&self) -> bool {
+        matches!(self.inner, DescriptorPublicKey::Single(_))
+    }
+
+    /// Hex of a raw public key (compressed or x-only)
+    pub fn raw_key(&self) -> Result<String> {
+        match &self.inner {
+            DescriptorPublicKey::Single(k) => Ok(match k.key {
+                SinglePubKey::FullKey(pk) => pk.to_string(),
+                SinglePubKey::XOnly(xpk) => xpk.to_string(),
+            }),
+            DescriptorPublicKey::XPub(_) | DescriptorPublicKey::MultiXPub(_) => {
+                Err(WalletError::UnsupportedKey.into())
+            }
+        }
+    }
+
+    /// Public key of a raw key, or of the extended key itself
+    ///
+    /// X-only keys are lifted to their even-Y point.
+    pub fn public_key(&self) -> PublicKey {
+        match &self.inner {
+            DescriptorPublicKey::XPub(k) => k.xkey.public_key,
+            DescriptorPublicKey::MultiXPub(k) => k.xkey.public_key,
+            DescriptorPublicKey::Single(k) => match k.key {
+                SinglePubKey::FullKey(pk) => pk.inner,
+                SinglePubKey::XOnly(xpk) => xpk.public_key(Parity::Even),
+            },
+        }
+    }
+
     pub fn derivation_path(&self) -> Result<DerivationPath> {
         // Get the origin/master derivation path (the fixed part before wildcards)
         match &self.inner {
@@ -104,7 +179,11 @@ impl PubKey {
                 .as_ref()
                 .map(|(_, path)| path.clone())
                 .unwrap_or_default()),
-            DescriptorPublicKey::Single(_) => Err(WalletError::UnsupportedKey.into()),
+            DescriptorPublicKey::Single(k) => Ok(k
+                .origin
+                .as_ref()
+                .map(|(_, path)| path.clone())
+                .unwrap_or_default()),
         }
     }
 
@@ -116,7 +195,11 @@ impl PubKey {
         }
     }
 
+    /// Raw keys carry no network, so they are compatible with any
     pub fn is_compatible_with_network(&self, network: Network) -> Result<bool> {
+        if self.is_raw() {
+            return Ok(true);
+        }
         Ok(self.xpub()?.network == NetworkKind::from(network))
     }
 
@@ -125,16 +208,104 @@ impl PubKey {
         self.is_unspendable
     }
 
+    /// BIP341 NUMS point as an x-only key, for descriptors without extended keys
+    pub fn nums_xonly() -> XOnlyPublicKey {
+        get_nums_pubkey().x_only_public_key().0
+    }
+
     /// Check if a descriptor public key uses the NUMS point (private helper)
     fn check_is_unspendable(key: &DescriptorPublicKey) -> Result<bool> {
-        let xpub = match key {
-            DescriptorPublicKey::XPub(k) => k.xkey,
-            DescriptorPublicKey::MultiXPub(k) => k.xkey,
-            DescriptorPublicKey::Single(_) => return Ok(false),
+        let nums = get_nums_pubkey();
+        match key {
+            DescriptorPublicKey::XPub(k) => Ok(k.xkey.public_key == *nums),
+            DescriptorPublicKey::MultiXPub(k) => Ok(k.xkey.public_key == *nums),
+            // Raw keys may be x-only, so compare the x coordinate
+            DescriptorPublicKey::Single(k) => Ok(match k.key {
+                SinglePubKey::FullKey(pk) => {
+                    pk.inner.x_only_public_key().0 == nums.x_only_public_key().0
+                }
+                SinglePubKey::XOnly(xpk) => xpk == nums.x_only_public_key().0,
+            }),
+        }
+    }
+
+    /// Parse an extended public key, also in the SLIP-132 formats
+    ///
+    /// ypub/zpub/Ypub/Zpub (and their testnet upub/vpub/Upub/Vpub) only differ
+    /// from xpub/tpub in the version bytes, which hint at the script type.
+    pub fn xpub_from_slip132(key: &str) -> Result<Xpub> {
+        if let std::result::Result::Ok(xpub) = key.parse::<Xpub>() {
+            return Ok(xpub);
+        }
+
+        let mut data = base58::decode_check(key).map_err(|_| WalletError::UnsupportedKey)?;
+        let network = SLIP132_VERSIONS
+            .iter()
+            .find(|(version, _)| data.get(..4) == Some(&version[..]))
+            .map(|(_, network)| *network)
+            .ok_or(WalletError::UnsupportedKey)?;
+        let version = match network {
+            NetworkKind::Main => XPUB_VERSION,
+            NetworkKind::Test => TPUB_VERSION,
+        };
+        data[..4].copy_from_slice(&version);
+        Ok(Xpub::decode(&data).map_err(|_| WalletError::UnsupportedKey)?)
+    }
+
+    /// Origin and xpub of a key deriving receive and change addresses with `/<0;1>/*`
+    ///
+    /// Wallet setup files (Coldcard, Electrum...) can only hold such keys.
+    pub fn account_xpub(key: &DescriptorPublicKey) -> Option<AccountXpub> {
+        let DescriptorPublicKey::MultiXPub(multi) = key else {
+            return None;
         };
+        let (mfp, path) = multi.origin.clone()?;
+        let paths = multi.derivation_paths.paths();
+        let receive_change = paths.len() == 2
+            && paths[0] == DerivationPath::from(vec![ChildNumber::from(0)])
+            && paths[1] == DerivationPath::from(vec![ChildNumber::from(1)])
+            && multi.wildcard == Wildcard::Unhardened;
+
+        receive_change.then_some((mfp, path, multi.xkey))
+    }
+
+    /// `account_xpub` of every key of a descriptor, None if any key isn't one
+    pub fn account_xpubs(descriptor: &Descriptor<DescriptorPublicKey>) -> Option<Vec<AccountXpub>> {
+        let mut keys = Vec::new();
+        let all_accounts = descriptor.for_each_key(|key| match Self::account_xpub(key) {
+            Some(key) => {
+                keys.push(key);
+                true
+            }
+            None => false,
+        });
+        all_accounts.then_some(keys)
+    }
 
-        // Compare with NUMS pubkey (singleton)
-        Ok(xpub.public_key == *get_nums_pubkey())
+    /// Key of an account deriving receive and change addresses with `/<0;1>/*`
+    ///
+    /// Inverse of `account_xpub`.
+    pub fn account_key(mfp: Fingerprint, path: DerivationPath, xpub: Xpub) -> DescriptorPublicKey {
+        let receive_change = DerivPaths::new(vec![
+            DerivationPath::from(vec![ChildNumber::from(0)]),
+            DerivationPath::from(vec![ChildNumber::from(1)]),
+        ])
+        .expect("two paths");
+        DescriptorPublicKey::MultiXPub(DescriptorMultiXKey {
+            origin: Some((mfp, path)),
+            xkey: xpub,
+            derivation_paths: receive_change,
+            wildcard: Wildcard::Unhardened,
+        })
+    }
+
+    /// Derivation path as wallet files write it: `m`, or `m/48h/0h/0h/2h`
+    pub fn origin_path(path: &DerivationPath) -> String {
+        if path.is_master() {
+            "m".to_owned()
+        } else {
+            format!("m/{}", path)
+        }
     }
 
     /// Generate an unspendable xpub
@@ -151,8 +322,7 @@ impl PubKey {
         // Collect all pubkeys
         let mut pubkeys: Vec<Vec<u8>> = Vec::new();
         for key in keys {
-            let xpub = key.xpub()?;
-            pubkeys.push(xpub.public_key.serialize().to_vec());
+            pubkeys.push(key.public_key().serialize().to_vec());
         }
 
         // Sort and deduplicate
@@ -183,7 +353,8 @@ impl PubKey {
     ///
     /// This is the new preferred method that avoids wallet creation.
     /// Uses the ForEachKey trait directly on the descriptor.
-    /// Filters out unspendable (NUMS) keys and unsupported key types.
+    /// Filters out unspendable (NUMS) keys. Raw keys are included, identified by
+    /// their origin fingerprint or a synthetic one (see `mfp`).
     ///
     /// Special case: For Taproot descriptors without keypath spend (internal_key is NUMS),
     /// generates a deterministic NUMS xpub and includes it in the results.
@@ -203,12 +374,10 @@ impl PubKey {
         let mut pub_keys: Vec<PubKey> = Vec::new();
 
         for key in keys {
-            // Skip unsupported key types (e.g., Single/raw keys like NUMS points)
-            if let Ok(pubkey) = Self::try_from(key.clone()) {
-                // Skip unspendable (NUMS) keys from script paths
-                if !pubkey.is_unspendable() {
-                    pub_keys.push(pubkey);
-                }
+            let pubkey = Self::try_from(key.clone())?;
+            // Skip unspendable (NUMS) keys from script paths
+            if !pubkey.is_unspendable() {
+                pub_keys.push(pubkey);
             }
         }
 
@@ -254,9 +423,9 @@ mod tests {
         assert_eq!(&keystr, &reskey);
 
         let compatible_with_mainnet = pk.is_compatible_with_network(Network::Bitcoin)?;
-        assert_eq!(compatible_with_mainnet, false);
+        assert!(!compatible_with_mainnet);
         let compatible_with_signet = pk.is_compatible_with_network(Network::Signet)?;
-        assert_eq!(compatible_with_signet, true);
+        assert!(compatible_with_signet);
 
         Ok(())
     }
@@ -315,7 +484,7 @@ mod tests {
         assert_eq!(nums_xpub.child_number, ChildNumber::from_normal_idx(0)?);
 
         // Create a PubKey from the NUMS xpub and verify it's detected as unspendable
-        let nums_key_str = format!("[00000000]{}", nums_xpub.to_string());
+        let nums_key_str = format!("[00000000]{}", nums_xpub);
         let nums_key = PubKey::try_from(nums_key_str.as_str())?;
         assert!(
             nums_key.is_unspendable(),
@@ -396,4 +565,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_raw_keys() -> Result<()> {
+        // No origin: synthetic fingerprint from the key hash
+        let raw =
+            PubKey::try_from("02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13")?;
+        assert!(raw.is_raw());
+        assert!(!raw.has_origin());
+        assert_eq!(
+            raw.raw_key()?,
+            "02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13"
+        );
+        assert_eq!(raw.derivation_path()?.to_string(), "");
+        assert!(raw.xpub().is_err());
+        assert!(raw.is_compatible_with_network(Network::Bitcoin)?);
+
+        // Rebuilding from its parts keeps it without origin
+        let rebuilt = PubKey::new(&raw.mfp().to_string(), "", &raw.raw_key()?)?;
+        assert_eq!(
+            rebuilt.to_string(),
+            "02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13"
+        );
+        assert!(!rebuilt.has_origin());
+
+        // With origin info
+        let with_origin = PubKey::new(
+            "deadbeef",
+            "48h/0h/0h/2h",
+            "02c6047f9441ed7d6d3034a46e1e3ec1e8e84c6b8c9b3c1c0c6c8c27c1e6a5a1bc",
+        )?;
+        assert!(with_origin.has_origin());
+        assert_eq!(with_origin.mfp().to_string(), "deadbeef");
+        assert_eq!(with_origin.derivation_path()?.to_string(), "48'/0'/0'/2'");
+
+        // X-only raw key of the NUMS point
+        let nums = PubKey::try_from(PubKey::nums_xonly().to_string().as_str())?;
+        assert!(nums.is_raw());
+        assert!(nums.is_unspendable());
+        assert!(!raw.is_unspendable());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_raw_keys_extracted_from_descriptor() -> Result<()> {
+        let descriptor: Descriptor<DescriptorPublicKey> =
+            "wsh(multi(2,02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13,[deadbeef/48h/0h/0h/2h]02c6047f9441ed7d6d3034a46e1e3ec1e8e84c6b8c9b3c1c0c6c8c27c1e6a5a1bc,[c449c5c5/48h/0h/0h/2h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*))".parse()?;
+
+        let keys = PubKey::extract_from_descriptor(&descriptor)?;
+        assert_eq!(keys.len(), 3);
+        assert!(keys[0].is_raw() && !keys[0].has_origin());
+        assert!(keys[1].is_raw() && keys[1].has_origin());
+        assert_eq!(keys[1].mfp().to_string(), "deadbeef");
+        assert!(!keys[2].is_raw());
+
+        Ok(())
+    }
 }
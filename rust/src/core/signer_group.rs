@@ -0,0 +1,314 @@
+use std::collections::BTreeSet;
+
+/// Group of signers with its own threshold
+///
+/// Each nested group counts as a single item towards the parent threshold,
+/// e.g. 2-of-(A, 2-of-(B,C,D)) is a group with threshold 2, signer A and
+/// a nested 2-of-3 group.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SignerGroup {
+    pub threshold: usize,
+    /// Direct signers of this group (nested groups excluded)
+    pub mfps: Vec<String>,
+    pub groups: Vec<SignerGroup>,
+}
+
+impl SignerGroup {
+    pub fn new(threshold: usize, mfps: Vec<String>, groups: Vec<SignerGroup>) -> Self {
+        let mut mfps = mfps;
+        mfps.sort();
+        mfps.dedup();
+        let mut groups = groups;
+        groups.sort();
+
+        Self {
+            threshold,
+            mfps,
+            groups,
+        }
+    }
+
+    /// Number of items (signers and nested groups) of this group
+    pub fn len(&self) -> usize {
+        self.mfps.len() + self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether every item is required (n-of-n)
+    pub fn is_conjunction(&self) -> bool {
+        self.threshold == self.len()
+    }
+
+    /// Every signer in this group and its nested groups
+    pub fn all_mfps(&self) -> BTreeSet<String> {
+        let mut mfps: BTreeSet<String> = self.mfps.iter().cloned().collect();
+        for group in &self.groups {
+            mfps.extend(group.all_mfps());
+        }
+        mfps
+    }
+
+    /// Smallest number of signatures that satisfies the group
+    pub fn min_signatures(&self) -> usize {
+        let mut costs: Vec<usize> = vec![1; self.mfps.len()];
+        costs.extend(self.groups.iter().map(SignerGroup::min_signatures));
+        costs.sort();
+        costs.iter().take(self.threshold).sum()
+    }
+
+    /// One of the smallest sets of signers that satisfies the group
+    ///
+    /// Among direct signers the last ones (by fingerprint) are preferred.
+    pub fn signing_set(&self) -> BTreeSet<String> {
+        let mut candidates: Vec<BTreeSet<String>> = self
+            .mfps
+            .iter()
+            .rev()
+            .map(|mfp| BTreeSet::from([mfp.clone()]))
+            .collect();
+        candidates.extend(self.groups.iter().map(SignerGroup::signing_set));
+        candidates.sort_by_key(BTreeSet::len);
+
+        candidates
+            .into_iter()
+            .take(self.threshold)
+            .flatten()
+            .collect()
+    }
+
+    /// Every minimal set of signers that satisfies the group
+    ///
+    /// A set is minimal when no signer can be removed from it. Enumeration stops
+    /// after `limit` sets, as the number of combinations grows quickly, and the
+    /// returned flag tells whether any set was left out.
+    pub fn minimal_signer_sets(&self, limit: usize) -> (Vec<BTreeSet<String>>, bool) {
+        let mut truncated = false;
+        let mut options: Vec<Vec<BTreeSet<String>>> = self
+            .mfps
+            .iter()
+            .map(|mfp| vec![BTreeSet::from([mfp.clone()])])
+            .collect();
+        for group in &self.groups {
+            let (sets, group_truncated) = group.minimal_signer_sets(limit);
+            truncated |= group_truncated;
+            options.push(sets);
+        }
+
+        let mut sets: BTreeSet<BTreeSet<String>> = BTreeSet::new();
+        for combination in combinations(options.len(), self.threshold) {
+            let items: Vec<&[BTreeSet<String>]> =
+                combination.iter().map(|&i| options[i].as_slice()).collect();
+            if !insert_unions(&items, BTreeSet::new(), &mut sets, limit) {
+                truncated = true;
+                break;
+            }
+        }
+
+        let minimal = sets
+            .iter()
+            .filter(|set| {
+                !sets
+                    .iter()
+                    .any(|other| other.len() < set.len() && other.is_subset(set))
+            })
+            .cloned()
+            .collect();
+        (minimal, truncated)
+    }
+
+    /// Canonical encoding used to derive spend path IDs
+    pub fn id_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![b'('];
+        bytes.extend_from_slice(&self.threshold.to_le_bytes());
+        for mfp in &self.mfps {
+            bytes.extend_from_slice(mfp.as_bytes());
+        }
+        for group in &self.groups {
+            bytes.extend(group.id_bytes());
+        }
+        bytes.push(b')');
+        bytes
+    }
+
+    /// Merge nested n-of-n groups into an n-of-n parent
+    ///
+    /// AND(A, AND(B, C)) is the same as AND(A, B, C).
+    pub fn flatten(self) -> Self {
+        if !self.is_conjunction() {
+            return self;
+        }
+
+        let mut mfps = self.mfps;
+        let mut groups = Vec::new();
+        for group in self.groups {
+            if group.is_conjunction() && group.groups.is_empty() {
+                mfps.extend(group.mfps);
+            } else {
+                groups.push(group);
+            }
+        }
+
+        let threshold = mfps.len() + groups.len();
+        Self::new(threshold, mfps, groups)
+    }
+}
+
+/// Insert into `sets` every union of `base` with one option of each item
+///
+/// Returns false, leaving the remaining unions out, as soon as a new set
+/// would go over `limit`.
+fn insert_unions(
+    items: &[&[BTreeSet<String>]],
+    base: BTreeSet<String>,
+    sets: &mut BTreeSet<BTreeSet<String>>,
+    limit: usize,
+) -> bool {
+    match items.split_first() {
+        Some((options, rest)) => options
+            .iter()
+            .all(|option| insert_unions(rest, base.union(option).cloned().collect(), sets, limit)),
+        None if sets.len() >= limit && !sets.contains(&base) => false,
+        None => {
+            sets.insert(base);
+            true
+        }
+    }
+}
+
+/// Every `k`-sized subset of `0..n`, as sorted indexes in lexicographic order
+///
+/// Subsets are produced one at a time, so callers can stop early.
+pub(crate) fn combinations(n: usize, k: usize) -> Combinations {
+    Combinations {
+        n,
+        next: (k <= n).then(|| (0..k).collect()),
+    }
+}
+
+pub(crate) struct Combinations {
+    n: usize,
+    next: Option<Vec<usize>>,
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let current = self.next.take()?;
+        let k = current.len();
+
+        // Move the rightmost index that can still move, and reset the following ones
+        if let Some(i) = (0..k).rev().find(|&i| current[i] < self.n - k + i) {
+            let mut next = current.clone();
+            next[i] += 1;
+            for j in i + 1..k {
+                next[j] = next[j - 1] + 1;
+            }
+            self.next = Some(next);
+        }
+
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mfps(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_nested_group_signatures() {
+        // 2-of-(A, 2-of-(B,C,D))
+        let inner = SignerGroup::new(2, mfps(&["bbbbbbbb", "cccccccc", "dddddddd"]), vec![]);
+        let outer = SignerGroup::new(2, mfps(&["aaaaaaaa"]), vec![inner]);
+
+        assert_eq!(outer.len(), 2);
+        assert!(outer.is_conjunction());
+        assert_eq!(outer.all_mfps().len(), 4);
+        assert_eq!(outer.min_signatures(), 3);
+
+        let set = outer.signing_set();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains("aaaaaaaa"));
+    }
+
+    #[test]
+    fn test_signing_set_prefers_cheapest_items() {
+        // 2-of-(A, B, 3-of-(C,D,E)) only needs A and B
+        let inner = SignerGroup::new(3, mfps(&["cccccccc", "dddddddd", "eeeeeeee"]), vec![]);
+        let outer = SignerGroup::new(2, mfps(&["aaaaaaaa", "bbbbbbbb"]), vec![inner]);
+
+        assert_eq!(outer.min_signatures(), 2);
+        assert_eq!(
+            outer.signing_set(),
+            BTreeSet::from(["aaaaaaaa".to_string(), "bbbbbbbb".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_minimal_signer_sets() {
+        let group = SignerGroup::new(2, mfps(&["aaaaaaaa", "bbbbbbbb", "cccccccc"]), vec![]);
+        let (sets, truncated) = group.minimal_signer_sets(100);
+        assert_eq!(sets.len(), 3);
+        assert!(sets.iter().all(|s| s.len() == 2));
+        assert!(!truncated);
+        assert!(!group.minimal_signer_sets(3).1);
+
+        // 2-of-(A, 2-of-(B,C,D)): A plus any pair of B, C, D
+        let inner = SignerGroup::new(2, mfps(&["bbbbbbbb", "cccccccc", "dddddddd"]), vec![]);
+        let outer = SignerGroup::new(2, mfps(&["aaaaaaaa"]), vec![inner]);
+        let (sets, truncated) = outer.minimal_signer_sets(100);
+        assert_eq!(sets.len(), 3);
+        assert!(sets.iter().all(|s| s.len() == 3 && s.contains("aaaaaaaa")));
+        assert!(!truncated);
+
+        let (sets, truncated) = outer.minimal_signer_sets(2);
+        assert_eq!(sets.len(), 2);
+        assert!(truncated);
+    }
+
+    #[test]
+    fn test_minimal_signer_sets_stop_at_limit() {
+        // 10-of-20 has 184756 combinations, only the first ones are looked at
+        let signers: Vec<String> = (0..20).map(|i| format!("{i:08x}")).collect();
+        let group = SignerGroup::new(10, signers, vec![]);
+        let (sets, truncated) = group.minimal_signer_sets(5);
+        assert_eq!(sets.len(), 5);
+        assert!(truncated);
+    }
+
+    #[test]
+    fn test_combinations() {
+        let all: Vec<Vec<usize>> = combinations(4, 2).collect();
+        assert_eq!(
+            all,
+            vec![
+                vec![0, 1],
+                vec![0, 2],
+                vec![0, 3],
+                vec![1, 2],
+                vec![1, 3],
+                vec![2, 3]
+            ]
+        );
+        assert_eq!(combinations(3, 0).count(), 1);
+        assert_eq!(combinations(2, 3).count(), 0);
+        assert_eq!(combinations(30, 15).take(10).count(), 10);
+    }
+
+    #[test]
+    fn test_flatten_conjunctions() {
+        let inner = SignerGroup::new(2, mfps(&["bbbbbbbb", "cccccccc"]), vec![]);
+        let outer = SignerGroup::new(2, mfps(&["aaaaaaaa"]), vec![inner]).flatten();
+
+        assert_eq!(outer.threshold, 3);
+        assert_eq!(outer.mfps.len(), 3);
+        assert!(outer.groups.is_empty());
+    }
+}
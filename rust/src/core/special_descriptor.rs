@@ -0,0 +1,244 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use bdk_wallet::bitcoin::address::NetworkUnchecked;
+use bdk_wallet::bitcoin::{Address, Network, ScriptBuf};
+use bdk_wallet::keys::DescriptorPublicKey;
+use bdk_wallet::miniscript::descriptor::checksum::desc_checksum;
+use bdk_wallet::miniscript::descriptor::SinglePubKey;
+use bdk_wallet::miniscript::Descriptor;
+
+use crate::core::error::WalletError;
+use crate::core::pubkey::PubKey;
+use crate::core::spend_path::{extend_spend_path_id, SpendPath, Spendability};
+use crate::core::wallet::WalletType;
+
+/// Bitcoin Core descriptors that miniscript doesn't parse
+///
+/// They are analyzed with whatever can be inferred from them: `addr()` and
+/// `raw()` only tell the output script, `rawtr()` and `combo()` also the key.
+#[derive(Debug, Clone)]
+pub enum SpecialDescriptor {
+    /// `addr(ADDR)`
+    Addr(Address<NetworkUnchecked>),
+    /// `raw(HEX)`
+    Raw(ScriptBuf),
+    /// `rawtr(KEY)`: KEY is the taproot output key, without tweak
+    RawTr(DescriptorPublicKey),
+    /// `combo(KEY)`: P2PK and P2PKH outputs, plus P2WPKH and P2SH-P2WPKH
+    /// when the key is compressed
+    Combo(DescriptorPublicKey),
+}
+
+impl SpecialDescriptor {
+    /// Parse one of the special descriptors
+    ///
+    /// Returns `None` if the string isn't one of them, so it can be tried
+    /// after the miniscript parser.
+    pub fn parse(descriptor: &str) -> Result<Option<Self>> {
+        let descriptor = Self::strip_checksum(descriptor.trim())?;
+        let Some((name, arg)) = descriptor.strip_suffix(')').and_then(|s| s.split_once('(')) else {
+            return Ok(None);
+        };
+
+        let parsed = match name {
+            "addr" => Self::Addr(
+                arg.parse()
+                    .map_err(|_| WalletError::InvalidDescriptorSyntax)?,
+            ),
+            "raw" => Self::Raw(
+                ScriptBuf::from_hex(arg).map_err(|_| WalletError::InvalidDescriptorSyntax)?,
+            ),
+            "rawtr" => Self::RawTr(Self::parse_key(arg)?),
+            "combo" => {
+                let key = Self::parse_key(arg)?;
+                if Self::is_x_only(&key) {
+                    Err(WalletError::InvalidDescriptorSyntax)?;
+                }
+                Self::Combo(key)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(parsed))
+    }
+
+    fn strip_checksum(descriptor: &str) -> Result<&str> {
+        let Some((descriptor, checksum)) = descriptor.split_once('#') else {
+            return Ok(descriptor);
+        };
+        if desc_checksum(descriptor)? != checksum {
+            Err(WalletError::InvalidDescriptorSyntax)?;
+        }
+        Ok(descriptor)
+    }
+
+    fn parse_key(key: &str) -> Result<DescriptorPublicKey> {
+        Ok(DescriptorPublicKey::from_str(key).map_err(|_| WalletError::InvalidDescriptorSyntax)?)
+    }
+
+    /// Script type of the outputs
+    ///
+    /// `combo()` mixes several script types, so it is `Unknown`.
+    pub fn wallet_type(&self) -> WalletType {
+        let script = match self {
+            Self::Addr(address) => address.assume_checked_ref().script_pubkey(),
+            Self::Raw(script) => script.clone(),
+            Self::RawTr(_) => return WalletType::P2TR,
+            Self::Combo(_) => return WalletType::Unknown,
+        };
+
+        if script.is_p2pkh() {
+            WalletType::P2PKH
+        } else if script.is_p2sh() {
+            WalletType::P2SH
+        } else if script.is_p2wpkh() {
+            WalletType::P2WPKH
+        } else if script.is_p2wsh() {
+            WalletType::P2WSH
+        } else if script.is_p2tr() {
+            WalletType::P2TR
+        } else {
+            WalletType::Unknown
+        }
+    }
+
+    /// Network of an `addr()` descriptor
+    ///
+    /// Testnet, signet and testnet4 share the address format and are all
+    /// reported as testnet.
+    pub fn network(&self) -> Option<Network> {
+        let Self::Addr(address) = self else {
+            return None;
+        };
+        [Network::Bitcoin, Network::Testnet, Network::Regtest]
+            .into_iter()
+            .find(|&network| address.is_valid_for_network(network))
+    }
+
+    pub fn keys(&self) -> Vec<&DescriptorPublicKey> {
+        match self {
+            Self::Addr(_) | Self::Raw(_) => vec![],
+            Self::RawTr(key) | Self::Combo(key) => vec![key],
+        }
+    }
+
+    pub fn spendability(&self) -> Spendability {
+        match self {
+            Self::Raw(script) if script.is_op_return() => Spendability::Unspendable,
+            Self::Addr(_) | Self::Raw(_) => Spendability::Unknown,
+            Self::RawTr(key) => match PubKey::try_from(key.clone()) {
+                Ok(pk) if pk.is_unspendable() => Spendability::Unspendable,
+                _ => Spendability::Known,
+            },
+            Self::Combo(_) => Spendability::Known,
+        }
+    }
+
+    /// Miniscript descriptors spent the same way as this one
+    ///
+    /// `rawtr(KEY)` has the witness of `tr(KEY)`, only the output key differs.
+    /// `combo(KEY)` is split into its P2PKH, P2WPKH and P2SH-P2WPKH outputs;
+    /// the bare P2PK output is left out.
+    pub fn equivalent_descriptors(&self) -> Result<Vec<Descriptor<DescriptorPublicKey>>> {
+        match self {
+            Self::Addr(_) | Self::Raw(_) => Ok(vec![]),
+            Self::RawTr(key) => Ok(vec![Descriptor::new_tr(key.clone(), None)?]),
+            Self::Combo(key) => {
+                let mut descriptors = vec![Descriptor::new_pkh(key.clone())?];
+                if Self::is_compressed(key) {
+                    descriptors.push(Descriptor::new_wpkh(key.clone())?);
+                    descriptors.push(Descriptor::new_sh_wpkh(key.clone())?);
+                }
+                Ok(descriptors)
+            }
+        }
+    }
+
+    fn is_x_only(key: &DescriptorPublicKey) -> bool {
+        matches!(
+            key,
+            DescriptorPublicKey::Single(single) if matches!(single.key, SinglePubKey::XOnly(_))
+        )
+    }
+
+    fn is_compressed(key: &DescriptorPublicKey) -> bool {
+        match key {
+            DescriptorPublicKey::Single(single) => match single.key {
+                SinglePubKey::FullKey(pk) => pk.compressed,
+                SinglePubKey::XOnly(_) => false,
+            },
+            DescriptorPublicKey::XPub(_) | DescriptorPublicKey::MultiXPub(_) => true,
+        }
+    }
+
+    /// Spend paths of the equivalent descriptors
+    ///
+    /// `combo()` paths only differ in the script type, which is mixed into
+    /// their IDs to tell them apart.
+    pub fn spend_paths(&self, network: Network) -> Result<Vec<SpendPath>> {
+        let mut spend_paths = Vec::new();
+        for descriptor in self.equivalent_descriptors()? {
+            spend_paths.extend(SpendPath::extract_from_descriptor(&descriptor, network)?);
+        }
+
+        if let Self::Combo(_) = self {
+            for sp in &mut spend_paths {
+                sp.id = extend_spend_path_id(sp.id, [sp.addr_type.as_bytes()]);
+            }
+        }
+        Ok(spend_paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XPUB: &str = "[c449c5c5/84h/0h/0h]xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn/<0;1>/*";
+
+    #[test]
+    fn test_parse_special_descriptors() -> Result<()> {
+        let addr = SpecialDescriptor::parse("addr(bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq)")?
+            .expect("addr() should parse");
+        assert_eq!(addr.wallet_type(), WalletType::P2WPKH);
+        assert_eq!(addr.network(), Some(Network::Bitcoin));
+        assert_eq!(addr.spendability(), Spendability::Unknown);
+        assert!(addr.keys().is_empty());
+
+        let op_return =
+            SpecialDescriptor::parse("raw(6a0568656c6c6f)")?.expect("raw() should parse");
+        assert_eq!(op_return.wallet_type(), WalletType::Unknown);
+        assert_eq!(op_return.spendability(), Spendability::Unspendable);
+
+        let rawtr =
+            SpecialDescriptor::parse(&format!("rawtr({XPUB})"))?.expect("rawtr() should parse");
+        assert_eq!(rawtr.wallet_type(), WalletType::P2TR);
+        assert_eq!(rawtr.keys().len(), 1);
+        assert_eq!(rawtr.spendability(), Spendability::Known);
+
+        let combo =
+            SpecialDescriptor::parse(&format!("combo({XPUB})"))?.expect("combo() should parse");
+        assert_eq!(combo.equivalent_descriptors()?.len(), 3);
+
+        // Not a special descriptor
+        assert!(SpecialDescriptor::parse(&format!("wpkh({XPUB})"))?.is_none());
+
+        // Checksums are verified
+        let checksum = desc_checksum("raw(6a0568656c6c6f)")?;
+        assert!(SpecialDescriptor::parse(&format!("raw(6a0568656c6c6f)#{checksum}"))?.is_some());
+        assert!(SpecialDescriptor::parse("raw(6a0568656c6c6f)#qqqqqqqq").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_uncompressed_combo() -> Result<()> {
+        let combo = SpecialDescriptor::parse("combo(04a34b99f22c790c4e36b2b3c2c35a36db06226e41c692fc82b8b56ac1c540c5bd5b8dec5235a0fa8722476c7709c02559e3aa73aa03918ba2d492eea75abea235)")?
+            .expect("combo() should parse");
+        let descriptors = combo.equivalent_descriptors()?;
+        assert_eq!(descriptors.len(), 1);
+        assert!(matches!(descriptors[0], Descriptor::Pkh(_)));
+
+        Ok(())
+    }
+}
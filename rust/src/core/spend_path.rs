@@ -1,26 +1,30 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::sync::Arc;
 
 use anyhow::{Ok, Result};
-use bdk_wallet::bitcoin::psbt::Input;
-use bdk_wallet::bitcoin::transaction::Version;
+use bdk_wallet::bitcoin::policy::MAX_STANDARD_TX_WEIGHT;
+use bdk_wallet::bitcoin::taproot::LeafVersion;
 use bdk_wallet::bitcoin::{
-    ecdsa, taproot, Amount, BlockHash, FeeRate, Network, OutPoint, Psbt, PublicKey, ScriptBuf,
-    TapLeafHash, Transaction, TxOut, Txid,
+    absolute, relative, Network, NetworkKind, PublicKey, Sequence, TapLeafHash, VarInt,
 };
-use bdk_wallet::chain::{BlockId, CheckPoint, ConfirmationBlockTime};
-use bdk_wallet::descriptor::policy::PkOrF;
-use bdk_wallet::descriptor::{policy::SatisfiableItem, Policy};
+use bdk_wallet::descriptor::policy::{BuildSatisfaction, PkOrF};
+use bdk_wallet::descriptor::{policy::SatisfiableItem, ExtractPolicy, Policy};
 use bdk_wallet::keys::DescriptorPublicKey;
-use bdk_wallet::miniscript::descriptor::{Pkh, Sh, Tr, Wpkh, Wsh};
-use bdk_wallet::miniscript::Descriptor;
-use bdk_wallet::rusqlite::Connection;
-#[expect(deprecated)]
-use bdk_wallet::SignOptions;
-use bdk_wallet::{KeychainKind, PersistedWallet, Update, Wallet};
-use secp256k1::hashes::{sha256, Hash, HashEngine};
+use bdk_wallet::miniscript::descriptor::{
+    DefiniteDescriptorKey, DescriptorType, Pkh, Sh, SinglePubKey, Tr, Wpkh, Wsh,
+};
+use bdk_wallet::miniscript::miniscript::satisfy::Placeholder;
+use bdk_wallet::miniscript::plan::{
+    AssetProvider, Assets, CanSign, Plan, TaprootAvailableLeaves, TaprootCanSign,
+};
+use bdk_wallet::miniscript::{hash256, AnalysisError, Descriptor, ForEachKey};
+use bdk_wallet::signer::SignersContainer;
+use bdk_wallet::{KeychainKind, Wallet};
+use secp256k1::hashes::{hash160, ripemd160, sha256, Hash, HashEngine};
+use secp256k1::Secp256k1;
 
 use crate::core::error::WalletError;
+use crate::core::signer_group::{combinations, SignerGroup};
+use crate::core::wallet::WalletType;
 
 /// Calculate a deterministic ID based on spend path properties
 /// This ensures the same spend path always gets the same ID across re-analysis
@@ -52,22 +56,88 @@ pub fn calculate_spend_path_id(
     u32::from_le_bytes([hash_bytes[0], hash_bytes[1], hash_bytes[2], hash_bytes[3]])
 }
 
+/// Mix extra spend conditions into a spend path ID
+///
+/// Paths without extra conditions keep the ID from `calculate_spend_path_id`,
+/// so IDs stored by older versions remain valid.
+pub(crate) fn extend_spend_path_id<'a>(
+    id: u32,
+    conditions: impl IntoIterator<Item = &'a [u8]>,
+) -> u32 {
+    let mut engine = sha256::Hash::engine();
+    engine.input(&id.to_le_bytes());
+    for condition in conditions {
+        engine.input(condition);
+    }
+
+    let hash = sha256::Hash::from_engine(engine);
+    let hash_bytes = hash.as_byte_array();
+    u32::from_le_bytes([hash_bytes[0], hash_bytes[1], hash_bytes[2], hash_bytes[3]])
+}
+
+/// Hash function used by a hashlock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HashType {
+    Sha256,
+    Hash256,
+    Ripemd160,
+    Hash160,
+}
+
+/// Hash preimage a spend path has to reveal
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HashLock {
+    pub hash_type: HashType,
+    /// Digest hex, as written in the descriptor
+    pub digest: String,
+}
+
+impl HashLock {
+    /// Size of the preimage pushed to the witness (miniscript only accepts 32 bytes)
+    pub const PREIMAGE_LEN: usize = 32;
+
+    fn new(hash_type: HashType, digest: String) -> Self {
+        Self { hash_type, digest }
+    }
+
+    fn id_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.hash_type as u8];
+        bytes.extend_from_slice(self.digest.as_bytes());
+        bytes
+    }
+}
+
+/// Minimal combination of signers for a spend path and its input weight
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignerSet {
+    pub mfps: Vec<String>,
+    /// Input weight (WU) when this combination signs
+    pub wu_in: u32,
+}
+
 #[derive(Debug, Clone, Default)]
 struct SpendPathBuilder {
     policy_path: BTreeMap<String, Vec<usize>>,
 
-    threshold_setted: bool,
     threshold: Option<usize>,
     mfps: BTreeSet<String>,
-    rel_timelock: u32,
-    abs_timelock: u32,
+    signer_groups: Vec<SignerGroup>,
+    rel_timelocks: BTreeSet<u32>,
+    abs_timelocks: BTreeSet<u32>,
+    hashlocks: BTreeSet<HashLock>,
 
     wu_base: Option<u32>,
+    signer_sets: Vec<SignerSet>,
+    signer_sets_truncated: bool,
     wu_in: Option<u32>,
+    wu_in_min: Option<u32>,
+    wu_in_max: Option<u32>,
     wu_out: Option<u32>,
 
     addr_type: Option<String>,
     is_tr_script: bool,
+    /// Position of the taproot leaf of a script path, in `Tr::iter_scripts` order
+    tr_leaf: Option<usize>,
     tr_depth: usize,
 }
 
@@ -75,7 +145,6 @@ impl SpendPathBuilder {
     fn new() -> Self {
         Self {
             is_tr_script: false,
-            threshold_setted: false,
             ..Default::default()
         }
     }
@@ -90,47 +159,91 @@ impl SpendPathBuilder {
         self
     }
 
-    fn get_threshold(&self) -> Result<usize> {
-        self.threshold.ok_or(WalletError::MissingThreshold.into())
-    }
-
     fn threshold(&mut self, threshold: usize) -> Result<&mut Self> {
         if self.threshold.is_some() {
             return Err(WalletError::UnsupportedDescriptor.into());
         }
 
-        self.threshold_setted = true;
         self.threshold = Some(threshold);
         Ok(self)
     }
 
-    fn add_threshold(&mut self, threshold: usize) -> Result<&mut Self> {
-        if self.threshold_setted {
-            return Err(WalletError::UnsupportedDescriptor.into());
-        }
+    fn add_mfp(&mut self, mfp: String) -> &mut Self {
+        self.mfps.insert(mfp);
+        self
+    }
 
-        self.threshold = match self.threshold {
-            Some(t) => Some(t + threshold),
-            None => Some(threshold),
-        };
+    /// Set the signers of the path from a signer group
+    ///
+    /// Nested groups are kept as signer groups; `mfps` gets every signer.
+    fn signers(&mut self, signers: SignerGroup) -> Result<&mut Self> {
+        self.threshold(signers.threshold)?;
+        self.mfps.extend(signers.all_mfps());
+        self.signer_groups = signers.groups;
         Ok(self)
     }
 
-    fn add_mfp(&mut self, mfp: String) -> &mut Self {
-        self.mfps.insert(mfp);
+    /// Signer group at the root of the path
+    fn root_signer_group(&self) -> Result<SignerGroup> {
+        let nested: BTreeSet<String> = self
+            .signer_groups
+            .iter()
+            .flat_map(SignerGroup::all_mfps)
+            .collect();
+        let direct = self.mfps.difference(&nested).cloned().collect();
+
+        Ok(SignerGroup::new(
+            self.get_threshold()?,
+            direct,
+            self.signer_groups.clone(),
+        ))
+    }
+
+    fn add_hashlock(&mut self, hashlock: HashLock) -> &mut Self {
+        self.hashlocks.insert(hashlock);
         self
     }
 
-    fn rel_timelock(&mut self, rel_timelock: u32) -> &mut Self {
-        self.rel_timelock = rel_timelock;
+    fn add_rel_timelock(&mut self, rel_timelock: u32) -> &mut Self {
+        self.rel_timelocks.insert(rel_timelock);
         self
     }
 
-    fn abs_timelock(&mut self, abs_timelock: u32) -> &mut Self {
-        self.abs_timelock = abs_timelock;
+    fn add_abs_timelock(&mut self, abs_timelock: u32) -> &mut Self {
+        self.abs_timelocks.insert(abs_timelock);
         self
     }
 
+    /// Relative timelock that satisfies every `older()` of the path
+    fn rel_timelock(&self) -> u32 {
+        self.rel_timelocks.last().copied().unwrap_or(0)
+    }
+
+    /// Absolute timelock that satisfies every `after()` of the path
+    fn abs_timelock(&self) -> u32 {
+        self.abs_timelocks.last().copied().unwrap_or(0)
+    }
+
+    /// Whether the path mixes height and time based locks of the same kind
+    ///
+    /// A transaction has a single nLockTime and each input a single nSequence,
+    /// so such a path can never be satisfied.
+    fn has_timelock_conflict(&self) -> bool {
+        let abs_times = self
+            .abs_timelocks
+            .iter()
+            .filter(|&&value| absolute::LockTime::from_consensus(value).is_block_time())
+            .count();
+        let rel_times = self
+            .rel_timelocks
+            .iter()
+            .filter(|&&value| Sequence::from_consensus(value).is_time_locked())
+            .count();
+
+        (abs_times != 0 && abs_times != self.abs_timelocks.len())
+            || (rel_times != 0 && rel_times != self.rel_timelocks.len())
+    }
+
     fn wu_base(&mut self, wu_base: u32) -> &mut Self {
         self.wu_base = Some(wu_base);
         self
@@ -146,6 +259,15 @@ impl SpendPathBuilder {
         self
     }
 
+    /// Keep the `MAX_SIGNER_SETS` cheapest of the enumerated sets
+    fn signer_sets(&mut self, mut signer_sets: Vec<SignerSet>, truncated: bool) -> &mut Self {
+        signer_sets.sort_by(|a, b| a.wu_in.cmp(&b.wu_in).then_with(|| a.mfps.cmp(&b.mfps)));
+        self.signer_sets_truncated = truncated || signer_sets.len() > MAX_SIGNER_SETS;
+        signer_sets.truncate(MAX_SIGNER_SETS);
+        self.signer_sets = signer_sets;
+        self
+    }
+
     fn addr_type(&mut self, addr_type: String) -> &mut Self {
         self.addr_type = Some(addr_type);
         self
@@ -154,30 +276,90 @@ impl SpendPathBuilder {
     /// Calculate a deterministic ID based on spend path properties
     /// This ensures the same spend path always gets the same ID across re-analysis
     fn calculate_id(&self) -> Result<u32> {
-        let threshold = self.threshold.ok_or(WalletError::MissingThreshold)?;
+        let threshold = self.get_threshold()?;
         let mfps_vec: Vec<String> = self.mfps.iter().cloned().collect();
-        Ok(calculate_spend_path_id(
+        let id = calculate_spend_path_id(
             threshold,
             &mfps_vec,
-            self.rel_timelock,
-            self.abs_timelock,
+            self.rel_timelock(),
+            self.abs_timelock(),
+        );
+
+        // Extra timelocks only change the ID when a path has more than one of a kind
+        let timelocks = |prefix: u8, values: &BTreeSet<u32>| -> Option<Vec<u8>> {
+            (values.len() > 1).then(|| {
+                let mut bytes = vec![prefix];
+                values
+                    .iter()
+                    .for_each(|value| bytes.extend_from_slice(&value.to_le_bytes()));
+                bytes
+            })
+        };
+
+        let conditions: Vec<Vec<u8>> = self
+            .signer_groups
+            .iter()
+            .map(SignerGroup::id_bytes)
+            .chain(self.hashlocks.iter().map(HashLock::id_bytes))
+            .chain(timelocks(b'o', &self.rel_timelocks))
+            .chain(timelocks(b'a', &self.abs_timelocks))
+            .collect();
+        if conditions.is_empty() {
+            return Ok(id);
+        }
+        Ok(extend_spend_path_id(
+            id,
+            conditions.iter().map(Vec::as_slice),
         ))
     }
 
+    /// Whether the path has conditions other than signatures
+    fn has_conditions(&self) -> bool {
+        !self.hashlocks.is_empty()
+            || !self.rel_timelocks.is_empty()
+            || !self.abs_timelocks.is_empty()
+    }
+
+    /// Threshold of signatures required
+    ///
+    /// A path without signers (e.g. only preimages or timelocks) has a threshold of 0.
+    fn get_threshold(&self) -> Result<usize> {
+        match self.threshold {
+            Some(threshold) => Ok(threshold),
+            None if self.mfps.is_empty() && self.has_conditions() => Ok(0),
+            None => Err(WalletError::MissingThreshold.into()),
+        }
+    }
+
     fn build(self, id: u32) -> Result<SpendPath> {
+        let threshold = self.get_threshold()?;
+        if self.mfps.is_empty() && !self.has_conditions() {
+            Err(WalletError::MissingFingerprint)?;
+        }
+        let rel_timelock = self.rel_timelock();
+        let abs_timelock = self.abs_timelock();
+        let timelock_conflict = self.has_timelock_conflict();
+
         Ok(SpendPath {
             id,
             addr_type: self.addr_type.ok_or(WalletError::UnsupportedDescriptor)?,
             policy_path: self.policy_path,
-            threshold: self.threshold.ok_or(WalletError::MissingThreshold)?,
-            mfps: (!self.mfps.is_empty())
-                .then(|| self.mfps.into_iter().collect())
-                .ok_or(WalletError::MissingFingerprint)?,
-            rel_timelock: self.rel_timelock,
-            abs_timelock: self.abs_timelock,
+            threshold,
+            mfps: self.mfps.into_iter().collect(),
+            signer_groups: self.signer_groups,
+            rel_timelock,
+            abs_timelock,
+            timelock_conflict,
+            rel_timelocks: self.rel_timelocks.into_iter().collect(),
+            abs_timelocks: self.abs_timelocks.into_iter().collect(),
+            hashlocks: self.hashlocks.into_iter().collect(),
             wu_base: self.wu_base.ok_or(WalletError::MissingSpendWeight)?,
             wu_in: self.wu_in.ok_or(WalletError::MissingSpendWeight)?,
+            wu_in_min: self.wu_in_min.ok_or(WalletError::MissingSpendWeight)?,
+            wu_in_max: self.wu_in_max.ok_or(WalletError::MissingSpendWeight)?,
             wu_out: self.wu_out.ok_or(WalletError::MissingSpendWeight)?,
+            signer_sets: self.signer_sets,
+            signer_sets_truncated: self.signer_sets_truncated,
             tr_depth: self.tr_depth,
         })
     }
@@ -191,106 +373,255 @@ impl SpendPathBuilder {
             .collect()
     }
 
-    fn from_policies(policy: &Policy) -> Result<Vec<SpendPathBuilder>> {
-        Self::_from_policies(policy, false)
+    fn from_policies(
+        policy: &Policy,
+        raw_keys: &RawKeyFingerprints,
+    ) -> Result<Vec<SpendPathBuilder>> {
+        Self::_from_policies(policy, raw_keys, false)
     }
 
-    fn from_tr_policies(policy: &Policy) -> Result<Vec<SpendPathBuilder>> {
-        Self::_from_policies(policy, true)
+    fn from_tr_policies(
+        policy: &Policy,
+        raw_keys: &RawKeyFingerprints,
+    ) -> Result<Vec<SpendPathBuilder>> {
+        Self::_from_policies(policy, raw_keys, true)
     }
 
-    fn _from_policies(policy: &Policy, is_taproot: bool) -> Result<Vec<SpendPathBuilder>> {
-        fn policy_finder(
-            policy: &Policy,
-            policy_path: &mut BTreeMap<String, Vec<usize>>,
-            sps: &mut Vec<SpendPathBuilder>,
-            force_path: bool,
-        ) -> Result<()> {
-            if force_path || policy.requires_path() {
-                match &policy.item {
-                    SatisfiableItem::Thresh {
-                        items,
-                        threshold: _,
-                    } => {
-                        for (i, item) in items.iter().enumerate() {
-                            policy_path.entry(policy.id.clone()).or_default().push(i);
-
-                            policy_finder(item, policy_path, sps, false)?;
-
-                            if let Some(vec) = policy_path.get_mut(&policy.id) {
-                                vec.pop();
-                                if vec.is_empty() {
-                                    policy_path.remove(&policy.id);
-                                }
+    fn _from_policies(
+        policy: &Policy,
+        raw_keys: &RawKeyFingerprints,
+        is_taproot: bool,
+    ) -> Result<Vec<SpendPathBuilder>> {
+        /// Policy path selecting a choice, and the items it requires together
+        type Alternative<'a> = (BTreeMap<String, Vec<usize>>, Vec<&'a Policy>);
+
+        /// Expand every choice of the policy, e.g. thresh(2,A,B,older(n))
+        /// into A+B, A+older(n) and B+older(n), up to `MAX_SPEND_PATHS` of them
+        fn policy_finder(policy: &Policy, force_path: bool) -> Result<Vec<Alternative<'_>>> {
+            if !(force_path || policy.requires_path()) {
+                return Ok(vec![(BTreeMap::new(), vec![policy])]);
+            }
+
+            match &policy.item {
+                SatisfiableItem::Thresh { items, threshold } => {
+                    let mut alternatives = Vec::new();
+                    for selected in combinations(items.len(), *threshold) {
+                        let mut partial: Vec<Alternative> = vec![(
+                            BTreeMap::from([(policy.id.clone(), selected.clone())]),
+                            vec![],
+                        )];
+
+                        // Stop as soon as the alternatives go over the cap,
+                        // before the product of the choices gets materialized
+                        let room = MAX_SPEND_PATHS - alternatives.len();
+                        for &i in &selected {
+                            let choices = policy_finder(&items[i], false)?;
+                            partial = partial
+                                .iter()
+                                .flat_map(|(path, leaves)| {
+                                    choices.iter().map(move |(choice_path, choice_leaves)| {
+                                        let mut path = path.clone();
+                                        path.extend(choice_path.clone());
+                                        let mut leaves = leaves.clone();
+                                        leaves.extend(choice_leaves);
+                                        (path, leaves)
+                                    })
+                                })
+                                .take(room + 1)
+                                .collect();
+                            if partial.len() > room {
+                                Err(WalletError::TooManySpendPaths)?;
                             }
                         }
+                        alternatives.extend(partial);
                     }
-                    SatisfiableItem::SchnorrSignature(_) | SatisfiableItem::EcdsaSignature(_) => {
-                        policy_finder(policy, policy_path, sps, false)?;
-                    }
-                    _ => {
-                        Err(WalletError::UnsupportedDescriptor)?;
-                    }
-                };
-            } else {
-                let mut sp = SpendPathBuilder::from_policy(policy)?;
-                sp.policy_path(policy_path.clone());
-                sps.push(sp);
+                    Ok(alternatives)
+                }
+                SatisfiableItem::SchnorrSignature(_) | SatisfiableItem::EcdsaSignature(_) => {
+                    Ok(vec![(BTreeMap::new(), vec![policy])])
+                }
+                _ => Err(WalletError::UnsupportedDescriptor.into()),
             }
-            Ok(())
         }
 
-        let mut sps: Vec<SpendPathBuilder> = Vec::new();
-        let mut policy_path = BTreeMap::new();
-        policy_finder(policy, &mut policy_path, &mut sps, is_taproot)?;
-        Ok(sps)
+        policy_finder(policy, is_taproot)?
+            .into_iter()
+            .map(|(policy_path, leaves)| {
+                let mut sp = SpendPathBuilder::from_policy(&leaves, raw_keys)?;
+                sp.policy_path(policy_path);
+                Ok(sp)
+            })
+            .collect()
     }
 
-    fn from_policy(policy: &Policy) -> Result<SpendPathBuilder> {
-        fn policy_parser(policy: &Policy, sp: &mut SpendPathBuilder) -> Result<()> {
-            match &policy.item {
+    /// Build a spend path that has to satisfy every policy in `policies`
+    fn from_policy(
+        policies: &[&Policy],
+        raw_keys: &RawKeyFingerprints,
+    ) -> Result<SpendPathBuilder> {
+        /// Signers required by a policy node, if any
+        enum Signers {
+            Key(String),
+            Group(SignerGroup),
+        }
+
+        /// Signers of a k-of-n over keys and groups
+        fn combine(
+            threshold: usize,
+            mut mfps: Vec<String>,
+            mut groups: Vec<SignerGroup>,
+        ) -> Option<Signers> {
+            match (mfps.len(), groups.len()) {
+                (0, 0) => None,
+                (1, 0) if threshold == 1 => mfps.pop().map(Signers::Key),
+                (0, 1) if threshold == 1 => groups.pop().map(Signers::Group),
+                _ => Some(Signers::Group(
+                    SignerGroup::new(threshold, mfps, groups).flatten(),
+                )),
+            }
+        }
+
+        fn policy_parser(
+            policy: &Policy,
+            sp: &mut SpendPathBuilder,
+            raw_keys: &RawKeyFingerprints,
+        ) -> Result<Option<Signers>> {
+            let signers = match &policy.item {
                 SatisfiableItem::Thresh { items, threshold } => {
                     if policy.requires_path() {
                         Err(WalletError::UnsupportedDescriptor)?;
                     }
-                    if *threshold != items.len() {
-                        sp.threshold(*threshold)?;
-                    }
+
+                    let mut mfps = Vec::new();
+                    let mut groups = Vec::new();
                     for item in items {
-                        policy_parser(item, sp)?;
+                        match policy_parser(item, sp, raw_keys)? {
+                            Some(Signers::Key(mfp)) => mfps.push(mfp),
+                            Some(Signers::Group(group)) => groups.push(group),
+                            None => {}
+                        }
                     }
+
+                    // n-of-n only counts the items that carry signers
+                    let threshold = if *threshold == items.len() {
+                        mfps.len() + groups.len()
+                    } else {
+                        *threshold
+                    };
+
+                    combine(threshold, mfps, groups)
                 }
                 SatisfiableItem::Multisig { keys, threshold } => {
-                    sp.threshold(*threshold)?;
-                    for key in keys {
-                        sp.add_mfp(fingerprint_of(key)?);
-                    }
+                    let mfps = keys
+                        .iter()
+                        .map(|key| fingerprint_of(key, raw_keys))
+                        .collect::<Result<_>>()?;
+                    Some(Signers::Group(SignerGroup::new(*threshold, mfps, vec![])))
                 }
                 SatisfiableItem::SchnorrSignature(key) | SatisfiableItem::EcdsaSignature(key) => {
-                    if !sp.threshold_setted {
-                        sp.add_threshold(1)?;
-                    }
-                    sp.add_mfp(fingerprint_of(key)?);
+                    Some(Signers::Key(fingerprint_of(key, raw_keys)?))
                 }
                 SatisfiableItem::RelativeTimelock { value } => {
-                    sp.rel_timelock(value.to_consensus_u32());
+                    sp.add_rel_timelock(value.to_consensus_u32());
+                    None
                 }
                 SatisfiableItem::AbsoluteTimelock { value } => {
-                    sp.abs_timelock(value.to_consensus_u32());
+                    sp.add_abs_timelock(value.to_consensus_u32());
+                    None
                 }
-                _ => {
-                    Err(WalletError::UnsupportedDescriptor)?;
+                SatisfiableItem::Sha256Preimage { hash } => {
+                    sp.add_hashlock(HashLock::new(HashType::Sha256, hash.to_string()));
+                    None
+                }
+                SatisfiableItem::Hash256Preimage { hash } => {
+                    sp.add_hashlock(HashLock::new(HashType::Hash256, hash.to_string()));
+                    None
+                }
+                SatisfiableItem::Ripemd160Preimage { hash } => {
+                    sp.add_hashlock(HashLock::new(HashType::Ripemd160, hash.to_string()));
+                    None
+                }
+                SatisfiableItem::Hash160Preimage { hash } => {
+                    sp.add_hashlock(HashLock::new(HashType::Hash160, hash.to_string()));
+                    None
                 }
             };
-            Ok(())
+            Ok(signers)
         }
 
         let mut spb = Self::new();
-        policy_parser(policy, &mut spb)?;
+        let mut mfps = Vec::new();
+        let mut groups = Vec::new();
+        for policy in policies {
+            match policy_parser(policy, &mut spb, raw_keys)? {
+                Some(Signers::Key(mfp)) => mfps.push(mfp),
+                Some(Signers::Group(group)) => groups.push(group),
+                None => {}
+            }
+        }
+
+        match combine(mfps.len() + groups.len(), mfps, groups) {
+            Some(Signers::Key(mfp)) => {
+                spb.threshold(1)?.add_mfp(mfp);
+            }
+            Some(Signers::Group(group)) => {
+                spb.signers(group)?;
+            }
+            None => {}
+        }
         Ok(spb)
     }
 }
 
+/// How much is known about the ways to spend a descriptor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Spendability {
+    /// Every spend path is known
+    Known,
+    /// Only the output script is known (e.g. `addr()`), so are its spend paths
+    Unknown,
+    /// The output can never be spent (e.g. OP_RETURN)
+    Unspendable,
+}
+
+/// Size and fee of a transaction at one fee rate
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeEstimate {
+    /// sat/vB
+    pub fee_rate: f64,
+    /// Virtual size, rounded up to the next vbyte
+    pub vbytes: u64,
+    /// Absolute fee in sats, rounded up
+    pub fee_sat: u64,
+    /// Virtual size with the largest signatures (`wu_in_max`)
+    pub vbytes_max: u64,
+    /// Fee with the largest signatures, safe to budget for
+    pub fee_sat_max: u64,
+}
+
+/// Cost of sweeping UTXOs into the wallet through one spend path
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsolidationPlan {
+    /// Most inputs a standard transaction can spend, even with the largest signatures
+    pub max_inputs_per_tx: usize,
+    /// Transactions needed to consolidate every UTXO
+    pub transactions: usize,
+    /// Total size and fee of the transactions, one per fee rate
+    pub fees: Vec<FeeEstimate>,
+}
+
+/// Size and fee of a spend averaged over the spend paths by their likelihood
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpectedCost {
+    /// sat/vB
+    pub fee_rate: f64,
+    pub vbytes: f64,
+    pub fee_sat: f64,
+    /// Same with the largest signatures
+    pub vbytes_max: f64,
+    pub fee_sat_max: f64,
+}
+
 #[derive(Debug)]
 pub struct SpendPath {
     // For TxBuilder::policy_path
@@ -299,13 +630,36 @@ pub struct SpendPath {
     pub id: u32,
 
     pub threshold: usize,
+    /// Every signer of the path, including those of nested groups
     pub mfps: Vec<String>,
+    /// Nested signer groups, each one counting as a single item towards `threshold`
+    pub signer_groups: Vec<SignerGroup>,
+    /// Strictest relative timelock, which satisfies every one in `rel_timelocks`
     pub rel_timelock: u32,
+    /// Strictest absolute timelock, which satisfies every one in `abs_timelocks`
     pub abs_timelock: u32,
+    /// Every `older()` value the path requires
+    pub rel_timelocks: Vec<u32>,
+    /// Every `after()` value the path requires
+    pub abs_timelocks: Vec<u32>,
+    /// Height and time based locks mixed, so the path can't be satisfied
+    pub timelock_conflict: bool,
+    pub hashlocks: Vec<HashLock>,
 
     pub wu_base: u32,
+    /// Input weight with 72 byte ECDSA signatures and 64 byte (`SIGHASH_DEFAULT`)
+    /// Schnorr signatures
     pub wu_in: u32,
+    /// Input weight when ECDSA signers grind for a low R (71 byte signatures)
+    pub wu_in_min: u32,
+    /// Input weight with high-R ECDSA signatures (72 bytes) and Schnorr
+    /// signatures with an explicit sighash type (65 bytes)
+    pub wu_in_max: u32,
     pub wu_out: u32,
+    /// Minimal signer combinations, cheapest first
+    pub signer_sets: Vec<SignerSet>,
+    /// More combinations exist than `signer_sets` lists
+    pub signer_sets_truncated: bool,
 
     pub addr_type: String,
     pub tr_depth: usize,
@@ -320,46 +674,250 @@ impl SpendPath {
         self.wu_base + (inputs as u32) * self.wu_in + (outputs as u32) * self.wu_out
     }
 
-    /// Extract spend paths from descriptor and network without requiring an existing wallet
+    /// Weight of a transaction spending `inputs` through this path to
+    /// `destinations`, plus a change output back to the wallet when `change`
+    ///
+    /// Destination outputs are weighted by their script type, change by the
+    /// wallet's own one (`wu_out`).
+    pub fn estimate_tx_wu_to(
+        &self,
+        inputs: usize,
+        destinations: &[WalletType],
+        change: bool,
+    ) -> Result<u64> {
+        let outputs = destinations.len() + usize::from(change);
+        if inputs == 0 || outputs == 0 {
+            Err(WalletError::EmptyTransaction)?;
+        }
+
+        // `wu_base` counts 1 byte for each of the input and output counts
+        let counts_len = VarInt(inputs as u64).size() + VarInt(outputs as u64).size();
+        let mut wu = u64::from(self.wu_base)
+            + (counts_len as u64 - 2) * 4
+            + inputs as u64 * u64::from(self.wu_in);
+        for destination in destinations {
+            wu += u64::from(WeightCalc::destination_wu(destination)?);
+        }
+        if change {
+            wu += u64::from(self.wu_out);
+        }
+
+        Ok(wu)
+    }
+
+    /// Size and fee of a transaction spending `inputs` through this path to
+    /// `destinations` (plus change when `change`), at each of `fee_rates` (sat/vB)
+    ///
+    /// The virtual size is rounded up as the network does, and so are fees.
+    /// Fee rates are taken with sat/kvB precision, like Bitcoin Core.
+    pub fn estimate_fees(
+        &self,
+        inputs: usize,
+        destinations: &[WalletType],
+        change: bool,
+        fee_rates: &[f64],
+    ) -> Result<Vec<FeeEstimate>> {
+        let wu = self.estimate_tx_wu_to(inputs, destinations, change)?;
+        let vbytes = wu.div_ceil(4);
+        let vbytes_max = (wu + inputs as u64 * u64::from(self.wu_in_max - self.wu_in)).div_ceil(4);
+        let fee = |vbytes: u64, sat_per_kvb: u64| vbytes.saturating_mul(sat_per_kvb).div_ceil(1000);
+
+        fee_rates
+            .iter()
+            .map(|&fee_rate| {
+                let sat_per_kvb = Self::sat_per_kvb(fee_rate)?;
+                Ok(FeeEstimate {
+                    fee_rate,
+                    vbytes,
+                    fee_sat: fee(vbytes, sat_per_kvb),
+                    vbytes_max,
+                    fee_sat_max: fee(vbytes_max, sat_per_kvb),
+                })
+            })
+            .collect()
+    }
+
+    /// Cost of consolidating `utxos` UTXOs through this path into one
+    /// output per transaction, at each of `fee_rates` (sat/vB)
+    ///
+    /// Transactions are filled up to the standard weight limit, which is
+    /// checked with the largest signatures (`wu_in_max`).
+    pub fn plan_consolidation(&self, utxos: usize, fee_rates: &[f64]) -> Result<ConsolidationPlan> {
+        if utxos == 0 {
+            Err(WalletError::EmptyTransaction)?;
+        }
+
+        let max_inputs_per_tx = self.max_inputs_per_tx()?;
+        let transactions = utxos.div_ceil(max_inputs_per_tx);
+
+        // Full transactions, then the rest of the UTXOs in the last one
+        let full = self.estimate_fees(max_inputs_per_tx, &[], true, fee_rates)?;
+        let last_inputs = utxos - (transactions - 1) * max_inputs_per_tx;
+        let last = self.estimate_fees(last_inputs, &[], true, fee_rates)?;
+
+        let full_txs = (transactions - 1) as u64;
+        let fees = full
+            .into_iter()
+            .zip(last)
+            .map(|(full, last)| FeeEstimate {
+                fee_rate: last.fee_rate,
+                vbytes: full_txs * full.vbytes + last.vbytes,
+                fee_sat: full_txs * full.fee_sat + last.fee_sat,
+                vbytes_max: full_txs * full.vbytes_max + last.vbytes_max,
+                fee_sat_max: full_txs * full.fee_sat_max + last.fee_sat_max,
+            })
+            .collect();
+
+        Ok(ConsolidationPlan {
+            max_inputs_per_tx,
+            transactions,
+            fees,
+        })
+    }
+
+    /// Most inputs of a standard consolidation through this path
+    fn max_inputs_per_tx(&self) -> Result<usize> {
+        let limit = u64::from(MAX_STANDARD_TX_WEIGHT);
+        let max_wu = |inputs: usize| -> Result<u64> {
+            Ok(self.estimate_tx_wu_to(inputs, &[], true)?
+                + inputs as u64 * u64::from(self.wu_in_max - self.wu_in))
+        };
+
+        // Upper bound, before the input count grows past its 1 byte size
+        let fixed = max_wu(1)? - u64::from(self.wu_in_max);
+        let mut inputs = (limit.saturating_sub(fixed) / u64::from(self.wu_in_max)) as usize;
+        while inputs > 0 && max_wu(inputs)? > limit {
+            inputs -= 1;
+        }
+
+        if inputs == 0 {
+            Err(WalletError::TransactionTooHeavy)?;
+        }
+        Ok(inputs)
+    }
+
+    /// Expected size and fee of a spend, with each spend path used as often
+    /// as its likelihood says, at each of `fee_rates` (sat/vB)
+    ///
+    /// Each path is costed as in `estimate_fees`. Likelihoods are in any unit,
+    /// must be non-negative and can't all be zero.
+    pub fn expected_cost(
+        likelihoods: &[(&SpendPath, f64)],
+        inputs: usize,
+        destinations: &[WalletType],
+        change: bool,
+        fee_rates: &[f64],
+    ) -> Result<Vec<ExpectedCost>> {
+        let total: f64 = likelihoods.iter().map(|(_, likelihood)| likelihood).sum();
+        if likelihoods
+            .iter()
+            .any(|(_, likelihood)| !likelihood.is_finite() || *likelihood < 0.0)
+            || !total.is_finite()
+            || total <= 0.0
+        {
+            Err(WalletError::InvalidLikelihood)?;
+        }
+
+        let mut costs: Vec<ExpectedCost> = fee_rates
+            .iter()
+            .map(|&fee_rate| ExpectedCost {
+                fee_rate,
+                vbytes: 0.0,
+                fee_sat: 0.0,
+                vbytes_max: 0.0,
+                fee_sat_max: 0.0,
+            })
+            .collect();
+        for (sp, likelihood) in likelihoods {
+            let share = likelihood / total;
+            let estimates = sp.estimate_fees(inputs, destinations, change, fee_rates)?;
+            for (cost, estimate) in costs.iter_mut().zip(estimates) {
+                cost.vbytes += share * estimate.vbytes as f64;
+                cost.fee_sat += share * estimate.fee_sat as f64;
+                cost.vbytes_max += share * estimate.vbytes_max as f64;
+                cost.fee_sat_max += share * estimate.fee_sat_max as f64;
+            }
+        }
+
+        Ok(costs)
+    }
+
+    /// Economic dust of this path at `fee_rate` (sat/vB), in sats
     ///
-    /// This is the new preferred method that avoids keeping a persistent wallet.
-    /// Creates a temporary wallet ONLY for weight calculation, which legitimately
-    /// requires transaction building via `build_tx()`.
+    /// Spending a UTXO through this path costs its input weight in fees, so
+    /// UTXOs worth this much or less give nothing back when spent.
+    pub fn economic_dust(&self, fee_rate: f64) -> Result<u64> {
+        let sat_per_kvb = Self::sat_per_kvb(fee_rate)?;
+        Ok(u64::from(self.wu_in)
+            .saturating_mul(sat_per_kvb)
+            .div_ceil(4000))
+    }
+
+    /// Fee rate in sat/kvB, the precision Bitcoin Core works with
+    fn sat_per_kvb(fee_rate: f64) -> Result<u64> {
+        if !fee_rate.is_finite() || fee_rate < 0.0 {
+            Err(WalletError::InvalidFeeRate)?;
+        }
+        Ok((fee_rate * 1000.0).round() as u64)
+    }
+
+    /// Extract spend paths from descriptor and network without requiring an existing wallet
     ///
-    /// This method still creates ONE temporary wallet, but that's significantly
-    /// better than the old approach which could create 5-6 wallets for a single analysis.
+    /// Weights are computed from the miniscript satisfaction of each path,
+    /// without building any transaction.
     pub fn extract_from_descriptor(
         descriptor: &Descriptor<DescriptorPublicKey>,
         network: Network,
     ) -> Result<Vec<SpendPath>> {
-        // Create minimal temporary wallet for weight calculation
-        // This is unavoidable because WeightCalc uses build_tx()
-        let descriptor_str = descriptor.to_string();
-        let temp_wallet = Self::create_weight_calc_wallet(&descriptor_str, network)?;
+        let mut spbs = Self::builders_from_descriptor(descriptor, network)?;
+        WeightCalc::calc_tx_weight(descriptor, &mut spbs)?;
+        SpendPathBuilder::build_many(spbs)
+    }
+
+    /// Spend path builders of a descriptor, without weights
+    ///
+    /// Policies are extracted from the descriptor rather than a wallet, which
+    /// would refuse paths mixing height and time based locks. Those paths are
+    /// kept and flagged, see `SpendPath::timelock_conflict`.
+    fn builders_from_descriptor(
+        descriptor: &Descriptor<DescriptorPublicKey>,
+        network: Network,
+    ) -> Result<Vec<SpendPathBuilder>> {
+        let external = descriptor.clone().into_single_descriptors()?.remove(0);
+        match external.sanity_check() {
+            Err(bdk_wallet::miniscript::Error::AnalysisError(
+                AnalysisError::HeightTimelockCombination,
+            )) => {}
+            result => result?,
+        }
+        let on_network = external.for_each_key(|key| match key {
+            DescriptorPublicKey::Single(_) => true,
+            DescriptorPublicKey::XPub(xkey) => xkey.xkey.network == NetworkKind::from(network),
+            DescriptorPublicKey::MultiXPub(xkey) => xkey.xkey.network == NetworkKind::from(network),
+        });
+        if !on_network {
+            Err(WalletError::NetworkDetectionFailed)?;
+        }
+
+        let policy = external
+            .extract_policy(
+                &SignersContainer::new(),
+                BuildSatisfaction::None,
+                &Secp256k1::new(),
+            )?
+            .ok_or(WalletError::MissingPolicy)?;
+        let raw_keys = RawKeyFingerprints::from_descriptor(&external);
 
-        // Delegate to existing type-specific methods
-        // These will use the temporary wallet for both policy extraction and weight calc
         match descriptor {
-            Descriptor::Pkh(pkh) => Self::from_pkh_to_spend_paths(pkh, &temp_wallet),
-            Descriptor::Sh(sh) => Self::from_sh_to_spend_paths(sh, &temp_wallet),
-            Descriptor::Wpkh(wpkh) => Self::from_wpkh_to_spend_paths(wpkh, &temp_wallet),
-            Descriptor::Wsh(wsh) => Self::from_wsh_to_spend_paths(wsh, &temp_wallet),
-            Descriptor::Tr(tr) => Self::from_tr_to_spend_paths(tr, &temp_wallet),
+            Descriptor::Pkh(pkh) => Self::from_pkh_to_builders(pkh, &policy),
+            Descriptor::Sh(sh) => Self::from_sh_to_builders(sh, &policy, &raw_keys),
+            Descriptor::Wpkh(wpkh) => Self::from_wpkh_to_builders(wpkh, &policy),
+            Descriptor::Wsh(wsh) => Self::from_wsh_to_builders(wsh, &policy, &raw_keys),
+            Descriptor::Tr(tr) => Self::from_tr_to_builders(tr, &policy, &raw_keys),
             _ => Err(WalletError::UnsupportedDescriptor.into()),
         }
     }
 
-    /// Create minimal temporary wallet for weight calculation only
-    ///
-    /// Weight calculation requires actual transaction building which needs a full wallet.
-    /// This is unavoidable but we only create it once and discard it immediately.
-    fn create_weight_calc_wallet(descriptor: &str, network: Network) -> Result<Wallet> {
-        Wallet::create_from_two_path_descriptor(descriptor.to_string())
-            .network(network)
-            .create_wallet_no_persist()
-            .map_err(Into::into)
-    }
-
     /// Extract spend paths from existing wallet (backward compatibility)
     ///
     /// This method is kept for backward compatibility with existing code
@@ -370,402 +928,540 @@ impl SpendPath {
         Self::extract_from_descriptor(descriptor, network)
     }
 
-    fn from_pkh_to_spend_paths(
+    fn from_pkh_to_builders(
         pkh: &Pkh<DescriptorPublicKey>,
-        wallet: &Wallet,
-    ) -> Result<Vec<SpendPath>> {
+        policy: &Policy,
+    ) -> Result<Vec<SpendPathBuilder>> {
         let mut spb = SpendPathBuilder::new();
-        spb.add_policy_path(&get_unique_policy_id(wallet)?, &[0])
+        spb.add_policy_path(&unique_policy_id(policy)?, &[0])
             .threshold(1)?
             .add_mfp(pkh.as_inner().master_fingerprint().to_string())
             .addr_type(String::from("P2PKH"));
 
-        let mut spbs = vec![spb];
-        WeightCalc::calc_tx_weight(wallet, &mut spbs)?;
-
-        Ok(SpendPathBuilder::build_many(spbs)?)
+        Ok(vec![spb])
     }
 
-    fn from_sh_to_spend_paths(
+    fn from_sh_to_builders(
         _sh: &Sh<DescriptorPublicKey>,
-        wallet: &Wallet,
-    ) -> Result<Vec<SpendPath>> {
-        let policy = get_policy(wallet)?;
-
-        let mut spbs = SpendPathBuilder::from_policies(&policy)?;
+        policy: &Policy,
+        raw_keys: &RawKeyFingerprints,
+    ) -> Result<Vec<SpendPathBuilder>> {
+        let mut spbs = SpendPathBuilder::from_policies(policy, raw_keys)?;
         for spb in &mut spbs {
             spb.addr_type(String::from("P2SH"));
         }
 
-        WeightCalc::calc_tx_weight(wallet, &mut spbs)?;
-        Ok(SpendPathBuilder::build_many(spbs)?)
+        Ok(spbs)
     }
 
-    fn from_wpkh_to_spend_paths(
+    fn from_wpkh_to_builders(
         wpkh: &Wpkh<DescriptorPublicKey>,
-        wallet: &Wallet,
-    ) -> Result<Vec<SpendPath>> {
+        policy: &Policy,
+    ) -> Result<Vec<SpendPathBuilder>> {
         let mut spb = SpendPathBuilder::new();
-        spb.add_policy_path(&get_unique_policy_id(wallet)?, &[0])
+        spb.add_policy_path(&unique_policy_id(policy)?, &[0])
             .threshold(1)?
-            .add_mfp(wpkh.as_inner().master_fingerprint().to_string());
-
-        let mut spbs = vec![spb];
-        for spb in &mut spbs {
-            spb.addr_type(String::from("P2WPKH"));
-        }
+            .add_mfp(wpkh.as_inner().master_fingerprint().to_string())
+            .addr_type(String::from("P2WPKH"));
 
-        WeightCalc::calc_tx_weight(wallet, &mut spbs)?;
-
-        Ok(SpendPathBuilder::build_many(spbs)?)
+        Ok(vec![spb])
     }
 
-    fn from_wsh_to_spend_paths(
+    fn from_wsh_to_builders(
         _wsh: &Wsh<DescriptorPublicKey>,
-        wallet: &Wallet,
-    ) -> Result<Vec<SpendPath>> {
-        let policy = get_policy(wallet)?;
-
-        let mut spbs = SpendPathBuilder::from_policies(&policy)?;
+        policy: &Policy,
+        raw_keys: &RawKeyFingerprints,
+    ) -> Result<Vec<SpendPathBuilder>> {
+        let mut spbs = SpendPathBuilder::from_policies(policy, raw_keys)?;
         for spb in &mut spbs {
             spb.addr_type(String::from("P2WSH"));
         }
 
-        WeightCalc::calc_tx_weight(wallet, &mut spbs)?;
-        Ok(SpendPathBuilder::build_many(spbs)?)
+        Ok(spbs)
     }
 
-    fn from_tr_to_spend_paths(
+    fn from_tr_to_builders(
         tr: &Tr<DescriptorPublicKey>,
-        wallet: &Wallet,
-    ) -> Result<Vec<SpendPath>> {
-        let policy = get_policy(wallet)?;
-
-        let mut spbs: Vec<SpendPathBuilder> = SpendPathBuilder::from_tr_policies(&policy)?;
+        policy: &Policy,
+        raw_keys: &RawKeyFingerprints,
+    ) -> Result<Vec<SpendPathBuilder>> {
+        let mut spbs: Vec<SpendPathBuilder> = SpendPathBuilder::from_tr_policies(policy, raw_keys)?;
         for spb in &mut spbs {
             spb.addr_type(String::from("P2TR"));
         }
 
-        // If the internal key is a raw (Single) key (e.g. NUMS unspendable point)
-        // or an unspendable xpub, remove the key-path spend path — it's not actually spendable.
-        use crate::core::pubkey::PubKey;
-        let internal_key = tr.internal_key();
-        let skip_key_path = match internal_key {
-            DescriptorPublicKey::Single(_) => true,
-            DescriptorPublicKey::XPub(_) | DescriptorPublicKey::MultiXPub(_) => {
-                // Check if internal key is an unspendable NUMS xpub
-                match PubKey::try_from(internal_key.clone()) {
-                    std::result::Result::Ok(pk) => pk.is_unspendable(),
-                    std::result::Result::Err(_) => false,
-                }
+        // The root policy lists the key path, then every leaf that has a policy
+        let secp = Secp256k1::new();
+        let mut leaves = Vec::new();
+        for (position, (_, ms)) in tr.iter_scripts().enumerate() {
+            let leaf_policy =
+                ms.extract_policy(&SignersContainer::new(), BuildSatisfaction::None, &secp)?;
+            if leaf_policy.is_some() {
+                leaves.push(position);
             }
-        };
-
-        if skip_key_path && !spbs.is_empty() {
-            spbs.remove(0);
-            for spb in spbs.iter_mut() {
+        }
+        for spb in &mut spbs {
+            let item = spb
+                .policy_path
+                .get(&policy.id)
+                .and_then(|items| items.first());
+            if let Some(&item) = item.filter(|&&item| item > 0) {
                 spb.is_tr_script = true;
+                spb.tr_leaf = leaves.get(item - 1).copied();
             }
-        } else {
-            for (i, spb) in spbs.iter_mut().enumerate() {
-                spb.is_tr_script = i != 0;
-            }
         }
 
-        WeightCalc::calc_tx_weight(wallet, &mut spbs)?;
-        Ok(SpendPathBuilder::build_many(spbs)?)
+        // If the internal key is unspendable (NUMS point, raw or as an xpub),
+        // remove the key-path spend path — it's not actually spendable.
+        use crate::core::pubkey::PubKey;
+        let internal_key = tr.internal_key();
+        if PubKey::try_from(internal_key.clone())?.is_unspendable() {
+            spbs.retain(|spb| spb.is_tr_script);
+        }
+
+        Ok(spbs)
     }
 }
 
+/// Upper bound of spend paths a descriptor expands to
+const MAX_SPEND_PATHS: usize = 256;
+/// Upper bound of signer sets listed per spend path
+const MAX_SIGNER_SETS: usize = 32;
+/// Upper bound of signer sets weighed per spend path, the cheapest being listed
+const MAX_SIGNER_SET_CANDIDATES: usize = 256;
+
 /// Taproot control block: 1 byte version + 32 bytes internal key
 const TAPROOT_CB_BASE_LEN: usize = 33;
 /// Each node in the Merkle path adds 32 bytes to the control block
 const TAPROOT_CB_NODE_LEN: usize = 32;
 
+/// Version and locktime, plus the 1 byte input and output counts
+const TX_BASE_LEN: usize = 4 + 4 + 1 + 1;
+/// Segwit marker and flag, only counted once in witness units
+const SEGWIT_MARKER_WU: u32 = 2;
+/// Outpoint and sequence of an input
+const TXIN_BASE_LEN: usize = 36 + 4;
+/// Amount of an output
+const TXOUT_BASE_LEN: usize = 8;
+/// Signature sizes an input is weighted with, sighash byte included
+///
+/// ECDSA signatures are 71 bytes when the signer grinds for a low R and 72
+/// when R needs a padding byte (low S is enforced by standardness). Schnorr
+/// signatures are 64 bytes with `SIGHASH_DEFAULT` and 65 with any other
+/// sighash type.
+#[derive(Debug, Clone, Copy)]
+struct SigLens {
+    ecdsa: usize,
+    schnorr: usize,
+}
+
+impl SigLens {
+    /// Worst-case ECDSA and `SIGHASH_DEFAULT` Schnorr, as most wallets sign
+    const NOMINAL: Self = Self {
+        ecdsa: 72,
+        schnorr: 64,
+    };
+    /// Low-R ECDSA and `SIGHASH_DEFAULT` Schnorr
+    const MIN: Self = Self {
+        ecdsa: 71,
+        schnorr: 64,
+    };
+    /// High-R ECDSA and Schnorr with an explicit sighash byte
+    const MAX: Self = Self {
+        ecdsa: 72,
+        schnorr: 65,
+    };
+}
+
+/// Transaction weights computed from the miniscript satisfaction of the paths
+///
+/// The weights are those of a transaction spending the path back to the
+/// wallet's first address, with the cheapest satisfaction the path signers
+/// can produce.
 pub struct WeightCalc;
 
 impl WeightCalc {
-    fn calc_tx_weight(wallet: &Wallet, spbs: &mut Vec<SpendPathBuilder>) -> Result<()> {
-        let (mut fake_wallet, txid) = Self::build_fake_wallet(wallet)?;
-
-        let addr_script_pubkey = fake_wallet
-            .next_unused_address(KeychainKind::External)
-            .script_pubkey();
+    fn calc_tx_weight(
+        descriptor: &Descriptor<DescriptorPublicKey>,
+        spbs: &mut [SpendPathBuilder],
+    ) -> Result<()> {
+        // Spend paths are the same on every keychain and index, so the first
+        // external address is planned
+        let definite = descriptor
+            .clone()
+            .into_single_descriptors()?
+            .remove(0)
+            .at_derivation_index(0)?;
+
+        let mut base = (TX_BASE_LEN * 4) as u32;
+        if definite.desc_type().segwit_version().is_some() {
+            base += SEGWIT_MARKER_WU;
+        }
+        // Change goes back to the wallet
+        let output = Self::output_wu(definite.script_pubkey().len());
 
         for spb in spbs {
-            let tx_1_1 =
-                Self::dummy_tx_wu(&mut fake_wallet, spb, &txid, &addr_script_pubkey, 1, 1)?;
-
-            let tx_1_2 =
-                Self::dummy_tx_wu(&mut fake_wallet, spb, &txid, &addr_script_pubkey, 1, 2)?;
-
-            let tx_2_1 =
-                Self::dummy_tx_wu(&mut fake_wallet, spb, &txid, &addr_script_pubkey, 2, 1)?;
-
-            let input = tx_2_1 - tx_1_1;
-            let output = tx_1_2 - tx_1_1;
-            let base = tx_1_1 - input - output;
+            // Add the minimun signatures to satisfy the policy
+            let root = spb.root_signer_group()?;
+            let plan = Self::plan_input(&definite, spb, &root.signing_set())?;
+            let input = Self::input_wu(&definite, &plan, SigLens::NOMINAL)?;
 
             spb.wu_base(base).wu_in(input).wu_out(output);
+            spb.wu_in_min = Some(Self::input_wu(&definite, &plan, SigLens::MIN)?);
+            spb.wu_in_max = Some(Self::input_wu(&definite, &plan, SigLens::MAX)?);
+            if spb.is_tr_script {
+                spb.tr_depth = Self::tr_depth(&plan)?;
+            }
+
+            let (sets, truncated) = root.minimal_signer_sets(MAX_SIGNER_SET_CANDIDATES);
+            let mut signer_sets = Vec::new();
+            for set in sets {
+                if set.is_empty() {
+                    continue;
+                }
+                // Sets that can't satisfy the path (e.g. the same key under
+                // two fingerprints) are not a real way to sign it
+                let std::result::Result::Ok(plan) = Self::plan_input(&definite, spb, &set) else {
+                    continue;
+                };
+                signer_sets.push(SignerSet {
+                    mfps: set.into_iter().collect(),
+                    wu_in: Self::input_wu(&definite, &plan, SigLens::NOMINAL)?,
+                });
+            }
+            spb.signer_sets(signer_sets, truncated);
         }
 
         Ok(())
     }
 
-    fn build_fake_wallet(wallet: &Wallet) -> Result<(PersistedWallet<Connection>, Txid)> {
-        // New fake wallet to build some TXs
-        let mut mem = Connection::open_in_memory()?;
-        let mut fake_wallet = Wallet::create(
-            wallet.public_descriptor(KeychainKind::External).to_string(),
-            wallet.public_descriptor(KeychainKind::Internal).to_string(),
-        )
-        .network(wallet.network())
-        .create_wallet(&mut mem)?;
-
-        // Get the first External address
-        let address_info = fake_wallet.reveal_next_address(KeychainKind::External);
-        let my_spk = address_info.address.script_pubkey();
-
-        // Build a fake input TX
-        let fake_tx = Transaction {
-            version: Version::TWO,
-            lock_time: bdk_wallet::bitcoin::absolute::LockTime::ZERO,
-            input: vec![],
-            output: vec![
-                TxOut {
-                    value: Amount::from_sat(100_000),
-                    script_pubkey: my_spk.clone(),
-                },
-                TxOut {
-                    value: Amount::from_sat(100_000),
-                    script_pubkey: my_spk.clone(),
-                },
-                TxOut {
-                    value: Amount::from_sat(100_000),
-                    script_pubkey: my_spk.clone(),
-                },
-                TxOut {
-                    value: Amount::from_sat(100_000),
-                    script_pubkey: my_spk.clone(),
-                },
-                TxOut {
-                    value: Amount::from_sat(100_000),
-                    script_pubkey: my_spk.clone(),
-                },
-            ],
+    /// Cheapest satisfaction of the path when `signers` sign
+    ///
+    /// Signers can use every key with their fingerprint. In taproot, script
+    /// paths only sign the leaf they come from.
+    fn plan_input(
+        definite: &Descriptor<DefiniteDescriptorKey>,
+        spb: &SpendPathBuilder,
+        signers: &BTreeSet<String>,
+    ) -> Result<Plan> {
+        let can_sign = CanSign {
+            ecdsa: true,
+            taproot: TaprootCanSign {
+                key_spend: !spb.is_tr_script,
+                script_spend: TaprootAvailableLeaves::Many(Self::path_leaves(definite, spb)),
+                sighash_default: true,
+            },
         };
-        let txid = fake_tx.compute_txid();
 
-        // Update to insert the TX on wallet
-        let mut update = Update::default();
+        let mut assets = Assets::new();
+        definite.for_each_key(|key| {
+            let mfp = key.master_fingerprint();
+            if signers.contains(&mfp.to_string()) {
+                if let Some(path) = key.full_derivation_path() {
+                    assets.keys.insert(((mfp, path), can_sign.clone()));
+                }
+            }
+            true
+        });
 
-        // Blocks 0 and 1
-        let hash0 = bdk_wallet::bitcoin::constants::genesis_block(Network::Signet).block_hash();
-        let hash1 = BlockHash::all_zeros();
+        for hashlock in &spb.hashlocks {
+            let digest = hex::decode(&hashlock.digest)?;
+            match hashlock.hash_type {
+                HashType::Sha256 => {
+                    assets
+                        .sha256_preimages
+                        .insert(sha256::Hash::from_slice(&digest)?);
+                }
+                HashType::Hash256 => {
+                    assets
+                        .hash256_preimages
+                        .insert(hash256::Hash::from_slice(&digest)?);
+                }
+                HashType::Ripemd160 => {
+                    assets
+                        .ripemd160_preimages
+                        .insert(ripemd160::Hash::from_slice(&digest)?);
+                }
+                HashType::Hash160 => {
+                    assets
+                        .hash160_preimages
+                        .insert(hash160::Hash::from_slice(&digest)?);
+                }
+            }
+        }
 
-        let cp0 = CheckPoint::new(BlockId {
-            height: 0,
-            hash: hash0,
-        });
-        let cp1 = cp0.insert(BlockId {
-            height: 1,
-            hash: hash1,
-        });
-        update.chain = Some(cp1);
+        if spb.has_timelock_conflict() {
+            // No transaction satisfies the path, its weight is still meaningful
+            return definite
+                .clone()
+                .plan(&PathTimelocks { assets, spb })
+                .map_err(|_| WalletError::UnsupportedDescriptor.into());
+        }
 
-        // Insert TX on update
-        update.tx_update.txs.push(Arc::new(fake_tx));
+        if spb.rel_timelock() > 0 {
+            let lock = relative::LockTime::from_consensus(spb.rel_timelock())
+                .map_err(|_| WalletError::UnsupportedDescriptor)?;
+            assets = assets.older(lock);
+        }
+        if spb.abs_timelock() > 0 {
+            assets = assets.after(absolute::LockTime::from_consensus(spb.abs_timelock()));
+        }
 
-        // Tx anchor to block 1
-        update.tx_update.anchors.insert((
-            ConfirmationBlockTime {
-                block_id: BlockId {
-                    height: 1,
-                    hash: hash1,
-                },
-                confirmation_time: 1700000000,
-            },
-            txid,
-        ));
+        definite
+            .clone()
+            .plan(&assets)
+            .map_err(|_| WalletError::UnsupportedDescriptor.into())
+    }
 
-        // Apply update to wallet
-        fake_wallet.apply_update(update)?;
+    /// Leaf of a taproot script path, none for other paths
+    fn path_leaves(
+        definite: &Descriptor<DefiniteDescriptorKey>,
+        spb: &SpendPathBuilder,
+    ) -> Vec<TapLeafHash> {
+        let Descriptor::Tr(tr) = definite else {
+            return vec![];
+        };
 
-        Ok((fake_wallet, txid))
+        spb.tr_leaf
+            .and_then(|leaf| tr.iter_scripts().nth(leaf))
+            .map(|(_, ms)| TapLeafHash::from_script(&ms.encode(), LeafVersion::TapScript))
+            .into_iter()
+            .collect()
     }
 
-    fn dummy_tx_wu(
-        wallet: &mut Wallet,
-        spb: &mut SpendPathBuilder,
-        utxos_txid: &Txid,
-        target_address: &ScriptBuf,
-        ninputs: usize,
-        noutputs: usize,
+    /// Weight of an input spent with `plan`, with signatures of `sig_lens` sizes
+    fn input_wu(
+        definite: &Descriptor<DefiniteDescriptorKey>,
+        plan: &Plan,
+        sig_lens: SigLens,
     ) -> Result<u32> {
-        let mut tx_builder = wallet.build_tx();
-
-        tx_builder
-            .policy_path(spb.policy_path.clone(), KeychainKind::External)
-            .policy_path(spb.policy_path.clone(), KeychainKind::Internal);
+        let template = plan.witness_template();
+        let witness_items = || {
+            template
+                .iter()
+                .map(|placeholder| Self::item_len(placeholder, sig_lens))
+                .collect::<Vec<_>>()
+        };
 
-        for i in 0..ninputs {
-            tx_builder.add_utxo(OutPoint {
-                txid: *utxos_txid,
-                vout: i as u32,
-            })?;
-        }
+        let (script_sig, witness) = match definite.desc_type() {
+            DescriptorType::Bare | DescriptorType::Pkh => {
+                (Self::script_sig_len(template, sig_lens), vec![])
+            }
+            DescriptorType::Sh | DescriptorType::ShSortedMulti => {
+                // The redeem script is pushed after its satisfaction
+                let redeem_script = definite.explicit_script()?;
+                (
+                    Self::script_sig_len(template, sig_lens) + Self::push_len(redeem_script.len()),
+                    vec![],
+                )
+            }
+            DescriptorType::Wpkh | DescriptorType::Tr => (0, witness_items()),
+            DescriptorType::ShWpkh => (definite.unsigned_script_sig().len(), witness_items()),
+            DescriptorType::Wsh | DescriptorType::WshSortedMulti => {
+                let mut witness = witness_items();
+                witness.push(definite.explicit_script()?.len());
+                (0, witness)
+            }
+            DescriptorType::ShWsh | DescriptorType::ShWshSortedMulti => {
+                let mut witness = witness_items();
+                witness.push(definite.explicit_script()?.len());
+                (definite.unsigned_script_sig().len(), witness)
+            }
+        };
 
-        for _ in 0..(noutputs - 1) {
-            tx_builder.add_recipient(target_address.clone(), Amount::from_sat(5_000));
+        let mut wu = (TXIN_BASE_LEN + VarInt(script_sig as u64).size() + script_sig) * 4;
+        if definite.desc_type().segwit_version().is_some() {
+            wu += VarInt(witness.len() as u64).size();
+            wu += witness
+                .iter()
+                .map(|&len| VarInt(len as u64).size() + len)
+                .sum::<usize>();
         }
 
-        tx_builder
-            .manually_selected_only()
-            .drain_to(target_address.clone())
-            .fee_rate(FeeRate::from_sat_per_vb(1).ok_or(WalletError::UnsupportedDescriptor)?);
-
-        let mut psbt = tx_builder.finish()?;
-
-        Self::dummy_sig(&mut psbt, spb)?;
-
-        // Calculate resulted WU
-        #[expect(deprecated)]
-        let sign_options = SignOptions::default();
-        let finalized = wallet.finalize_psbt(&mut psbt, sign_options)?;
+        Ok(wu as u32)
+    }
 
-        if !finalized {
-            return Err(WalletError::UnsupportedDescriptor.into());
+    /// Size of the data of a witness item
+    fn item_len(placeholder: &Placeholder<DefiniteDescriptorKey>, sig_lens: SigLens) -> usize {
+        match placeholder {
+            // Key sizes include their push opcode
+            Placeholder::Pubkey(_, size) | Placeholder::PubkeyHash(_, size) => size - 1,
+            Placeholder::EcdsaSigPk(_) | Placeholder::EcdsaSigPkHash(_) => sig_lens.ecdsa,
+            Placeholder::SchnorrSigPk(..) | Placeholder::SchnorrSigPkHash(..) => sig_lens.schnorr,
+            Placeholder::Sha256Preimage(_)
+            | Placeholder::Hash256Preimage(_)
+            | Placeholder::Ripemd160Preimage(_)
+            | Placeholder::Hash160Preimage(_)
+            | Placeholder::HashDissatisfaction => HashLock::PREIMAGE_LEN,
+            Placeholder::PushZero => 0,
+            Placeholder::PushOne => 1,
+            Placeholder::TapScript(script) => script.len(),
+            Placeholder::TapControlBlock(control_block) => control_block.serialize().len(),
         }
+    }
 
-        let tx = psbt.extract_tx()?;
+    /// Size of a legacy scriptSig pushing the satisfaction items
+    fn script_sig_len(template: &[Placeholder<DefiniteDescriptorKey>], sig_lens: SigLens) -> usize {
+        template
+            .iter()
+            .map(|placeholder| match placeholder {
+                // OP_0 and OP_1
+                Placeholder::PushZero | Placeholder::PushOne => 1,
+                placeholder => Self::push_len(Self::item_len(placeholder, sig_lens)),
+            })
+            .sum()
+    }
 
-        //Self::print_witness_forensics(&tx);
+    /// Size of the push of `len` bytes, opcode included
+    fn push_len(len: usize) -> usize {
+        match len {
+            0..=75 => 1 + len,
+            76..=0xff => 2 + len,
+            0x100..=0xffff => 3 + len,
+            _ => 5 + len,
+        }
+    }
 
-        let wu = tx.weight().to_wu();
+    fn output_wu(script_pubkey_len: usize) -> u32 {
+        let len = script_pubkey_len;
+        ((TXOUT_BASE_LEN + VarInt(len as u64).size() + len) * 4) as u32
+    }
 
-        if spb.is_tr_script {
-            // Analyze first input
-            let input = tx.input.first().ok_or(WalletError::UnsupportedDescriptor)?;
-            let witness = &input.witness;
-            // Last witness is control block
-            let control_block_bytes = witness.last().ok_or(WalletError::UnsupportedDescriptor)?;
+    /// Weight of an output paying to a script of type `destination`
+    pub fn destination_wu(destination: &WalletType) -> Result<u32> {
+        let len = destination
+            .script_pubkey_len()
+            .ok_or(WalletError::UnknownOutputType)?;
+        Ok(Self::output_wu(len))
+    }
 
-            let cb_len = control_block_bytes.len();
+    /// Depth of the leaf spent by a taproot script path plan
+    fn tr_depth(plan: &Plan) -> Result<usize> {
+        let cb_len = plan
+            .witness_template()
+            .iter()
+            .find_map(|placeholder| match placeholder {
+                Placeholder::TapControlBlock(control_block) => {
+                    Some(control_block.serialize().len())
+                }
+                _ => None,
+            })
+            .ok_or(WalletError::UnsupportedDescriptor)?;
 
-            // Control block size check
-            if cb_len >= TAPROOT_CB_BASE_LEN
-                && (cb_len - TAPROOT_CB_BASE_LEN).is_multiple_of(TAPROOT_CB_NODE_LEN)
-            {
-                spb.tr_depth = ((cb_len - TAPROOT_CB_BASE_LEN) / TAPROOT_CB_NODE_LEN) + 1;
-            } else {
-                Err(WalletError::UnsupportedDescriptor)?;
-            }
+        // Control block size check
+        if cb_len >= TAPROOT_CB_BASE_LEN
+            && (cb_len - TAPROOT_CB_BASE_LEN).is_multiple_of(TAPROOT_CB_NODE_LEN)
+        {
+            Ok(((cb_len - TAPROOT_CB_BASE_LEN) / TAPROOT_CB_NODE_LEN) + 1)
+        } else {
+            Err(WalletError::UnsupportedDescriptor.into())
         }
+    }
 
-        Ok(wu as u32)
+    pub fn to_vbytes(wu: u32) -> f32 {
+        wu as f32 / 4.0
     }
+}
 
-    fn dummy_sig(psbt: &mut Psbt, spb: &SpendPathBuilder) -> Result<()> {
-        // Dummy signatures
-        let dummy_ecdsa: ecdsa::Signature = "3045022100800000000000000000000000000000000000000000000000000000000000000002207fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff01".parse()?;
-        // Schnorr signature
-        let schnorr_bytes = hex::decode(
-            "d45e6105b81093128d2243d6c97a474c106093630f4a475306d649d21469e38f1727725514f9d0c8d5d878783451515202810281200210212002102120021021",
-        )?;
-        let dummy_schnorr = taproot::Signature::from_slice(&schnorr_bytes)?;
+/// Assets meeting every timelock of a path, whatever its unit
+///
+/// `Assets` holds a single lock of each kind, so it can't plan a path mixing
+/// height and time based locks.
+struct PathTimelocks<'a> {
+    assets: Assets,
+    spb: &'a SpendPathBuilder,
+}
 
-        for input in psbt.inputs.iter_mut() {
-            // Add the minimun signatures to satisfy the policy
-            let mut available_mfp = spb.mfps.clone();
-            let threshold = spb.get_threshold()?;
-            while available_mfp.len() > threshold {
-                available_mfp.pop_first();
-            }
+impl AssetProvider<DefiniteDescriptorKey> for PathTimelocks<'_> {
+    fn provider_lookup_ecdsa_sig(&self, pk: &DefiniteDescriptorKey) -> bool {
+        self.assets.provider_lookup_ecdsa_sig(pk)
+    }
 
-            Self::dummy_sig_input(input, spb, &available_mfp, dummy_ecdsa, dummy_schnorr)?;
-        }
+    fn provider_lookup_tap_key_spend_sig(&self, pk: &DefiniteDescriptorKey) -> Option<usize> {
+        self.assets.provider_lookup_tap_key_spend_sig(pk)
+    }
 
-        Ok(())
+    fn provider_lookup_tap_leaf_script_sig(
+        &self,
+        pk: &DefiniteDescriptorKey,
+        tap_leaf_hash: &TapLeafHash,
+    ) -> Option<usize> {
+        self.assets
+            .provider_lookup_tap_leaf_script_sig(pk, tap_leaf_hash)
     }
 
-    fn dummy_sig_input(
-        input: &mut Input,
-        spb: &SpendPathBuilder,
-        available_mfp: &BTreeSet<String>,
-        dummy_ecdsa: ecdsa::Signature,
-        dummy_schnorr: taproot::Signature,
-    ) -> Result<()> {
-        // Legacy and Segwit
-        let keys_to_sign: Vec<PublicKey> = input
-            .bip32_derivation
-            .iter()
-            .filter(|(_, source)| available_mfp.contains(&source.0.to_string()))
-            .map(|(&pk, _)| PublicKey::new(pk))
-            .collect();
+    fn provider_lookup_sha256(&self, hash: &sha256::Hash) -> bool {
+        self.assets.provider_lookup_sha256(hash)
+    }
 
-        if !keys_to_sign.is_empty() {
-            for pk in keys_to_sign {
-                input.partial_sigs.insert(pk, dummy_ecdsa);
-            }
-            return Ok(());
-        }
+    fn provider_lookup_hash256(&self, hash: &hash256::Hash) -> bool {
+        self.assets.provider_lookup_hash256(hash)
+    }
 
-        // Taproot KeyPath
-        if !spb.is_tr_script {
-            if let Some(internal_key) = input.tap_internal_key {
-                let matches = input
-                    .tap_key_origins
-                    .get(&internal_key)
-                    .map(|(_, (mfp, _))| available_mfp.contains(&mfp.to_string()))
-                    .ok_or(WalletError::UnsupportedDescriptor)?;
+    fn provider_lookup_ripemd160(&self, hash: &ripemd160::Hash) -> bool {
+        self.assets.provider_lookup_ripemd160(hash)
+    }
 
-                if matches {
-                    input.tap_key_sig = Some(dummy_schnorr);
-                    return Ok(());
-                }
-            }
-        }
+    fn provider_lookup_hash160(&self, hash: &hash160::Hash) -> bool {
+        self.assets.provider_lookup_hash160(hash)
+    }
 
-        // Taproot ScriptPath
-        for (leaf_script, leaf_ver) in input.tap_scripts.values() {
-            let leaf_hash = TapLeafHash::from_script(leaf_script, *leaf_ver);
+    fn check_older(&self, lock: relative::LockTime) -> bool {
+        self.spb.rel_timelocks.iter().any(|&value| {
+            relative::LockTime::from_consensus(value).is_ok_and(|path| lock.is_implied_by(path))
+        })
+    }
 
-            let leaf_mfps: BTreeSet<String> = input
-                .tap_key_origins
-                .iter()
-                .filter(|(_, (hashes, _))| hashes.contains(&leaf_hash))
-                .map(|(_, (_, (mfp, _)))| mfp.to_string())
-                .collect();
+    fn check_after(&self, lock: absolute::LockTime) -> bool {
+        self.spb
+            .abs_timelocks
+            .iter()
+            .any(|&value| lock.is_implied_by(absolute::LockTime::from_consensus(value)))
+    }
+}
 
-            // Only sign leaf that matches 100% with policy mfps
-            if spb.mfps == leaf_mfps {
-                for (x_only_pk, (hashes, _)) in &input.tap_key_origins {
-                    if hashes.contains(&leaf_hash) {
-                        input
-                            .tap_script_sigs
-                            .insert((*x_only_pk, leaf_hash), dummy_schnorr);
-                    }
-                }
+/// Fingerprints of the raw keys of a descriptor, by key hex
+///
+/// Policies only carry the raw key, while PSBTs and `PubKey` identify it by its
+/// origin fingerprint, or the synthetic one miniscript derives from the key.
+struct RawKeyFingerprints(BTreeMap<String, String>);
+
+impl RawKeyFingerprints {
+    fn from_descriptor(descriptor: &Descriptor<DescriptorPublicKey>) -> Self {
+        let mut fingerprints = BTreeMap::new();
+        descriptor.for_each_key(|key| {
+            if let DescriptorPublicKey::Single(single) = key {
+                let hex = match single.key {
+                    SinglePubKey::FullKey(pk) => pk.to_string(),
+                    SinglePubKey::XOnly(xpk) => xpk.to_string(),
+                };
+                fingerprints.insert(hex, key.master_fingerprint().to_string());
             }
-        }
-
-        Ok(())
+            true
+        });
+        Self(fingerprints)
     }
 
-    pub fn to_vbytes(wu: u32) -> f32 {
-        wu as f32 / 4.0
+    fn get(&self, key: &impl ToString) -> Option<String> {
+        self.0.get(&key.to_string()).cloned()
     }
 }
 
-fn fingerprint_of(key: &PkOrF) -> Result<String> {
+fn fingerprint_of(key: &PkOrF, keys: &RawKeyFingerprints) -> Result<String> {
     match key {
         PkOrF::Fingerprint(fp) => Ok(fp.to_string()),
         PkOrF::Pubkey(pk) => {
+            if let Some(fp) = keys.get(pk) {
+                return Ok(fp);
+            }
             let hash = pk.pubkey_hash();
             let bytes: [u8; 4] = hash.to_byte_array()[..4].try_into().unwrap();
             Ok(bdk_wallet::bitcoin::bip32::Fingerprint::from(bytes).to_string())
         }
         PkOrF::XOnlyPubkey(xpk) => {
+            if let Some(fp) = keys.get(xpk) {
+                return Ok(fp);
+            }
             let mut compressed = [0u8; 33];
             compressed[0] = 0x02;
             compressed[1..].copy_from_slice(&xpk.serialize());
@@ -778,16 +1474,693 @@ fn fingerprint_of(key: &PkOrF) -> Result<String> {
     }
 }
 
-fn get_policy(wallet: &Wallet) -> Result<Policy> {
-    wallet
-        .policies(KeychainKind::External)?
+fn unique_policy_id(policy: &Policy) -> Result<String> {
+    (!policy.requires_path())
+        .then_some(policy.id.clone())
         .ok_or(WalletError::MissingPolicy.into())
 }
 
-fn get_unique_policy_id(wallet: &Wallet) -> Result<String> {
-    let policy = get_policy(wallet)?;
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    const XPUB_A: &str = "xpub6Dtni7dearhzvCuQ3aZYC5VkDEnpjJjoCSJRxs2m6D63r1KzvgvAvQKypzqFpSZ2uaYfNx8HSgi63jcK4ZFgFCTVph1MTMZxP55L1am1Csn";
+    const XPUB_B: &str = "xpub6EDTxSWtzPTBiQtxScLWm1sJ6By9QPrG6J5RvA3ZuKYHP1mfvyeyTG2Gy3CgnQ2ps5p6cgGTvuULfxuqQtSAvkVp9VyASus6pMFoe8mztCj";
+    const TPUB_A: &str = "tpubDC5FSnBiZDMmhiuCmWAYsLwgLYrrT9rAqvTySfuCCrgsWz8wxMXUS9Tb9iVMvcRbvFcAHGkMD5Kx8koh4GquNGNTfohfk7pgjhaPCdXpoba";
+
+    use bdk_wallet::bitcoin::psbt::Input;
+    use bdk_wallet::bitcoin::transaction::Version;
+    use bdk_wallet::bitcoin::{
+        ecdsa, taproot, Amount, BlockHash, FeeRate, OutPoint, Psbt, ScriptBuf, Transaction, TxOut,
+        Txid,
+    };
+    use bdk_wallet::chain::{BlockId, CheckPoint, ConfirmationBlockTime};
+    use bdk_wallet::rusqlite::Connection;
+    #[allow(deprecated)]
+    use bdk_wallet::SignOptions;
+    use bdk_wallet::{PersistedWallet, Update};
+    use secp256k1::hashes::sha256d;
+
+    use super::*;
+    use crate::core::wallet::create_params;
+
+    /// Weights measured on finalized transactions built with dummy signatures
+    ///
+    /// Slow, but independent from the analytical weights it checks.
+    struct FakeTxWeightCalc;
+
+    impl FakeTxWeightCalc {
+        fn calc_tx_weight(wallet: &Wallet, spbs: &mut [SpendPathBuilder]) -> Result<()> {
+            let (mut fake_wallet, txid) = Self::build_fake_wallet(wallet)?;
+
+            let addr_script_pubkey = fake_wallet
+                .next_unused_address(KeychainKind::External)
+                .script_pubkey();
+
+            for spb in spbs {
+                // Add the minimun signatures to satisfy the policy
+                let root = spb.root_signer_group()?;
+                let signers = root.signing_set();
+
+                let tx_1_1 = Self::dummy_tx_wu(
+                    &mut fake_wallet,
+                    spb,
+                    &txid,
+                    &addr_script_pubkey,
+                    1,
+                    1,
+                    &signers,
+                )?;
+
+                let tx_1_2 = Self::dummy_tx_wu(
+                    &mut fake_wallet,
+                    spb,
+                    &txid,
+                    &addr_script_pubkey,
+                    1,
+                    2,
+                    &signers,
+                )?;
+
+                let tx_2_1 = Self::dummy_tx_wu(
+                    &mut fake_wallet,
+                    spb,
+                    &txid,
+                    &addr_script_pubkey,
+                    2,
+                    1,
+                    &signers,
+                )?;
+
+                let input = tx_2_1 - tx_1_1;
+                let output = tx_1_2 - tx_1_1;
+                let base = tx_1_1 - input - output;
+
+                spb.wu_base(base).wu_in(input).wu_out(output);
+
+                // Only the witness changes between signer sets, so the difference
+                // on a 1-input transaction is the difference on the input weight
+                let (sets, truncated) = root.minimal_signer_sets(MAX_SIGNER_SET_CANDIDATES);
+                let mut signer_sets = Vec::new();
+                for set in sets {
+                    if set.is_empty() {
+                        continue;
+                    }
+                    // Sets that can't finalize (e.g. the same key under two
+                    // fingerprints) are not a real way to sign the path
+                    let std::result::Result::Ok(tx) = Self::dummy_tx_wu(
+                        &mut fake_wallet,
+                        spb,
+                        &txid,
+                        &addr_script_pubkey,
+                        1,
+                        1,
+                        &set,
+                    ) else {
+                        continue;
+                    };
+                    signer_sets.push(SignerSet {
+                        mfps: set.into_iter().collect(),
+                        wu_in: input + tx - tx_1_1,
+                    });
+                }
+                spb.signer_sets(signer_sets, truncated);
+            }
 
-    (!policy.requires_path())
-        .then_some(policy.id)
-        .ok_or(WalletError::MissingPolicy.into())
+            Ok(())
+        }
+
+        fn build_fake_wallet(wallet: &Wallet) -> Result<(PersistedWallet<Connection>, Txid)> {
+            // New fake wallet to build some TXs
+            let mut mem = Connection::open_in_memory()?;
+            let external = wallet.public_descriptor(KeychainKind::External).to_string();
+            // Single descriptor wallets use the same keychain for change
+            let params = if wallet.keychains().count() > 1 {
+                let internal = wallet.public_descriptor(KeychainKind::Internal).to_string();
+                Wallet::create(external, internal)
+            } else {
+                Wallet::create_single(external)
+            };
+            let mut fake_wallet = params.network(wallet.network()).create_wallet(&mut mem)?;
+
+            // Get the first External address
+            let address_info = fake_wallet.reveal_next_address(KeychainKind::External);
+            let my_spk = address_info.address.script_pubkey();
+
+            // Build a fake input TX
+            let fake_tx = Transaction {
+                version: Version::TWO,
+                lock_time: bdk_wallet::bitcoin::absolute::LockTime::ZERO,
+                input: vec![],
+                output: vec![
+                    TxOut {
+                        value: Amount::from_sat(100_000),
+                        script_pubkey: my_spk.clone(),
+                    },
+                    TxOut {
+                        value: Amount::from_sat(100_000),
+                        script_pubkey: my_spk.clone(),
+                    },
+                    TxOut {
+                        value: Amount::from_sat(100_000),
+                        script_pubkey: my_spk.clone(),
+                    },
+                    TxOut {
+                        value: Amount::from_sat(100_000),
+                        script_pubkey: my_spk.clone(),
+                    },
+                    TxOut {
+                        value: Amount::from_sat(100_000),
+                        script_pubkey: my_spk.clone(),
+                    },
+                ],
+            };
+            let txid = fake_tx.compute_txid();
+
+            // Update to insert the TX on wallet
+            let mut update = Update::default();
+
+            // Blocks 0 and 1
+            let hash0 =
+                bdk_wallet::bitcoin::constants::genesis_block(wallet.network()).block_hash();
+            let hash1 = BlockHash::all_zeros();
+
+            let cp0 = CheckPoint::new(BlockId {
+                height: 0,
+                hash: hash0,
+            });
+            let cp1 = cp0.insert(BlockId {
+                height: 1,
+                hash: hash1,
+            });
+            update.chain = Some(cp1);
+
+            // Insert TX on update
+            update.tx_update.txs.push(Arc::new(fake_tx));
+
+            // Tx anchor to block 1
+            update.tx_update.anchors.insert((
+                ConfirmationBlockTime {
+                    block_id: BlockId {
+                        height: 1,
+                        hash: hash1,
+                    },
+                    confirmation_time: 1700000000,
+                },
+                txid,
+            ));
+
+            // Apply update to wallet
+            fake_wallet.apply_update(update)?;
+
+            Ok((fake_wallet, txid))
+        }
+
+        fn dummy_tx_wu(
+            wallet: &mut Wallet,
+            spb: &mut SpendPathBuilder,
+            utxos_txid: &Txid,
+            target_address: &ScriptBuf,
+            ninputs: usize,
+            noutputs: usize,
+            signers: &BTreeSet<String>,
+        ) -> Result<u32> {
+            let mut tx_builder = wallet.build_tx();
+
+            tx_builder
+                .policy_path(spb.policy_path.clone(), KeychainKind::External)
+                .policy_path(spb.policy_path.clone(), KeychainKind::Internal);
+
+            for i in 0..ninputs {
+                tx_builder.add_utxo(OutPoint {
+                    txid: *utxos_txid,
+                    vout: i as u32,
+                })?;
+            }
+
+            for _ in 0..(noutputs - 1) {
+                tx_builder.add_recipient(target_address.clone(), Amount::from_sat(5_000));
+            }
+
+            tx_builder
+                .manually_selected_only()
+                .drain_to(target_address.clone())
+                .fee_rate(FeeRate::from_sat_per_vb(1).ok_or(WalletError::UnsupportedDescriptor)?);
+
+            let mut psbt = tx_builder.finish()?;
+
+            // The fake UTXOs pay to the first external address
+            let definite = wallet
+                .public_descriptor(KeychainKind::External)
+                .at_derivation_index(0)?;
+            let leaves = WeightCalc::path_leaves(&definite, spb);
+            Self::dummy_sig(&mut psbt, spb, signers, &leaves)?;
+
+            // Calculate resulted WU
+            #[allow(deprecated)]
+            let sign_options = SignOptions::default();
+            let finalized = wallet.finalize_psbt(&mut psbt, sign_options)?;
+
+            if !finalized {
+                return Err(WalletError::UnsupportedDescriptor.into());
+            }
+
+            let tx = psbt.extract_tx()?;
+
+            //Self::print_witness_forensics(&tx);
+
+            let wu = tx.weight().to_wu();
+
+            if spb.is_tr_script {
+                // Analyze first input
+                let input = tx.input.first().ok_or(WalletError::UnsupportedDescriptor)?;
+                let witness = &input.witness;
+                // Last witness is control block
+                let control_block_bytes =
+                    witness.last().ok_or(WalletError::UnsupportedDescriptor)?;
+
+                let cb_len = control_block_bytes.len();
+
+                // Control block size check
+                if cb_len >= TAPROOT_CB_BASE_LEN
+                    && (cb_len - TAPROOT_CB_BASE_LEN).is_multiple_of(TAPROOT_CB_NODE_LEN)
+                {
+                    spb.tr_depth = ((cb_len - TAPROOT_CB_BASE_LEN) / TAPROOT_CB_NODE_LEN) + 1;
+                } else {
+                    Err(WalletError::UnsupportedDescriptor)?;
+                }
+            }
+
+            Ok(wu as u32)
+        }
+
+        fn dummy_sig(
+            psbt: &mut Psbt,
+            spb: &SpendPathBuilder,
+            signers: &BTreeSet<String>,
+            leaves: &[TapLeafHash],
+        ) -> Result<()> {
+            // Dummy signatures
+            let dummy_ecdsa: ecdsa::Signature = "3045022100800000000000000000000000000000000000000000000000000000000000000002207fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff01".parse()?;
+            // Schnorr signature
+            let schnorr_bytes = hex::decode(
+                "d45e6105b81093128d2243d6c97a474c106093630f4a475306d649d21469e38f1727725514f9d0c8d5d878783451515202810281200210212002102120021021",
+            )?;
+            let dummy_schnorr = taproot::Signature::from_slice(&schnorr_bytes)?;
+
+            for input in psbt.inputs.iter_mut() {
+                Self::dummy_sig_input(input, spb, signers, leaves, dummy_ecdsa, dummy_schnorr)?;
+                Self::dummy_preimages_input(input, &spb.hashlocks)?;
+            }
+
+            Ok(())
+        }
+
+        /// Provide a dummy preimage for every hashlock of the path
+        ///
+        /// The finalizer doesn't check the preimage against the digest, only its size.
+        fn dummy_preimages_input(input: &mut Input, hashlocks: &BTreeSet<HashLock>) -> Result<()> {
+            let preimage = vec![0u8; HashLock::PREIMAGE_LEN];
+
+            for hashlock in hashlocks {
+                let digest = hex::decode(&hashlock.digest)?;
+                match hashlock.hash_type {
+                    HashType::Sha256 => {
+                        input
+                            .sha256_preimages
+                            .insert(sha256::Hash::from_slice(&digest)?, preimage.clone());
+                    }
+                    HashType::Hash256 => {
+                        input
+                            .hash256_preimages
+                            .insert(sha256d::Hash::from_slice(&digest)?, preimage.clone());
+                    }
+                    HashType::Ripemd160 => {
+                        input
+                            .ripemd160_preimages
+                            .insert(ripemd160::Hash::from_slice(&digest)?, preimage.clone());
+                    }
+                    HashType::Hash160 => {
+                        input
+                            .hash160_preimages
+                            .insert(hash160::Hash::from_slice(&digest)?, preimage.clone());
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn dummy_sig_input(
+            input: &mut Input,
+            spb: &SpendPathBuilder,
+            available_mfp: &BTreeSet<String>,
+            leaves: &[TapLeafHash],
+            dummy_ecdsa: ecdsa::Signature,
+            dummy_schnorr: taproot::Signature,
+        ) -> Result<()> {
+            // Legacy and Segwit
+            let keys_to_sign: Vec<PublicKey> = input
+                .bip32_derivation
+                .iter()
+                .filter(|(_, source)| available_mfp.contains(&source.0.to_string()))
+                .map(|(&pk, _)| PublicKey::new(pk))
+                .collect();
+
+            if !keys_to_sign.is_empty() {
+                for pk in keys_to_sign {
+                    input.partial_sigs.insert(pk, dummy_ecdsa);
+                }
+                return Ok(());
+            }
+
+            // Taproot KeyPath
+            if !spb.is_tr_script {
+                if let Some(internal_key) = input.tap_internal_key {
+                    let matches = input
+                        .tap_key_origins
+                        .get(&internal_key)
+                        .map(|(_, (mfp, _))| available_mfp.contains(&mfp.to_string()))
+                        .ok_or(WalletError::UnsupportedDescriptor)?;
+
+                    if matches {
+                        input.tap_key_sig = Some(dummy_schnorr);
+                        return Ok(());
+                    }
+                }
+            }
+
+            // Taproot ScriptPath, only the leaf the path comes from is signed
+            for leaf_hash in leaves {
+                for (x_only_pk, (hashes, (mfp, _))) in &input.tap_key_origins {
+                    if hashes.contains(leaf_hash) && available_mfp.contains(&mfp.to_string()) {
+                        input
+                            .tap_script_sigs
+                            .insert((*x_only_pk, *leaf_hash), dummy_schnorr);
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    fn cross_check(descriptor: &str) -> Result<()> {
+        let descriptor: Descriptor<DescriptorPublicKey> = descriptor.parse()?;
+        let network = if descriptor.to_string().contains("tpub") {
+            Network::Testnet
+        } else {
+            Network::Bitcoin
+        };
+
+        let spbs = SpendPath::builders_from_descriptor(&descriptor, network)?;
+        let mut analytical = spbs.clone();
+        let mut measured = spbs;
+        WeightCalc::calc_tx_weight(&descriptor, &mut analytical)?;
+        let wallet = create_params(&descriptor)
+            .network(network)
+            .create_wallet_no_persist()?;
+        FakeTxWeightCalc::calc_tx_weight(&wallet, &mut measured)?;
+
+        assert!(!analytical.is_empty());
+        for (a, m) in analytical.iter().zip(&measured) {
+            assert_eq!(a.wu_base, m.wu_base, "wu_base of {descriptor}");
+            assert_eq!(a.wu_in, m.wu_in, "wu_in of {descriptor}");
+            assert_eq!(a.wu_out, m.wu_out, "wu_out of {descriptor}");
+            assert_eq!(a.tr_depth, m.tr_depth, "tr_depth of {descriptor}");
+            assert_eq!(a.signer_sets, m.signer_sets, "signer sets of {descriptor}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_weights_match_fake_transactions() -> Result<()> {
+        let a = format!("[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*");
+        let b = format!("[bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*");
+        let c = format!("[cccccccc/48h/0h/0h/2h]{XPUB_B}/0/<0;1>/*");
+        let d = format!("[dddddddd/48h/0h/0h/2h]{XPUB_B}/1/<0;1>/*");
+        let raw = "02c6047f9441ed7d6d3034a46e1e3ec1e8e84c6b8c9b3c1c0c6c8c27c1e6a5a1bc";
+        let nums = "50929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0";
+        let digest = sha256::Hash::hash(&[0u8; 32]);
+
+        for descriptor in [
+            format!("pkh({a})"),
+            format!("wpkh({a})"),
+            format!("sh(wpkh({a}))"),
+            format!("sh(multi(2,{a},{b},{c}))"),
+            format!("sh(wsh(sortedmulti(2,{a},{b},{c})))"),
+            format!("wsh(multi(2,{a},{b},{raw}))"),
+            format!("wsh(thresh(2,pk({a}),s:pk({b}),sln:older(4320)))"),
+            format!("wsh(andor(pk({a}),sha256({digest}),and_v(v:pk({b}),older(144))))"),
+            format!("wsh(and_v(v:pk({a}),multi(2,{b},{c},{d})))"),
+            format!("wsh(or_d(pk({a}),and_v(v:pk({b}),after(800000))))"),
+            format!("tr({a})"),
+            format!("tr({a},{{pk({b}),{{and_v(v:pk({c}),older(52560)),multi_a(2,{b},{c},{d})}}}})"),
+            format!("tr({nums},thresh(2,pk({a}),s:pk({b}),a:multi_a(2,{c},{d})))"),
+            format!("tr({nums},{{pk({a}),pk({b})}})"),
+            format!("tr({a},thresh(2,pk({b}),s:pk({c}),sln:older(4320)))"),
+        ] {
+            cross_check(&descriptor)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fake_transactions_on_every_network() -> Result<()> {
+        // The fake wallet's chain must start at the genesis block of its network
+        let xpub = format!("wpkh([c449c5c5/84h/0h/0h]{XPUB_A}/<0;1>/*)");
+        let tpub = format!("wpkh([73c5da0a/84h/1h/0h]{TPUB_A}/<0;1>/*)");
+
+        for (descriptor, network) in [
+            (&xpub, Network::Bitcoin),
+            (&tpub, Network::Testnet),
+            (&tpub, Network::Signet),
+            (&tpub, Network::Regtest),
+        ] {
+            let descriptor: Descriptor<DescriptorPublicKey> = descriptor.parse()?;
+            let mut analytical = SpendPath::builders_from_descriptor(&descriptor, network)?;
+            let mut measured = analytical.clone();
+            WeightCalc::calc_tx_weight(&descriptor, &mut analytical)?;
+            let wallet = create_params(&descriptor)
+                .network(network)
+                .create_wallet_no_persist()?;
+            FakeTxWeightCalc::calc_tx_weight(&wallet, &mut measured)?;
+            assert_eq!(measured[0].wu_in, analytical[0].wu_in, "{network}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_fees() -> Result<()> {
+        let descriptor = format!("wpkh([c449c5c5/84h/0h/0h]{XPUB_A}/<0;1>/*)").parse()?;
+        let spend_path =
+            SpendPath::extract_from_descriptor(&descriptor, Network::Bitcoin)?.remove(0);
+        assert_eq!(spend_path.estimate_tx_wu(1, 1), 438);
+
+        let estimates = spend_path.estimate_fees(1, &[], true, &[1.0, 5.0, 20.5, 1.1])?;
+        let fees: Vec<u64> = estimates.iter().map(|e| e.fee_sat).collect();
+        // 438 WU round up to 110 vB
+        assert!(estimates.iter().all(|e| e.vbytes == 110));
+        assert_eq!(fees, vec![110, 550, 2255, 121]);
+
+        let destinations = [WalletType::P2WPKH, WalletType::P2WPKH];
+        let estimates = spend_path.estimate_fees(2, &destinations, true, &[2.0])?;
+        assert_eq!(estimates[0].vbytes, 240);
+        assert_eq!(estimates[0].fee_sat, 480);
+
+        assert!(spend_path.estimate_fees(0, &[], true, &[1.0]).is_err());
+        assert!(spend_path.estimate_fees(1, &[], false, &[1.0]).is_err());
+        assert!(spend_path.estimate_fees(1, &[], true, &[-1.0]).is_err());
+        assert!(spend_path.estimate_fees(1, &[], true, &[f64::NAN]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_destination_output_weights() -> Result<()> {
+        let descriptor = format!("wpkh([c449c5c5/84h/0h/0h]{XPUB_A}/<0;1>/*)").parse()?;
+        let spend_path =
+            SpendPath::extract_from_descriptor(&descriptor, Network::Bitcoin)?.remove(0);
+
+        // Change is the wallet's own P2WPKH output
+        assert_eq!(spend_path.estimate_tx_wu_to(1, &[], true)?, 438);
+        assert_eq!(
+            spend_path.estimate_tx_wu_to(1, &[WalletType::P2WPKH], false)?,
+            438
+        );
+        // P2TR destination (43 bytes) and change
+        assert_eq!(
+            spend_path.estimate_tx_wu_to(1, &[WalletType::P2TR], true)?,
+            438 + 172
+        );
+        // Legacy and nested destinations
+        assert_eq!(
+            spend_path.estimate_tx_wu_to(1, &[WalletType::P2PKH, WalletType::P2SH_WPKH], false)?,
+            314 + 136 + 128
+        );
+        // 253 outputs need a 3 byte output count
+        let destinations = vec![WalletType::P2WSH; 253];
+        assert_eq!(
+            spend_path.estimate_tx_wu_to(1, &destinations, false)?,
+            314 + 8 + 253 * 172
+        );
+
+        assert!(spend_path
+            .estimate_tx_wu_to(1, &[WalletType::Unknown], true)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_signature_size_range() -> Result<()> {
+        let a = format!("[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*");
+        let b = format!("[bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*");
+        let spend_path = |descriptor: String| -> Result<SpendPath> {
+            Ok(
+                SpendPath::extract_from_descriptor(&descriptor.parse()?, Network::Bitcoin)?
+                    .remove(0),
+            )
+        };
+
+        // Segwit v0: one witness byte per ECDSA signature, no Schnorr variance
+        let wpkh = spend_path(format!("wpkh({a})"))?;
+        assert_eq!(wpkh.wu_in, 272);
+        assert_eq!((wpkh.wu_in_min, wpkh.wu_in_max), (271, 272));
+        let wsh = spend_path(format!("wsh(multi(2,{a},{b}))"))?;
+        assert_eq!((wsh.wu_in_min, wsh.wu_in_max), (wsh.wu_in - 2, wsh.wu_in));
+
+        // Legacy: signatures are in the scriptSig, so a byte is 4 WU
+        let pkh = spend_path(format!("pkh({a})"))?;
+        assert_eq!((pkh.wu_in_min, pkh.wu_in_max), (pkh.wu_in - 4, pkh.wu_in));
+
+        // Taproot: only the sighash byte varies
+        let tr = spend_path(format!("tr({a})"))?;
+        assert_eq!(tr.wu_in, 230);
+        assert_eq!((tr.wu_in_min, tr.wu_in_max), (230, 231));
+
+        // Fee budgets use the largest signatures: 4 inputs add 1 vB
+        let estimate = tr.estimate_fees(4, &[], true, &[10.0])?.remove(0);
+        assert_eq!(estimate.vbytes_max, estimate.vbytes + 1);
+        assert_eq!(estimate.fee_sat_max, estimate.fee_sat + 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dust() -> Result<()> {
+        let a = format!("[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*");
+        let descriptor = format!("wpkh({a})").parse()?;
+        let spend_path =
+            SpendPath::extract_from_descriptor(&descriptor, Network::Bitcoin)?.remove(0);
+
+        // 272 WU input: 68 vB
+        assert_eq!(spend_path.economic_dust(1.0)?, 68);
+        assert_eq!(spend_path.economic_dust(100.0)?, 6800);
+        assert_eq!(spend_path.economic_dust(1.5)?, 102);
+        assert_eq!(spend_path.economic_dust(0.0)?, 0);
+        assert!(spend_path.economic_dust(-1.0).is_err());
+
+        // Bitcoin Core dust limits
+        assert_eq!(WalletType::P2PKH.dust_limit(), Some(546));
+        assert_eq!(WalletType::P2SH.dust_limit(), Some(540));
+        assert_eq!(WalletType::P2SH_WSH.dust_limit(), Some(540));
+        assert_eq!(WalletType::P2WPKH.dust_limit(), Some(294));
+        assert_eq!(WalletType::P2WSH.dust_limit(), Some(330));
+        assert_eq!(WalletType::P2TR.dust_limit(), Some(330));
+        assert_eq!(WalletType::Unknown.dust_limit(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_plan_consolidation() -> Result<()> {
+        let a = format!("[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*");
+        let descriptor = format!("wpkh({a})").parse()?;
+        let spend_path =
+            SpendPath::extract_from_descriptor(&descriptor, Network::Bitcoin)?.remove(0);
+
+        // (400000 - 42 - 124 - 4) / 272: the input count takes 3 bytes
+        let plan = spend_path.plan_consolidation(10, &[1.0])?;
+        assert_eq!(plan.max_inputs_per_tx, 1469);
+        assert!(spend_path.estimate_tx_wu_to(1469, &[], true)? <= 400_000);
+        assert!(spend_path.estimate_tx_wu_to(1470, &[], true)? > 400_000);
+        assert_eq!(plan.transactions, 1);
+        assert_eq!(plan.fees, spend_path.estimate_fees(10, &[], true, &[1.0])?);
+
+        // 1469 + 1469 + 62 inputs
+        let plan = spend_path.plan_consolidation(3000, &[2.0, 10.0])?;
+        assert_eq!(plan.transactions, 3);
+        let full = spend_path.estimate_fees(1469, &[], true, &[2.0, 10.0])?;
+        let last = spend_path.estimate_fees(62, &[], true, &[2.0, 10.0])?;
+        for i in 0..2 {
+            assert_eq!(plan.fees[i].vbytes, 2 * full[i].vbytes + last[i].vbytes);
+            assert_eq!(plan.fees[i].fee_sat, 2 * full[i].fee_sat + last[i].fee_sat);
+        }
+
+        assert!(spend_path.plan_consolidation(0, &[1.0]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expected_cost() -> Result<()> {
+        let a = format!("[aaaaaaaa/48h/0h/0h/2h]{XPUB_A}/0/<0;1>/*");
+        let b = format!("[bbbbbbbb/48h/0h/0h/2h]{XPUB_A}/1/<0;1>/*");
+        let c = format!("[cccccccc/48h/0h/0h/2h]{XPUB_B}/0/<0;1>/*");
+        let d = format!("[dddddddd/48h/0h/0h/2h]{XPUB_B}/1/<0;1>/*");
+        let descriptor =
+            format!("wsh(or_d(pk({d}),and_v(v:multi(2,{a},{b},{c}),older(52560))))").parse()?;
+        let spend_paths = SpendPath::extract_from_descriptor(&descriptor, Network::Bitcoin)?;
+        let (primary, recovery) = (&spend_paths[0], &spend_paths[1]);
+        let fee_rates = [1.0, 20.0];
+
+        let primary_fees = primary.estimate_fees(1, &[WalletType::P2WPKH], true, &fee_rates)?;
+        let recovery_fees = recovery.estimate_fees(1, &[WalletType::P2WPKH], true, &fee_rates)?;
+
+        // Likelihoods are normalized: 3 to 1 is 75% and 25%
+        let costs = SpendPath::expected_cost(
+            &[(primary, 3.0), (recovery, 1.0)],
+            1,
+            &[WalletType::P2WPKH],
+            true,
+            &fee_rates,
+        )?;
+        assert_eq!(costs.len(), 2);
+        for ((cost, p), r) in costs.iter().zip(&primary_fees).zip(&recovery_fees) {
+            let expected = 0.75 * p.fee_sat as f64 + 0.25 * r.fee_sat as f64;
+            assert!((cost.fee_sat - expected).abs() < 1e-9);
+            assert!(cost.vbytes > p.vbytes as f64 && cost.vbytes < r.vbytes as f64);
+            assert!(cost.fee_sat_max >= cost.fee_sat);
+        }
+
+        // A path that is never used doesn't count
+        let costs = SpendPath::expected_cost(
+            &[(primary, 1.0), (recovery, 0.0)],
+            1,
+            &[WalletType::P2WPKH],
+            true,
+            &fee_rates,
+        )?;
+        assert_eq!(costs[1].fee_sat, primary_fees[1].fee_sat as f64);
+
+        let invalid = |likelihoods: &[(&SpendPath, f64)]| {
+            SpendPath::expected_cost(likelihoods, 1, &[], true, &fee_rates)
+                .err()
+                .and_then(|e| e.downcast::<WalletError>().ok())
+        };
+        assert!(matches!(
+            invalid(&[(primary, 0.0), (recovery, 0.0)]),
+            Some(WalletError::InvalidLikelihood)
+        ));
+        assert!(matches!(
+            invalid(&[(primary, -1.0), (recovery, 2.0)]),
+            Some(WalletError::InvalidLikelihood)
+        ));
+
+        Ok(())
+    }
 }
@@ -7,10 +7,11 @@ use crate::core::spend_path::SpendPath;
 
 use anyhow::Result;
 use bdk_wallet::bitcoin::Network;
+use bdk_wallet::keys::DescriptorPublicKey;
 use bdk_wallet::miniscript::descriptor::ShInner;
 use bdk_wallet::miniscript::Descriptor;
 use bdk_wallet::rusqlite::Connection;
-use bdk_wallet::{KeychainKind, PersistedWallet, Wallet};
+use bdk_wallet::{CreateParams, KeychainKind, PersistedWallet, Wallet};
 
 #[derive(Debug)]
 pub struct CoreWallet {
@@ -40,9 +41,55 @@ pub enum WalletType {
     Unknown,
 }
 
+impl WalletType {
+    /// Size of the output script of this type
+    ///
+    /// Nested segwit outputs are P2SH. `Unknown` has no fixed size.
+    pub fn script_pubkey_len(&self) -> Option<usize> {
+        match self {
+            WalletType::P2PKH => Some(25),
+            WalletType::P2SH | WalletType::P2SH_WPKH | WalletType::P2SH_WSH => Some(23),
+            WalletType::P2WPKH => Some(22),
+            WalletType::P2WSH | WalletType::P2TR => Some(34),
+            WalletType::Unknown => None,
+        }
+    }
+
+    /// Standard dust limit of an output of this type, in sats
+    ///
+    /// Bitcoin Core relays outputs worth at least the cost of creating and
+    /// spending them at the 3 sat/vB dust relay fee, assuming a 148 byte
+    /// legacy or 67 vbyte segwit input. Outputs below it are non-standard.
+    pub fn dust_limit(&self) -> Option<u64> {
+        let spend_len = match self {
+            WalletType::P2WPKH | WalletType::P2WSH | WalletType::P2TR => 67,
+            // Nested segwit outputs are P2SH, not witness programs
+            _ => 148,
+        };
+        let output_len = 8 + 1 + self.script_pubkey_len()? as u64;
+        Some((output_len + spend_len) * DUST_RELAY_FEE)
+    }
+}
+
+/// Dust relay fee of Bitcoin Core, in sat/vB
+const DUST_RELAY_FEE: u64 = 3;
+
+/// Wallet creation parameters for a descriptor
+///
+/// A multipath descriptor (`<0;1>`) gets separate receive and change keychains,
+/// any other descriptor is used for both.
+pub fn create_params(descriptor: &Descriptor<DescriptorPublicKey>) -> CreateParams {
+    if descriptor.is_multipath() {
+        Wallet::create_from_two_path_descriptor(descriptor.to_string())
+    } else {
+        Wallet::create_single(descriptor.to_string())
+    }
+}
+
 impl CoreWallet {
     pub fn new_temporal(network: Network, descriptor: &str) -> Result<CoreWallet> {
-        let wallet = Wallet::create_from_two_path_descriptor(descriptor.to_owned())
+        let parser = DescriptorParser::parse(descriptor)?;
+        let wallet = create_params(parser.descriptor()?)
             .network(network)
             .create_wallet_no_persist()?;
 
@@ -57,7 +104,8 @@ impl CoreWallet {
     pub fn new_persisted(network: Network, descriptor: &str) -> Result<CoreWallet> {
         let mut mem = Connection::open("")?;
 
-        let wallet = Wallet::create_from_two_path_descriptor(descriptor.to_owned())
+        let parser = DescriptorParser::parse(descriptor)?;
+        let wallet = create_params(parser.descriptor()?)
             .network(network)
             .create_wallet(&mut mem)?;
 
@@ -70,10 +118,7 @@ impl CoreWallet {
     }
 
     pub fn network_from_descriptor(descriptor: &str) -> Result<Network> {
-        // NEW: Use DescriptorParser for network detection
-        // This eliminates up to 5 wallet creations (one per network variant)
-        // For mainnet descriptors: 0 wallets created (uses xpub prefix)
-        // For testnet descriptors: 1-4 wallets created (still better than 5)
+        // Decided from the key version bytes, without creating wallets
         let parser = DescriptorParser::parse(descriptor)?;
         parser.detect_network()
     }
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1693999108;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 57443799;
 
 // Section: executor
 
@@ -69,11 +69,87 @@ fn wire__crate__api__analyzer__analyze_descriptor_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_descriptor = <String>::sse_decode(&mut deserializer);
+            let api_change_descriptor = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::analyzer::analyze_descriptor(api_descriptor)?;
+                        let output_ok = crate::api::analyzer::analyze_descriptor(
+                            api_descriptor,
+                            api_change_descriptor,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__analyze_descriptors_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "analyze_descriptors",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_descriptors = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::analyze_descriptors(api_descriptors)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__analyze_dust_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "analyze_dust",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            let api_fee_rates = <Vec<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::analyzer::analyze_dust(api_descriptor, api_fee_rates)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -392,6 +468,46 @@ fn wire__crate__api__analyzer__build_descriptor_impl(
         },
     )
 }
+fn wire__crate__api__analyzer__build_taproot_descriptor_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "build_taproot_descriptor",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_keys = <Vec<crate::api::model::APIPubKey>>::sse_decode(&mut deserializer);
+            let api_spend_paths =
+                <Vec<crate::api::model::APISpendPathDef>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::build_taproot_descriptor(
+                            api_keys,
+                            api_spend_paths,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__analyzer__calculate_rustid_from_timelocks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -479,6 +595,124 @@ fn wire__crate__api__analyzer__calculate_spend_path_id_impl(
         },
     )
 }
+fn wire__crate__api__analyzer__clear_analysis_cache_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_analysis_cache",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::analyzer::clear_analysis_cache();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__compare_script_types_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "compare_script_types",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_keys = <Vec<crate::api::model::APIPubKey>>::sse_decode(&mut deserializer);
+            let api_spend_paths =
+                <Vec<crate::api::model::APISpendPathDef>>::sse_decode(&mut deserializer);
+            let api_inputs = <u32>::sse_decode(&mut deserializer);
+            let api_destinations =
+                <Vec<crate::api::model::APIWalletType>>::sse_decode(&mut deserializer);
+            let api_change = <bool>::sse_decode(&mut deserializer);
+            let api_fee_rates = <Vec<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::compare_script_types(
+                            api_keys,
+                            api_spend_paths,
+                            api_inputs,
+                            api_destinations,
+                            api_change,
+                            api_fee_rates,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__create_bsms_token_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_bsms_token",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_extended = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::create_bsms_token(api_extended)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__analyzer__decode_legacy_abs_timelock_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -549,7 +783,7 @@ fn wire__crate__api__analyzer__decode_legacy_rel_timelock_impl(
         },
     )
 }
-fn wire__crate__api__analyzer__init_app_impl(
+fn wire__crate__api__analyzer__decrypt_bsms_payload_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -557,7 +791,7 @@ fn wire__crate__api__analyzer__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "decrypt_bsms_payload",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -571,19 +805,22 @@ fn wire__crate__api__analyzer__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_payload = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::analyzer::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::analyzer::decrypt_bsms_payload(api_token, api_payload)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__analyzer__validate_key_impl(
+fn wire__crate__api__analyzer__derive_addresses_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -591,7 +828,7 @@ fn wire__crate__api__analyzer__validate_key_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "validate_key",
+            debug_name: "derive_addresses",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -605,19 +842,19 @@ fn wire__crate__api__analyzer__validate_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mfp = <String>::sse_decode(&mut deserializer);
-            let api_derivation_path = <String>::sse_decode(&mut deserializer);
-            let api_xpub = <String>::sse_decode(&mut deserializer);
-            let api_network = <crate::api::model::APINetwork>::sse_decode(&mut deserializer);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            let api_keychain = <crate::api::model::APIKeychainKind>::sse_decode(&mut deserializer);
+            let api_start = <u32>::sse_decode(&mut deserializer);
+            let api_count = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::analyzer::validate_key(
-                            api_mfp,
-                            api_derivation_path,
-                            api_xpub,
-                            api_network,
+                        let output_ok = crate::api::analyzer::derive_addresses(
+                            api_descriptor,
+                            api_keychain,
+                            api_start,
+                            api_count,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -626,348 +863,1575 @@ fn wire__crate__api__analyzer__validate_key_impl(
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpendPath>
-);
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for SpendPath {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpendPath>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
-}
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpendPath>>
+fn wire__crate__api__analyzer__encrypt_bsms_payload_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encrypt_bsms_payload",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_data = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::analyzer::encrypt_bsms_payload(api_token, api_data)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__estimate_expected_cost_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "estimate_expected_cost",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            let api_likelihoods =
+                <Vec<crate::api::model::APISpendPathLikelihood>>::sse_decode(&mut deserializer);
+            let api_inputs = <u32>::sse_decode(&mut deserializer);
+            let api_destinations =
+                <Vec<crate::api::model::APIWalletType>>::sse_decode(&mut deserializer);
+            let api_change = <bool>::sse_decode(&mut deserializer);
+            let api_fee_rates = <Vec<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::estimate_expected_cost(
+                            api_descriptor,
+                            api_likelihoods,
+                            api_inputs,
+                            api_destinations,
+                            api_change,
+                            api_fee_rates,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__estimate_spend_path_fees_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "estimate_spend_path_fees",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            let api_spend_path_id = <u32>::sse_decode(&mut deserializer);
+            let api_inputs = <u32>::sse_decode(&mut deserializer);
+            let api_destinations =
+                <Vec<crate::api::model::APIWalletType>>::sse_decode(&mut deserializer);
+            let api_change = <bool>::sse_decode(&mut deserializer);
+            let api_fee_rates = <Vec<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::estimate_spend_path_fees(
+                            api_descriptor,
+                            api_spend_path_id,
+                            api_inputs,
+                            api_destinations,
+                            api_change,
+                            api_fee_rates,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__export_bsms_record_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_bsms_record",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::export_bsms_record(api_descriptor)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__export_caravan_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_caravan_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            let api_network = <crate::api::model::APINetwork>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::export_caravan_wallet(
+                            api_name,
+                            api_descriptor,
+                            api_network,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__export_coldcard_multisig_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_coldcard_multisig",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::export_coldcard_multisig(
+                            api_name,
+                            api_descriptor,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__export_electrum_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_electrum_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::analyzer::export_electrum_wallet(api_descriptor)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__find_address_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_address",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            let api_gap = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::find_address(
+                            api_descriptor,
+                            api_address,
+                            api_gap,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__import_bsms_key_record_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_bsms_key_record",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_record = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::analyzer::import_bsms_key_record(api_token, api_record)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__import_bsms_record_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_bsms_record",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_record = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::import_bsms_record(api_record)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__import_caravan_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_caravan_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::import_caravan_wallet(api_config)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__import_coldcard_multisig_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_coldcard_multisig",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::import_coldcard_multisig(api_file)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__import_electrum_wallet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_electrum_wallet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::import_electrum_wallet(api_file)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::analyzer::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__plan_consolidation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "plan_consolidation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_descriptor = <String>::sse_decode(&mut deserializer);
+            let api_utxos = <u32>::sse_decode(&mut deserializer);
+            let api_fee_rates = <Vec<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::plan_consolidation(
+                            api_descriptor,
+                            api_utxos,
+                            api_fee_rates,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__analyzer__validate_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mfp = <String>::sse_decode(&mut deserializer);
+            let api_derivation_path = <String>::sse_decode(&mut deserializer);
+            let api_xpub = <String>::sse_decode(&mut deserializer);
+            let api_network = <crate::api::model::APINetwork>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::analyzer::validate_key(
+                            api_mfp,
+                            api_derivation_path,
+                            api_xpub,
+                            api_network,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpendPath>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for SpendPath {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpendPath>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpendPath>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::model::APIAbsoluteTimelock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timelockType =
+            <crate::api::model::APIAbsoluteTimelockType>::sse_decode(deserializer);
+        let mut var_value = <u32>::sse_decode(deserializer);
+        return crate::api::model::APIAbsoluteTimelock {
+            timelock_type: var_timelockType,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIAbsoluteTimelockType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::model::APIAbsoluteTimelockType::Blocks,
+            1 => crate::api::model::APIAbsoluteTimelockType::Timestamp,
+            _ => unreachable!("Invalid variant for APIAbsoluteTimelockType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIAddress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_keychain = <crate::api::model::APIKeychainKind>::sse_decode(deserializer);
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_scriptPubkey = <String>::sse_decode(deserializer);
+        return crate::api::model::APIAddress {
+            keychain: var_keychain,
+            index: var_index,
+            address: var_address,
+            script_pubkey: var_scriptPubkey,
+        };
+    }
+}
+
+impl SseDecode for crate::api::analyzer::APIAnalysisResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_descriptor = <String>::sse_decode(deserializer);
+        let mut var_network = <crate::api::model::APINetwork>::sse_decode(deserializer);
+        let mut var_walletType = <crate::api::model::APIWalletType>::sse_decode(deserializer);
+        let mut var_keys = <Vec<crate::api::model::APIPubKey>>::sse_decode(deserializer);
+        let mut var_spendPaths = <Vec<crate::api::model::APISpendPath>>::sse_decode(deserializer);
+        let mut var_spendability = <crate::api::model::APISpendability>::sse_decode(deserializer);
+        return crate::api::analyzer::APIAnalysisResult {
+            descriptor: var_descriptor,
+            network: var_network,
+            wallet_type: var_walletType,
+            keys: var_keys,
+            spend_paths: var_spendPaths,
+            spendability: var_spendability,
+        };
+    }
+}
+
+impl SseDecode for crate::api::analyzer::APIBatchAnalysis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_descriptor = <String>::sse_decode(deserializer);
+        let mut var_result =
+            <Option<crate::api::analyzer::APIAnalysisResult>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::analyzer::APIBatchAnalysis {
+            descriptor: var_descriptor,
+            result: var_result,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIConsolidationPlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_spendPathId = <u32>::sse_decode(deserializer);
+        let mut var_maxInputsPerTx = <u32>::sse_decode(deserializer);
+        let mut var_transactions = <u32>::sse_decode(deserializer);
+        let mut var_fees = <Vec<crate::api::model::APIFeeEstimate>>::sse_decode(deserializer);
+        return crate::api::model::APIConsolidationPlan {
+            spend_path_id: var_spendPathId,
+            max_inputs_per_tx: var_maxInputsPerTx,
+            transactions: var_transactions,
+            fees: var_fees,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIDustAnalysis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dustLimit = <Option<u64>>::sse_decode(deserializer);
+        let mut var_spendPaths =
+            <Vec<crate::api::model::APISpendPathDust>>::sse_decode(deserializer);
+        return crate::api::model::APIDustAnalysis {
+            dust_limit: var_dustLimit,
+            spend_paths: var_spendPaths,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIExpectedCost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_feeRate = <f64>::sse_decode(deserializer);
+        let mut var_vbytes = <f64>::sse_decode(deserializer);
+        let mut var_feeSat = <f64>::sse_decode(deserializer);
+        let mut var_vbytesMax = <f64>::sse_decode(deserializer);
+        let mut var_feeSatMax = <f64>::sse_decode(deserializer);
+        return crate::api::model::APIExpectedCost {
+            fee_rate: var_feeRate,
+            vbytes: var_vbytes,
+            fee_sat: var_feeSat,
+            vbytes_max: var_vbytesMax,
+            fee_sat_max: var_feeSatMax,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIFeeEstimate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_feeRate = <f64>::sse_decode(deserializer);
+        let mut var_vbytes = <u64>::sse_decode(deserializer);
+        let mut var_feeSat = <u64>::sse_decode(deserializer);
+        let mut var_vbytesMax = <u64>::sse_decode(deserializer);
+        let mut var_feeSatMax = <u64>::sse_decode(deserializer);
+        return crate::api::model::APIFeeEstimate {
+            fee_rate: var_feeRate,
+            vbytes: var_vbytes,
+            fee_sat: var_feeSat,
+            vbytes_max: var_vbytesMax,
+            fee_sat_max: var_feeSatMax,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIHashLock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hashType = <crate::api::model::APIHashType>::sse_decode(deserializer);
+        let mut var_digest = <String>::sse_decode(deserializer);
+        return crate::api::model::APIHashLock {
+            hash_type: var_hashType,
+            digest: var_digest,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIHashType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::model::APIHashType::Sha256,
+            1 => crate::api::model::APIHashType::Hash256,
+            2 => crate::api::model::APIHashType::Ripemd160,
+            3 => crate::api::model::APIHashType::Hash160,
+            _ => unreachable!("Invalid variant for APIHashType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::analyzer::APIImportedWallet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_analysis = <crate::api::analyzer::APIAnalysisResult>::sse_decode(deserializer);
+        return crate::api::analyzer::APIImportedWallet {
+            name: var_name,
+            analysis: var_analysis,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIKeychainKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::model::APIKeychainKind::External,
+            1 => crate::api::model::APIKeychainKind::Internal,
+            _ => unreachable!("Invalid variant for APIKeychainKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APINetwork {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::model::APINetwork::Bitcoin,
+            1 => crate::api::model::APINetwork::Testnet,
+            2 => crate::api::model::APINetwork::Testnet4,
+            3 => crate::api::model::APINetwork::Signet,
+            4 => crate::api::model::APINetwork::Regtest,
+            _ => unreachable!("Invalid variant for APINetwork: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIPolicyPath {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_policyId = <String>::sse_decode(deserializer);
+        let mut var_path = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::model::APIPolicyPath {
+            policy_id: var_policyId,
+            path: var_path,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIPubKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mfp = <String>::sse_decode(deserializer);
+        let mut var_derivationPath = <String>::sse_decode(deserializer);
+        let mut var_xpub = <String>::sse_decode(deserializer);
+        let mut var_syntheticMfp = <bool>::sse_decode(deserializer);
+        return crate::api::model::APIPubKey {
+            mfp: var_mfp,
+            derivation_path: var_derivationPath,
+            xpub: var_xpub,
+            synthetic_mfp: var_syntheticMfp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIRelativeTimelock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timelockType =
+            <crate::api::model::APIRelativeTimelockType>::sse_decode(deserializer);
+        let mut var_value = <u32>::sse_decode(deserializer);
+        return crate::api::model::APIRelativeTimelock {
+            timelock_type: var_timelockType,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIRelativeTimelockType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::model::APIRelativeTimelockType::Blocks,
+            1 => crate::api::model::APIRelativeTimelockType::Time,
+            _ => unreachable!("Invalid variant for APIRelativeTimelockType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIScriptTypeComparison {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_walletType = <crate::api::model::APIWalletType>::sse_decode(deserializer);
+        let mut var_supported = <bool>::sse_decode(deserializer);
+        let mut var_unsupportedReason = <Option<String>>::sse_decode(deserializer);
+        let mut var_descriptor = <Option<String>>::sse_decode(deserializer);
+        let mut var_spendPaths =
+            <Vec<crate::api::model::APISpendPathCost>>::sse_decode(deserializer);
+        return crate::api::model::APIScriptTypeComparison {
+            wallet_type: var_walletType,
+            supported: var_supported,
+            unsupported_reason: var_unsupportedReason,
+            descriptor: var_descriptor,
+            spend_paths: var_spendPaths,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APISignerGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_threshold = <u32>::sse_decode(deserializer);
+        let mut var_mfps = <Vec<String>>::sse_decode(deserializer);
+        let mut var_groups = <Vec<crate::api::model::APISignerGroup>>::sse_decode(deserializer);
+        return crate::api::model::APISignerGroup {
+            threshold: var_threshold,
+            mfps: var_mfps,
+            groups: var_groups,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APISignerSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mfps = <Vec<String>>::sse_decode(deserializer);
+        let mut var_wuIn = <u32>::sse_decode(deserializer);
+        return crate::api::model::APISignerSet {
+            mfps: var_mfps,
+            wu_in: var_wuIn,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APISpendPath {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_policyPath = <Vec<crate::api::model::APIPolicyPath>>::sse_decode(deserializer);
+        let mut var_threshold = <u32>::sse_decode(deserializer);
+        let mut var_mfps = <Vec<String>>::sse_decode(deserializer);
+        let mut var_signerGroups =
+            <Vec<crate::api::model::APISignerGroup>>::sse_decode(deserializer);
+        let mut var_relTimelock =
+            <crate::api::model::APIRelativeTimelock>::sse_decode(deserializer);
+        let mut var_absTimelock =
+            <crate::api::model::APIAbsoluteTimelock>::sse_decode(deserializer);
+        let mut var_relTimelocks =
+            <Vec<crate::api::model::APIRelativeTimelock>>::sse_decode(deserializer);
+        let mut var_absTimelocks =
+            <Vec<crate::api::model::APIAbsoluteTimelock>>::sse_decode(deserializer);
+        let mut var_timelockConflict = <bool>::sse_decode(deserializer);
+        let mut var_hashlocks = <Vec<crate::api::model::APIHashLock>>::sse_decode(deserializer);
+        let mut var_wuBase = <u32>::sse_decode(deserializer);
+        let mut var_wuIn = <u32>::sse_decode(deserializer);
+        let mut var_wuInMin = <u32>::sse_decode(deserializer);
+        let mut var_wuInMax = <u32>::sse_decode(deserializer);
+        let mut var_wuOut = <u32>::sse_decode(deserializer);
+        let mut var_signerSets = <Vec<crate::api::model::APISignerSet>>::sse_decode(deserializer);
+        let mut var_signerSetsTruncated = <bool>::sse_decode(deserializer);
+        let mut var_trDepth = <i32>::sse_decode(deserializer);
+        let mut var_vbSweep = <f32>::sse_decode(deserializer);
+        return crate::api::model::APISpendPath {
+            id: var_id,
+            policy_path: var_policyPath,
+            threshold: var_threshold,
+            mfps: var_mfps,
+            signer_groups: var_signerGroups,
+            rel_timelock: var_relTimelock,
+            abs_timelock: var_absTimelock,
+            rel_timelocks: var_relTimelocks,
+            abs_timelocks: var_absTimelocks,
+            timelock_conflict: var_timelockConflict,
+            hashlocks: var_hashlocks,
+            wu_base: var_wuBase,
+            wu_in: var_wuIn,
+            wu_in_min: var_wuInMin,
+            wu_in_max: var_wuInMax,
+            wu_out: var_wuOut,
+            signer_sets: var_signerSets,
+            signer_sets_truncated: var_signerSetsTruncated,
+            tr_depth: var_trDepth,
+            vb_sweep: var_vbSweep,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APISpendPathCost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_spendPathId = <u32>::sse_decode(deserializer);
+        let mut var_wuIn = <u32>::sse_decode(deserializer);
+        let mut var_wuInMax = <u32>::sse_decode(deserializer);
+        let mut var_fees = <Vec<crate::api::model::APIFeeEstimate>>::sse_decode(deserializer);
+        return crate::api::model::APISpendPathCost {
+            spend_path_id: var_spendPathId,
+            wu_in: var_wuIn,
+            wu_in_max: var_wuInMax,
+            fees: var_fees,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APISpendPathDef {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_threshold = <u32>::sse_decode(deserializer);
+        let mut var_mfps = <Vec<String>>::sse_decode(deserializer);
+        let mut var_relTimelock =
+            <crate::api::model::APIRelativeTimelock>::sse_decode(deserializer);
+        let mut var_absTimelock =
+            <crate::api::model::APIAbsoluteTimelock>::sse_decode(deserializer);
+        let mut var_isKeyPath = <bool>::sse_decode(deserializer);
+        let mut var_priority = <u32>::sse_decode(deserializer);
+        let mut var_probability = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::model::APISpendPathDef {
+            threshold: var_threshold,
+            mfps: var_mfps,
+            rel_timelock: var_relTimelock,
+            abs_timelock: var_absTimelock,
+            is_key_path: var_isKeyPath,
+            priority: var_priority,
+            probability: var_probability,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APISpendPathDust {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_spendPathId = <u32>::sse_decode(deserializer);
+        let mut var_economicDust = <Vec<u64>>::sse_decode(deserializer);
+        return crate::api::model::APISpendPathDust {
+            spend_path_id: var_spendPathId,
+            economic_dust: var_economicDust,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APISpendPathLikelihood {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_spendPathId = <u32>::sse_decode(deserializer);
+        let mut var_likelihood = <f64>::sse_decode(deserializer);
+        return crate::api::model::APISpendPathLikelihood {
+            spend_path_id: var_spendPathId,
+            likelihood: var_likelihood,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APISpendability {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::model::APISpendability::Known,
+            1 => crate::api::model::APISpendability::Unknown,
+            2 => crate::api::model::APISpendability::Unspendable,
+            _ => unreachable!("Invalid variant for APISpendability: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APITaprootBuild {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_descriptor = <String>::sse_decode(deserializer);
+        let mut var_spendPaths =
+            <Vec<crate::api::model::APITaprootPathCost>>::sse_decode(deserializer);
+        let mut var_expectedWuIn = <f64>::sse_decode(deserializer);
+        return crate::api::model::APITaprootBuild {
+            descriptor: var_descriptor,
+            spend_paths: var_spendPaths,
+            expected_wu_in: var_expectedWuIn,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APITaprootPathCost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_spendPathId = <u32>::sse_decode(deserializer);
+        let mut var_probability = <f64>::sse_decode(deserializer);
+        let mut var_trDepth = <i32>::sse_decode(deserializer);
+        let mut var_wuIn = <u32>::sse_decode(deserializer);
+        return crate::api::model::APITaprootPathCost {
+            spend_path_id: var_spendPathId,
+            probability: var_probability,
+            tr_depth: var_trDepth,
+            wu_in: var_wuIn,
+        };
+    }
+}
+
+impl SseDecode for crate::api::model::APIWalletType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::model::APIWalletType::P2PKH,
+            1 => crate::api::model::APIWalletType::P2WPKH,
+            2 => crate::api::model::APIWalletType::P2SH,
+            3 => crate::api::model::APIWalletType::P2WSH,
+            4 => crate::api::model::APIWalletType::P2TR,
+            5 => crate::api::model::APIWalletType::P2SH_WPKH,
+            6 => crate::api::model::APIWalletType::P2SH_WSH,
+            7 => crate::api::model::APIWalletType::Unknown,
+            _ => unreachable!("Invalid variant for APIWalletType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<SpendPath> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<SpendPath>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::model::APIAbsoluteTimelock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIAbsoluteTimelock>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::model::APIAddress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIAddress>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::analyzer::APIBatchAnalysis> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::analyzer::APIBatchAnalysis>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::model::APIConsolidationPlan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIConsolidationPlan>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for String {
+impl SseDecode for Vec<crate::api::model::APIExpectedCost> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIExpectedCost>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APIAbsoluteTimelock {
+impl SseDecode for Vec<crate::api::model::APIFeeEstimate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_timelockType =
-            <crate::api::model::APIAbsoluteTimelockType>::sse_decode(deserializer);
-        let mut var_value = <u32>::sse_decode(deserializer);
-        return crate::api::model::APIAbsoluteTimelock {
-            timelock_type: var_timelockType,
-            value: var_value,
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIFeeEstimate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APIAbsoluteTimelockType {
+impl SseDecode for Vec<crate::api::model::APIHashLock> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::model::APIAbsoluteTimelockType::Blocks,
-            1 => crate::api::model::APIAbsoluteTimelockType::Timestamp,
-            _ => unreachable!("Invalid variant for APIAbsoluteTimelockType: {}", inner),
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIHashLock>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::analyzer::APIAnalysisResult {
+impl SseDecode for Vec<crate::api::model::APIPolicyPath> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_descriptor = <String>::sse_decode(deserializer);
-        let mut var_network = <crate::api::model::APINetwork>::sse_decode(deserializer);
-        let mut var_walletType = <crate::api::model::APIWalletType>::sse_decode(deserializer);
-        let mut var_keys = <Vec<crate::api::model::APIPubKey>>::sse_decode(deserializer);
-        let mut var_spendPaths = <Vec<crate::api::model::APISpendPath>>::sse_decode(deserializer);
-        return crate::api::analyzer::APIAnalysisResult {
-            descriptor: var_descriptor,
-            network: var_network,
-            wallet_type: var_walletType,
-            keys: var_keys,
-            spend_paths: var_spendPaths,
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIPolicyPath>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APINetwork {
+impl SseDecode for Vec<crate::api::model::APIPubKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::model::APINetwork::Bitcoin,
-            1 => crate::api::model::APINetwork::Testnet,
-            2 => crate::api::model::APINetwork::Testnet4,
-            3 => crate::api::model::APINetwork::Signet,
-            4 => crate::api::model::APINetwork::Regtest,
-            _ => unreachable!("Invalid variant for APINetwork: {}", inner),
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIPubKey>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APIPolicyPath {
+impl SseDecode for Vec<crate::api::model::APIRelativeTimelock> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_policyId = <String>::sse_decode(deserializer);
-        let mut var_path = <Vec<u32>>::sse_decode(deserializer);
-        return crate::api::model::APIPolicyPath {
-            policy_id: var_policyId,
-            path: var_path,
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIRelativeTimelock>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APIPubKey {
+impl SseDecode for Vec<crate::api::model::APIScriptTypeComparison> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_mfp = <String>::sse_decode(deserializer);
-        let mut var_derivationPath = <String>::sse_decode(deserializer);
-        let mut var_xpub = <String>::sse_decode(deserializer);
-        return crate::api::model::APIPubKey {
-            mfp: var_mfp,
-            derivation_path: var_derivationPath,
-            xpub: var_xpub,
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APIScriptTypeComparison>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APIRelativeTimelock {
+impl SseDecode for Vec<crate::api::model::APISignerGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_timelockType =
-            <crate::api::model::APIRelativeTimelockType>::sse_decode(deserializer);
-        let mut var_value = <u32>::sse_decode(deserializer);
-        return crate::api::model::APIRelativeTimelock {
-            timelock_type: var_timelockType,
-            value: var_value,
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APISignerGroup>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APIRelativeTimelockType {
+impl SseDecode for Vec<crate::api::model::APISignerSet> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::model::APIRelativeTimelockType::Blocks,
-            1 => crate::api::model::APIRelativeTimelockType::Time,
-            _ => unreachable!("Invalid variant for APIRelativeTimelockType: {}", inner),
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APISignerSet>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APISpendPath {
+impl SseDecode for Vec<crate::api::model::APISpendPath> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <u32>::sse_decode(deserializer);
-        let mut var_policyPath = <Vec<crate::api::model::APIPolicyPath>>::sse_decode(deserializer);
-        let mut var_threshold = <u32>::sse_decode(deserializer);
-        let mut var_mfps = <Vec<String>>::sse_decode(deserializer);
-        let mut var_relTimelock =
-            <crate::api::model::APIRelativeTimelock>::sse_decode(deserializer);
-        let mut var_absTimelock =
-            <crate::api::model::APIAbsoluteTimelock>::sse_decode(deserializer);
-        let mut var_wuBase = <u32>::sse_decode(deserializer);
-        let mut var_wuIn = <u32>::sse_decode(deserializer);
-        let mut var_wuOut = <u32>::sse_decode(deserializer);
-        let mut var_trDepth = <i32>::sse_decode(deserializer);
-        let mut var_vbSweep = <f32>::sse_decode(deserializer);
-        return crate::api::model::APISpendPath {
-            id: var_id,
-            policy_path: var_policyPath,
-            threshold: var_threshold,
-            mfps: var_mfps,
-            rel_timelock: var_relTimelock,
-            abs_timelock: var_absTimelock,
-            wu_base: var_wuBase,
-            wu_in: var_wuIn,
-            wu_out: var_wuOut,
-            tr_depth: var_trDepth,
-            vb_sweep: var_vbSweep,
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APISpendPath>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APISpendPathDef {
+impl SseDecode for Vec<crate::api::model::APISpendPathCost> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_threshold = <u32>::sse_decode(deserializer);
-        let mut var_mfps = <Vec<String>>::sse_decode(deserializer);
-        let mut var_relTimelock =
-            <crate::api::model::APIRelativeTimelock>::sse_decode(deserializer);
-        let mut var_absTimelock =
-            <crate::api::model::APIAbsoluteTimelock>::sse_decode(deserializer);
-        let mut var_isKeyPath = <bool>::sse_decode(deserializer);
-        let mut var_priority = <u32>::sse_decode(deserializer);
-        return crate::api::model::APISpendPathDef {
-            threshold: var_threshold,
-            mfps: var_mfps,
-            rel_timelock: var_relTimelock,
-            abs_timelock: var_absTimelock,
-            is_key_path: var_isKeyPath,
-            priority: var_priority,
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APISpendPathCost>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::model::APIWalletType {
+impl SseDecode for Vec<crate::api::model::APISpendPathDef> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::model::APIWalletType::P2PKH,
-            1 => crate::api::model::APIWalletType::P2WPKH,
-            2 => crate::api::model::APIWalletType::P2SH,
-            3 => crate::api::model::APIWalletType::P2WSH,
-            4 => crate::api::model::APIWalletType::P2TR,
-            5 => crate::api::model::APIWalletType::P2SH_WPKH,
-            6 => crate::api::model::APIWalletType::P2SH_WSH,
-            7 => crate::api::model::APIWalletType::Unknown,
-            _ => unreachable!("Invalid variant for APIWalletType: {}", inner),
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APISpendPathDef>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for bool {
+impl SseDecode for Vec<crate::api::model::APISpendPathDust> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APISpendPathDust>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for f32 {
+impl SseDecode for Vec<crate::api::model::APISpendPathLikelihood> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APISpendPathLikelihood>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for i32 {
+impl SseDecode for Vec<crate::api::model::APITaprootPathCost> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::model::APITaprootPathCost>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for Vec<SpendPath> {
+impl SseDecode for Vec<crate::api::model::APIWalletType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<SpendPath>::sse_decode(deserializer));
+            ans_.push(<crate::api::model::APIWalletType>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for Vec<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+            ans_.push(<f64>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::model::APIPolicyPath> {
+impl SseDecode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::model::APIPolicyPath>::sse_decode(deserializer));
+            ans_.push(<u32>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::model::APIPubKey> {
+impl SseDecode for Vec<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::model::APIPubKey>::sse_decode(deserializer));
+            ans_.push(<u64>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::model::APISpendPath> {
+impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::model::APISpendPath>::sse_decode(deserializer));
+            ans_.push(<u8>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::model::APISpendPathDef> {
+impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::model::APISpendPathDef>::sse_decode(
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::model::APIAddress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::model::APIAddress>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::analyzer::APIAnalysisResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::analyzer::APIAnalysisResult>::sse_decode(
                 deserializer,
             ));
+        } else {
+            return None;
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u32> {
+impl SseDecode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u32>::sse_decode(deserializer));
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
         }
-        return ans_;
     }
 }
 
@@ -978,6 +2442,13 @@ impl SseDecode for u32 {
     }
 }
 
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1007,72 +2478,160 @@ fn pde_ffi_dispatcher_primary_impl(
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
         1 => wire__crate__api__analyzer__analyze_descriptor_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__model__api_absolute_timelock_from_consensus_impl(
+        2 => {
+            wire__crate__api__analyzer__analyze_descriptors_impl(port, ptr, rust_vec_len, data_len)
+        }
+        3 => wire__crate__api__analyzer__analyze_dust_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__model__api_absolute_timelock_from_consensus_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        3 => wire__crate__api__model__api_absolute_timelock_to_consensus_impl(
+        5 => wire__crate__api__model__api_absolute_timelock_to_consensus_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        4 => {
+        6 => {
             wire__crate__api__model__api_policy_path_default_impl(port, ptr, rust_vec_len, data_len)
         }
-        5 => wire__crate__api__model__api_policy_path_from_spendpath_impl(
+        7 => wire__crate__api__model__api_policy_path_from_spendpath_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__model__api_relative_timelock_from_consensus_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__model__api_relative_timelock_to_consensus_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__model__api_spend_path_from_sorted_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__analyzer__build_descriptor_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__analyzer__build_taproot_descriptor_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__analyzer__calculate_rustid_from_timelocks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__analyzer__calculate_spend_path_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => {
+            wire__crate__api__analyzer__clear_analysis_cache_impl(port, ptr, rust_vec_len, data_len)
+        }
+        16 => {
+            wire__crate__api__analyzer__compare_script_types_impl(port, ptr, rust_vec_len, data_len)
+        }
+        17 => wire__crate__api__analyzer__create_bsms_token_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__analyzer__decode_legacy_abs_timelock_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__analyzer__decode_legacy_rel_timelock_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => {
+            wire__crate__api__analyzer__decrypt_bsms_payload_impl(port, ptr, rust_vec_len, data_len)
+        }
+        21 => wire__crate__api__analyzer__derive_addresses_impl(port, ptr, rust_vec_len, data_len),
+        22 => {
+            wire__crate__api__analyzer__encrypt_bsms_payload_impl(port, ptr, rust_vec_len, data_len)
+        }
+        23 => wire__crate__api__analyzer__estimate_expected_cost_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__analyzer__estimate_spend_path_fees_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        6 => wire__crate__api__model__api_relative_timelock_from_consensus_impl(
+        25 => {
+            wire__crate__api__analyzer__export_bsms_record_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => wire__crate__api__analyzer__export_caravan_wallet_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        7 => wire__crate__api__model__api_relative_timelock_to_consensus_impl(
+        27 => wire__crate__api__analyzer__export_coldcard_multisig_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        8 => wire__crate__api__model__api_spend_path_from_sorted_impl(
+        28 => wire__crate__api__analyzer__export_electrum_wallet_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        9 => wire__crate__api__analyzer__build_descriptor_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__analyzer__calculate_rustid_from_timelocks_impl(
+        29 => wire__crate__api__analyzer__find_address_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__analyzer__import_bsms_key_record_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        11 => wire__crate__api__analyzer__calculate_spend_path_id_impl(
+        31 => {
+            wire__crate__api__analyzer__import_bsms_record_impl(port, ptr, rust_vec_len, data_len)
+        }
+        32 => wire__crate__api__analyzer__import_caravan_wallet_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        12 => wire__crate__api__analyzer__decode_legacy_abs_timelock_impl(
+        33 => wire__crate__api__analyzer__import_coldcard_multisig_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        13 => wire__crate__api__analyzer__decode_legacy_rel_timelock_impl(
+        34 => wire__crate__api__analyzer__import_electrum_wallet_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        14 => wire__crate__api__analyzer__init_app_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__analyzer__validate_key_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__analyzer__init_app_impl(port, ptr, rust_vec_len, data_len),
+        36 => {
+            wire__crate__api__analyzer__plan_consolidation_impl(port, ptr, rust_vec_len, data_len)
+        }
+        37 => wire__crate__api__analyzer__validate_key_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -1088,87 +2647,308 @@ fn pde_ffi_dispatcher_sync_impl(
         _ => unreachable!(),
     }
 }
-
-// Section: rust2dart
-
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SpendPath> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SpendPath> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SpendPath>> for SpendPath {
+    fn into_into_dart(self) -> FrbWrapper<SpendPath> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIAbsoluteTimelock {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.timelock_type.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APIAbsoluteTimelock
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIAbsoluteTimelock>
+    for crate::api::model::APIAbsoluteTimelock
+{
+    fn into_into_dart(self) -> crate::api::model::APIAbsoluteTimelock {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIAbsoluteTimelockType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Blocks => 0.into_dart(),
+            Self::Timestamp => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APIAbsoluteTimelockType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIAbsoluteTimelockType>
+    for crate::api::model::APIAbsoluteTimelockType
+{
+    fn into_into_dart(self) -> crate::api::model::APIAbsoluteTimelockType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIAddress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.keychain.into_into_dart().into_dart(),
+            self.index.into_into_dart().into_dart(),
+            self.address.into_into_dart().into_dart(),
+            self.script_pubkey.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::model::APIAddress {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIAddress>
+    for crate::api::model::APIAddress
+{
+    fn into_into_dart(self) -> crate::api::model::APIAddress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::analyzer::APIAnalysisResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.descriptor.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.wallet_type.into_into_dart().into_dart(),
+            self.keys.into_into_dart().into_dart(),
+            self.spend_paths.into_into_dart().into_dart(),
+            self.spendability.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::analyzer::APIAnalysisResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::analyzer::APIAnalysisResult>
+    for crate::api::analyzer::APIAnalysisResult
+{
+    fn into_into_dart(self) -> crate::api::analyzer::APIAnalysisResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::analyzer::APIBatchAnalysis {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.descriptor.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::analyzer::APIBatchAnalysis
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::analyzer::APIBatchAnalysis>
+    for crate::api::analyzer::APIBatchAnalysis
+{
+    fn into_into_dart(self) -> crate::api::analyzer::APIBatchAnalysis {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIConsolidationPlan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.spend_path_id.into_into_dart().into_dart(),
+            self.max_inputs_per_tx.into_into_dart().into_dart(),
+            self.transactions.into_into_dart().into_dart(),
+            self.fees.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APIConsolidationPlan
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIConsolidationPlan>
+    for crate::api::model::APIConsolidationPlan
+{
+    fn into_into_dart(self) -> crate::api::model::APIConsolidationPlan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIDustAnalysis {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.dust_limit.into_into_dart().into_dart(),
+            self.spend_paths.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APIDustAnalysis
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIDustAnalysis>
+    for crate::api::model::APIDustAnalysis
+{
+    fn into_into_dart(self) -> crate::api::model::APIDustAnalysis {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIExpectedCost {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fee_rate.into_into_dart().into_dart(),
+            self.vbytes.into_into_dart().into_dart(),
+            self.fee_sat.into_into_dart().into_dart(),
+            self.vbytes_max.into_into_dart().into_dart(),
+            self.fee_sat_max.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APIExpectedCost
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIExpectedCost>
+    for crate::api::model::APIExpectedCost
+{
+    fn into_into_dart(self) -> crate::api::model::APIExpectedCost {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SpendPath> {
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIFeeEstimate {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.fee_rate.into_into_dart().into_dart(),
+            self.vbytes.into_into_dart().into_dart(),
+            self.fee_sat.into_into_dart().into_dart(),
+            self.vbytes_max.into_into_dart().into_dart(),
+            self.fee_sat_max.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SpendPath> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SpendPath>> for SpendPath {
-    fn into_into_dart(self) -> FrbWrapper<SpendPath> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APIFeeEstimate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIFeeEstimate>
+    for crate::api::model::APIFeeEstimate
+{
+    fn into_into_dart(self) -> crate::api::model::APIFeeEstimate {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::model::APIAbsoluteTimelock {
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIHashLock {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.timelock_type.into_into_dart().into_dart(),
-            self.value.into_into_dart().into_dart(),
+            self.hash_type.into_into_dart().into_dart(),
+            self.digest.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::model::APIAbsoluteTimelock
+    for crate::api::model::APIHashLock
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIAbsoluteTimelock>
-    for crate::api::model::APIAbsoluteTimelock
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIHashLock>
+    for crate::api::model::APIHashLock
 {
-    fn into_into_dart(self) -> crate::api::model::APIAbsoluteTimelock {
+    fn into_into_dart(self) -> crate::api::model::APIHashLock {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::model::APIAbsoluteTimelockType {
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIHashType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            Self::Blocks => 0.into_dart(),
-            Self::Timestamp => 1.into_dart(),
+            Self::Sha256 => 0.into_dart(),
+            Self::Hash256 => 1.into_dart(),
+            Self::Ripemd160 => 2.into_dart(),
+            Self::Hash160 => 3.into_dart(),
             _ => unreachable!(),
         }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::model::APIAbsoluteTimelockType
+    for crate::api::model::APIHashType
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIAbsoluteTimelockType>
-    for crate::api::model::APIAbsoluteTimelockType
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIHashType>
+    for crate::api::model::APIHashType
 {
-    fn into_into_dart(self) -> crate::api::model::APIAbsoluteTimelockType {
+    fn into_into_dart(self) -> crate::api::model::APIHashType {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::analyzer::APIAnalysisResult {
+impl flutter_rust_bridge::IntoDart for crate::api::analyzer::APIImportedWallet {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.descriptor.into_into_dart().into_dart(),
-            self.network.into_into_dart().into_dart(),
-            self.wallet_type.into_into_dart().into_dart(),
-            self.keys.into_into_dart().into_dart(),
-            self.spend_paths.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.analysis.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::analyzer::APIAnalysisResult
+    for crate::api::analyzer::APIImportedWallet
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::analyzer::APIAnalysisResult>
-    for crate::api::analyzer::APIAnalysisResult
+impl flutter_rust_bridge::IntoIntoDart<crate::api::analyzer::APIImportedWallet>
+    for crate::api::analyzer::APIImportedWallet
 {
-    fn into_into_dart(self) -> crate::api::analyzer::APIAnalysisResult {
+    fn into_into_dart(self) -> crate::api::analyzer::APIImportedWallet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIKeychainKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::External => 0.into_dart(),
+            Self::Internal => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APIKeychainKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIKeychainKind>
+    for crate::api::model::APIKeychainKind
+{
+    fn into_into_dart(self) -> crate::api::model::APIKeychainKind {
         self
     }
 }
@@ -1221,6 +3001,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::model::APIPubKey {
             self.mfp.into_into_dart().into_dart(),
             self.derivation_path.into_into_dart().into_dart(),
             self.xpub.into_into_dart().into_dart(),
+            self.synthetic_mfp.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1276,6 +3057,73 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIRelativeTimelockTyp
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APIScriptTypeComparison {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.wallet_type.into_into_dart().into_dart(),
+            self.supported.into_into_dart().into_dart(),
+            self.unsupported_reason.into_into_dart().into_dart(),
+            self.descriptor.into_into_dart().into_dart(),
+            self.spend_paths.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APIScriptTypeComparison
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APIScriptTypeComparison>
+    for crate::api::model::APIScriptTypeComparison
+{
+    fn into_into_dart(self) -> crate::api::model::APIScriptTypeComparison {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APISignerGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.threshold.into_into_dart().into_dart(),
+            self.mfps.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APISignerGroup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APISignerGroup>
+    for crate::api::model::APISignerGroup
+{
+    fn into_into_dart(self) -> crate::api::model::APISignerGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APISignerSet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mfps.into_into_dart().into_dart(),
+            self.wu_in.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APISignerSet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APISignerSet>
+    for crate::api::model::APISignerSet
+{
+    fn into_into_dart(self) -> crate::api::model::APISignerSet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::model::APISpendPath {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -1283,11 +3131,20 @@ impl flutter_rust_bridge::IntoDart for crate::api::model::APISpendPath {
             self.policy_path.into_into_dart().into_dart(),
             self.threshold.into_into_dart().into_dart(),
             self.mfps.into_into_dart().into_dart(),
+            self.signer_groups.into_into_dart().into_dart(),
             self.rel_timelock.into_into_dart().into_dart(),
             self.abs_timelock.into_into_dart().into_dart(),
+            self.rel_timelocks.into_into_dart().into_dart(),
+            self.abs_timelocks.into_into_dart().into_dart(),
+            self.timelock_conflict.into_into_dart().into_dart(),
+            self.hashlocks.into_into_dart().into_dart(),
             self.wu_base.into_into_dart().into_dart(),
             self.wu_in.into_into_dart().into_dart(),
+            self.wu_in_min.into_into_dart().into_dart(),
+            self.wu_in_max.into_into_dart().into_dart(),
             self.wu_out.into_into_dart().into_dart(),
+            self.signer_sets.into_into_dart().into_dart(),
+            self.signer_sets_truncated.into_into_dart().into_dart(),
             self.tr_depth.into_into_dart().into_dart(),
             self.vb_sweep.into_into_dart().into_dart(),
         ]
@@ -1306,6 +3163,29 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APISpendPath>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APISpendPathCost {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.spend_path_id.into_into_dart().into_dart(),
+            self.wu_in.into_into_dart().into_dart(),
+            self.wu_in_max.into_into_dart().into_dart(),
+            self.fees.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APISpendPathCost
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APISpendPathCost>
+    for crate::api::model::APISpendPathCost
+{
+    fn into_into_dart(self) -> crate::api::model::APISpendPathCost {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::model::APISpendPathDef {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -1315,6 +3195,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::model::APISpendPathDef {
             self.abs_timelock.into_into_dart().into_dart(),
             self.is_key_path.into_into_dart().into_dart(),
             self.priority.into_into_dart().into_dart(),
+            self.probability.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1331,6 +3212,115 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APISpendPathDef>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APISpendPathDust {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.spend_path_id.into_into_dart().into_dart(),
+            self.economic_dust.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APISpendPathDust
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APISpendPathDust>
+    for crate::api::model::APISpendPathDust
+{
+    fn into_into_dart(self) -> crate::api::model::APISpendPathDust {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APISpendPathLikelihood {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.spend_path_id.into_into_dart().into_dart(),
+            self.likelihood.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APISpendPathLikelihood
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APISpendPathLikelihood>
+    for crate::api::model::APISpendPathLikelihood
+{
+    fn into_into_dart(self) -> crate::api::model::APISpendPathLikelihood {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APISpendability {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Known => 0.into_dart(),
+            Self::Unknown => 1.into_dart(),
+            Self::Unspendable => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APISpendability
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APISpendability>
+    for crate::api::model::APISpendability
+{
+    fn into_into_dart(self) -> crate::api::model::APISpendability {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APITaprootBuild {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.descriptor.into_into_dart().into_dart(),
+            self.spend_paths.into_into_dart().into_dart(),
+            self.expected_wu_in.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APITaprootBuild
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APITaprootBuild>
+    for crate::api::model::APITaprootBuild
+{
+    fn into_into_dart(self) -> crate::api::model::APITaprootBuild {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::model::APITaprootPathCost {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.spend_path_id.into_into_dart().into_dart(),
+            self.probability.into_into_dart().into_dart(),
+            self.tr_depth.into_into_dart().into_dart(),
+            self.wu_in.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::model::APITaprootPathCost
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::model::APITaprootPathCost>
+    for crate::api::model::APITaprootPathCost
+{
+    fn into_into_dart(self) -> crate::api::model::APITaprootPathCost {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::model::APIWalletType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -1414,6 +3404,16 @@ impl SseEncode for crate::api::model::APIAbsoluteTimelockType {
     }
 }
 
+impl SseEncode for crate::api::model::APIAddress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::model::APIKeychainKind>::sse_encode(self.keychain, serializer);
+        <u32>::sse_encode(self.index, serializer);
+        <String>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.script_pubkey, serializer);
+    }
+}
+
 impl SseEncode for crate::api::analyzer::APIAnalysisResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1422,6 +3422,106 @@ impl SseEncode for crate::api::analyzer::APIAnalysisResult {
         <crate::api::model::APIWalletType>::sse_encode(self.wallet_type, serializer);
         <Vec<crate::api::model::APIPubKey>>::sse_encode(self.keys, serializer);
         <Vec<crate::api::model::APISpendPath>>::sse_encode(self.spend_paths, serializer);
+        <crate::api::model::APISpendability>::sse_encode(self.spendability, serializer);
+    }
+}
+
+impl SseEncode for crate::api::analyzer::APIBatchAnalysis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.descriptor, serializer);
+        <Option<crate::api::analyzer::APIAnalysisResult>>::sse_encode(self.result, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APIConsolidationPlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.spend_path_id, serializer);
+        <u32>::sse_encode(self.max_inputs_per_tx, serializer);
+        <u32>::sse_encode(self.transactions, serializer);
+        <Vec<crate::api::model::APIFeeEstimate>>::sse_encode(self.fees, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APIDustAnalysis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.dust_limit, serializer);
+        <Vec<crate::api::model::APISpendPathDust>>::sse_encode(self.spend_paths, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APIExpectedCost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.fee_rate, serializer);
+        <f64>::sse_encode(self.vbytes, serializer);
+        <f64>::sse_encode(self.fee_sat, serializer);
+        <f64>::sse_encode(self.vbytes_max, serializer);
+        <f64>::sse_encode(self.fee_sat_max, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APIFeeEstimate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.fee_rate, serializer);
+        <u64>::sse_encode(self.vbytes, serializer);
+        <u64>::sse_encode(self.fee_sat, serializer);
+        <u64>::sse_encode(self.vbytes_max, serializer);
+        <u64>::sse_encode(self.fee_sat_max, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APIHashLock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::model::APIHashType>::sse_encode(self.hash_type, serializer);
+        <String>::sse_encode(self.digest, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APIHashType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::model::APIHashType::Sha256 => 0,
+                crate::api::model::APIHashType::Hash256 => 1,
+                crate::api::model::APIHashType::Ripemd160 => 2,
+                crate::api::model::APIHashType::Hash160 => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::analyzer::APIImportedWallet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.name, serializer);
+        <crate::api::analyzer::APIAnalysisResult>::sse_encode(self.analysis, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APIKeychainKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::model::APIKeychainKind::External => 0,
+                crate::api::model::APIKeychainKind::Internal => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -1458,6 +3558,7 @@ impl SseEncode for crate::api::model::APIPubKey {
         <String>::sse_encode(self.mfp, serializer);
         <String>::sse_encode(self.derivation_path, serializer);
         <String>::sse_encode(self.xpub, serializer);
+        <bool>::sse_encode(self.synthetic_mfp, serializer);
     }
 }
 
@@ -1485,6 +3586,34 @@ impl SseEncode for crate::api::model::APIRelativeTimelockType {
     }
 }
 
+impl SseEncode for crate::api::model::APIScriptTypeComparison {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::model::APIWalletType>::sse_encode(self.wallet_type, serializer);
+        <bool>::sse_encode(self.supported, serializer);
+        <Option<String>>::sse_encode(self.unsupported_reason, serializer);
+        <Option<String>>::sse_encode(self.descriptor, serializer);
+        <Vec<crate::api::model::APISpendPathCost>>::sse_encode(self.spend_paths, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APISignerGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.threshold, serializer);
+        <Vec<String>>::sse_encode(self.mfps, serializer);
+        <Vec<crate::api::model::APISignerGroup>>::sse_encode(self.groups, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APISignerSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.mfps, serializer);
+        <u32>::sse_encode(self.wu_in, serializer);
+    }
+}
+
 impl SseEncode for crate::api::model::APISpendPath {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1492,16 +3621,35 @@ impl SseEncode for crate::api::model::APISpendPath {
         <Vec<crate::api::model::APIPolicyPath>>::sse_encode(self.policy_path, serializer);
         <u32>::sse_encode(self.threshold, serializer);
         <Vec<String>>::sse_encode(self.mfps, serializer);
+        <Vec<crate::api::model::APISignerGroup>>::sse_encode(self.signer_groups, serializer);
         <crate::api::model::APIRelativeTimelock>::sse_encode(self.rel_timelock, serializer);
         <crate::api::model::APIAbsoluteTimelock>::sse_encode(self.abs_timelock, serializer);
+        <Vec<crate::api::model::APIRelativeTimelock>>::sse_encode(self.rel_timelocks, serializer);
+        <Vec<crate::api::model::APIAbsoluteTimelock>>::sse_encode(self.abs_timelocks, serializer);
+        <bool>::sse_encode(self.timelock_conflict, serializer);
+        <Vec<crate::api::model::APIHashLock>>::sse_encode(self.hashlocks, serializer);
         <u32>::sse_encode(self.wu_base, serializer);
         <u32>::sse_encode(self.wu_in, serializer);
+        <u32>::sse_encode(self.wu_in_min, serializer);
+        <u32>::sse_encode(self.wu_in_max, serializer);
         <u32>::sse_encode(self.wu_out, serializer);
+        <Vec<crate::api::model::APISignerSet>>::sse_encode(self.signer_sets, serializer);
+        <bool>::sse_encode(self.signer_sets_truncated, serializer);
         <i32>::sse_encode(self.tr_depth, serializer);
         <f32>::sse_encode(self.vb_sweep, serializer);
     }
 }
 
+impl SseEncode for crate::api::model::APISpendPathCost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.spend_path_id, serializer);
+        <u32>::sse_encode(self.wu_in, serializer);
+        <u32>::sse_encode(self.wu_in_max, serializer);
+        <Vec<crate::api::model::APIFeeEstimate>>::sse_encode(self.fees, serializer);
+    }
+}
+
 impl SseEncode for crate::api::model::APISpendPathDef {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1511,6 +3659,59 @@ impl SseEncode for crate::api::model::APISpendPathDef {
         <crate::api::model::APIAbsoluteTimelock>::sse_encode(self.abs_timelock, serializer);
         <bool>::sse_encode(self.is_key_path, serializer);
         <u32>::sse_encode(self.priority, serializer);
+        <Option<f64>>::sse_encode(self.probability, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APISpendPathDust {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.spend_path_id, serializer);
+        <Vec<u64>>::sse_encode(self.economic_dust, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APISpendPathLikelihood {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.spend_path_id, serializer);
+        <f64>::sse_encode(self.likelihood, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APISpendability {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::model::APISpendability::Known => 0,
+                crate::api::model::APISpendability::Unknown => 1,
+                crate::api::model::APISpendability::Unspendable => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::model::APITaprootBuild {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.descriptor, serializer);
+        <Vec<crate::api::model::APITaprootPathCost>>::sse_encode(self.spend_paths, serializer);
+        <f64>::sse_encode(self.expected_wu_in, serializer);
+    }
+}
+
+impl SseEncode for crate::api::model::APITaprootPathCost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.spend_path_id, serializer);
+        <f64>::sse_encode(self.probability, serializer);
+        <i32>::sse_encode(self.tr_depth, serializer);
+        <u32>::sse_encode(self.wu_in, serializer);
     }
 }
 
@@ -1550,6 +3751,13 @@ impl SseEncode for f32 {
     }
 }
 
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1577,6 +3785,76 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::api::model::APIAbsoluteTimelock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIAbsoluteTimelock>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APIAddress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIAddress>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::analyzer::APIBatchAnalysis> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::analyzer::APIBatchAnalysis>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APIConsolidationPlan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIConsolidationPlan>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APIExpectedCost> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIExpectedCost>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APIFeeEstimate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIFeeEstimate>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APIHashLock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIHashLock>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::model::APIPolicyPath> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1597,6 +3875,46 @@ impl SseEncode for Vec<crate::api::model::APIPubKey> {
     }
 }
 
+impl SseEncode for Vec<crate::api::model::APIRelativeTimelock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIRelativeTimelock>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APIScriptTypeComparison> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIScriptTypeComparison>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APISignerGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APISignerGroup>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APISignerSet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APISignerSet>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::model::APISpendPath> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1607,6 +3925,16 @@ impl SseEncode for Vec<crate::api::model::APISpendPath> {
     }
 }
 
+impl SseEncode for Vec<crate::api::model::APISpendPathCost> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APISpendPathCost>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::model::APISpendPathDef> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1617,6 +3945,56 @@ impl SseEncode for Vec<crate::api::model::APISpendPathDef> {
     }
 }
 
+impl SseEncode for Vec<crate::api::model::APISpendPathDust> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APISpendPathDust>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APISpendPathLikelihood> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APISpendPathLikelihood>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APITaprootPathCost> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APITaprootPathCost>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::model::APIWalletType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::model::APIWalletType>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1627,6 +4005,16 @@ impl SseEncode for Vec<u32> {
     }
 }
 
+impl SseEncode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u64>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1637,6 +4025,56 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::model::APIAddress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::model::APIAddress>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::analyzer::APIAnalysisResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::analyzer::APIAnalysisResult>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1644,6 +4082,13 @@ impl SseEncode for u32 {
     }
 }
 
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {